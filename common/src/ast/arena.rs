@@ -0,0 +1,406 @@
+//! This file defines an arena-backed representation of an `AST`.
+//!
+//! `ASTNode::get_children` and `AST::get_root` clone whole subtrees, which makes a recursive phase quadratic in
+//! the size of the tree. An `ArenaAST` instead stores every node once in a flat vector and refers to nodes by
+//! `NodeId`, so children are borrowed as a slice of ids and every node knows its parent. Facts computed by a
+//! phase, such as a node's type, live in a `SideTable` keyed by `NodeId` rather than in the tree.
+//!
+//! Trees are still built with `ASTNode::new` and `add_child`, then converted with `ArenaAST::from_ast`;
+//! `ArenaAST::to_ast` converts back for the phases that take an `AST`.
+
+use std::fmt;
+
+use crate::ast::{
+    core::{ASTNode, AST},
+    data_type::DataType,
+    node_type::NodeType,
+};
+
+/// A handle to a node in an `ArenaAST`. Ids are stable for the lifetime of the arena, since nodes are never
+/// removed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NodeId(usize);
+
+/// A node stored in an `ArenaAST`.
+///
+/// # Fields
+/// * `node_type` - The kind of the node and its payload.
+/// * `children` - The ids of the node's children, in order.
+/// * `parent` - The id of the node's parent, or `None` for the root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArenaNode {
+    node_type: NodeType,
+    children: Vec<NodeId>,
+    parent: Option<NodeId>,
+}
+
+/// An `AST` whose nodes are stored in a single arena and addressed by `NodeId`.
+///
+/// # Fields
+/// * `nodes` - Every node of the tree, indexed by `NodeId`. Nodes converted from an `AST` are in pre-order.
+/// * `root` - The id of the root node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArenaAST {
+    nodes: Vec<ArenaNode>,
+    root: NodeId,
+}
+
+/// Information about the nodes of an `ArenaAST` that is computed by a phase and kept outside the tree.
+///
+/// # Fields
+/// * `values` - The value recorded for each node, indexed by `NodeId`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SideTable<T> {
+    values: Vec<Option<T>>,
+}
+
+/// A side table recording the data type of each expression node.
+pub type TypeTable = SideTable<DataType>;
+
+impl NodeId {
+    /// Retrieves the index of this node in its arena.
+    ///
+    /// # Returns
+    ///
+    /// Returns the node's index as a `usize`.
+    pub fn get_index(&self) -> usize {
+        self.0
+    }
+}
+
+impl fmt::Display for NodeId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{}", self.0)
+    }
+}
+
+impl ArenaNode {
+    /// Retrieves the node type of this node.
+    ///
+    /// # Returns
+    ///
+    /// Returns a reference to the node's `NodeType`.
+    pub fn get_node_type(&self) -> &NodeType {
+        &self.node_type
+    }
+
+    /// Retrieves the children of this node.
+    ///
+    /// # Returns
+    ///
+    /// Returns the ids of the node's children as a slice.
+    pub fn get_children(&self) -> &[NodeId] {
+        &self.children
+    }
+
+    /// Retrieves the parent of this node.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(NodeId)` with the node's parent, or `None` for the root.
+    pub fn get_parent(&self) -> Option<NodeId> {
+        self.parent
+    }
+}
+
+impl ArenaAST {
+    /// Creates a new `ArenaAST` holding only a root node.
+    ///
+    /// # Parameters
+    ///
+    /// - `root_type`: The `NodeType` of the root node.
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `ArenaAST` whose root has no children.
+    pub fn new(root_type: NodeType) -> Self {
+        ArenaAST {
+            nodes: vec![ArenaNode { node_type: root_type, children: Vec::new(), parent: None }],
+            root: NodeId(0),
+        }
+    }
+
+    /// Converts an `AST` into an arena, numbering its nodes in pre-order.
+    ///
+    /// # Parameters
+    ///
+    /// - `ast`: The `AST` to convert.
+    ///
+    /// # Returns
+    ///
+    /// Returns an `ArenaAST` with the same structure as `ast`.
+    pub fn from_ast(ast: &AST) -> Self {
+        let root = ast.get_root_ref();
+        let mut arena = ArenaAST::new(root.get_node_type());
+        let root_id = arena.root;
+        for child in root.get_children_ref() {
+            arena.insert_subtree(root_id, child);
+        }
+        arena
+    }
+
+    /// Converts this arena back into an `AST`.
+    ///
+    /// # Returns
+    ///
+    /// Returns an `AST` with the same structure as this arena.
+    pub fn to_ast(&self) -> AST {
+        AST::new(self.to_node(self.root))
+    }
+
+    /// Converts the subtree rooted at a node back into an `ASTNode`.
+    ///
+    /// # Parameters
+    ///
+    /// - `id`: The id of the subtree's root.
+    ///
+    /// # Returns
+    ///
+    /// Returns an `ASTNode` with the same structure as the subtree.
+    pub fn to_node(&self, id: NodeId) -> ASTNode {
+        let node = self.get_node(id);
+        let mut converted = ASTNode::new(node.node_type.clone());
+        converted.set_children(node.children.iter().map(|child| self.to_node(*child)).collect());
+        converted
+    }
+
+    /// Adds a new node as the last child of an existing node.
+    ///
+    /// # Parameters
+    ///
+    /// - `parent`: The id of the node to add a child to.
+    /// - `node_type`: The `NodeType` of the new node.
+    ///
+    /// # Returns
+    ///
+    /// Returns the `NodeId` of the new node.
+    pub fn add_child(&mut self, parent: NodeId, node_type: NodeType) -> NodeId {
+        let id = NodeId(self.nodes.len());
+        self.nodes.push(ArenaNode { node_type, children: Vec::new(), parent: Some(parent) });
+        self.nodes[parent.0].children.push(id);
+        id
+    }
+
+    /// Copies an `ASTNode` and its descendants into the arena as the last child of an existing node.
+    ///
+    /// # Parameters
+    ///
+    /// - `parent`: The id of the node to add the subtree to.
+    /// - `node`: The subtree to copy.
+    ///
+    /// # Returns
+    ///
+    /// Returns the `NodeId` of the subtree's root.
+    pub fn insert_subtree(&mut self, parent: NodeId, node: &ASTNode) -> NodeId {
+        let id = self.add_child(parent, node.get_node_type());
+        for child in node.get_children_ref() {
+            self.insert_subtree(id, child);
+        }
+        id
+    }
+
+    /// Retrieves the id of the root node.
+    ///
+    /// # Returns
+    ///
+    /// Returns the root's `NodeId`.
+    pub fn get_root(&self) -> NodeId {
+        self.root
+    }
+
+    /// Retrieves a node.
+    ///
+    /// # Parameters
+    ///
+    /// - `id`: The id of the node.
+    ///
+    /// # Returns
+    ///
+    /// Returns a reference to the `ArenaNode`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id` was not issued by this arena.
+    pub fn get_node(&self, id: NodeId) -> &ArenaNode {
+        &self.nodes[id.0]
+    }
+
+    /// Retrieves the node type of a node.
+    ///
+    /// # Parameters
+    ///
+    /// - `id`: The id of the node.
+    ///
+    /// # Returns
+    ///
+    /// Returns a reference to the node's `NodeType`.
+    pub fn get_node_type(&self, id: NodeId) -> &NodeType {
+        &self.get_node(id).node_type
+    }
+
+    /// Retrieves the children of a node.
+    ///
+    /// # Parameters
+    ///
+    /// - `id`: The id of the node.
+    ///
+    /// # Returns
+    ///
+    /// Returns the ids of the node's children as a slice.
+    pub fn get_children(&self, id: NodeId) -> &[NodeId] {
+        &self.get_node(id).children
+    }
+
+    /// Retrieves the parent of a node.
+    ///
+    /// # Parameters
+    ///
+    /// - `id`: The id of the node.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(NodeId)` with the node's parent, or `None` for the root.
+    pub fn get_parent(&self, id: NodeId) -> Option<NodeId> {
+        self.get_node(id).parent
+    }
+
+    /// Retrieves the ancestors of a node, from its parent up to the root.
+    ///
+    /// # Parameters
+    ///
+    /// - `id`: The id of the node.
+    ///
+    /// # Returns
+    ///
+    /// Returns the ids of the node's ancestors, nearest first.
+    pub fn get_ancestors(&self, id: NodeId) -> Vec<NodeId> {
+        let mut ancestors = Vec::new();
+        let mut current = self.get_parent(id);
+        while let Some(parent) = current {
+            ancestors.push(parent);
+            current = self.get_parent(parent);
+        }
+        ancestors
+    }
+
+    /// Retrieves the ids of the nodes in the subtree rooted at a node, in pre-order.
+    ///
+    /// # Parameters
+    ///
+    /// - `id`: The id of the subtree's root.
+    ///
+    /// # Returns
+    ///
+    /// Returns the ids of the subtree's nodes, starting with `id`.
+    pub fn get_descendants(&self, id: NodeId) -> Vec<NodeId> {
+        let mut order = Vec::new();
+        let mut stack = vec![id];
+        while let Some(current) = stack.pop() {
+            order.push(current);
+            stack.extend(self.get_children(current).iter().rev());
+        }
+        order
+    }
+
+    /// Retrieves the number of nodes in the arena.
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of nodes, which is always at least one.
+    pub fn size(&self) -> usize {
+        self.nodes.len()
+    }
+}
+
+impl From<&AST> for ArenaAST {
+    fn from(ast: &AST) -> Self {
+        ArenaAST::from_ast(ast)
+    }
+}
+
+impl From<&ArenaAST> for AST {
+    fn from(arena: &ArenaAST) -> Self {
+        arena.to_ast()
+    }
+}
+
+impl<T> Default for SideTable<T> {
+    fn default() -> Self {
+        SideTable { values: Vec::new() }
+    }
+}
+
+impl<T> SideTable<T> {
+    /// Creates a new, empty `SideTable`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `SideTable` with no recorded values.
+    pub fn new() -> Self {
+        SideTable::default()
+    }
+
+    /// Records a value for a node, replacing any previous value.
+    ///
+    /// # Parameters
+    ///
+    /// - `id`: The id of the node.
+    /// - `value`: The value to record.
+    ///
+    /// # Returns
+    ///
+    /// Returns the previously recorded value, if any.
+    pub fn insert(&mut self, id: NodeId, value: T) -> Option<T> {
+        if self.values.len() <= id.0 {
+            self.values.resize_with(id.0 + 1, || None);
+        }
+        self.values[id.0].replace(value)
+    }
+
+    /// Retrieves the value recorded for a node.
+    ///
+    /// # Parameters
+    ///
+    /// - `id`: The id of the node.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(&T)` if a value was recorded, `None` otherwise.
+    pub fn get(&self, id: NodeId) -> Option<&T> {
+        self.values.get(id.0).and_then(|value| value.as_ref())
+    }
+
+    /// Removes the value recorded for a node.
+    ///
+    /// # Parameters
+    ///
+    /// - `id`: The id of the node.
+    ///
+    /// # Returns
+    ///
+    /// Returns the removed value, if any.
+    pub fn remove(&mut self, id: NodeId) -> Option<T> {
+        self.values.get_mut(id.0).and_then(|value| value.take())
+    }
+
+    /// Retrieves every recorded value with the id of its node, in id order.
+    ///
+    /// # Returns
+    ///
+    /// Returns the `(NodeId, &T)` pairs of the table.
+    pub fn entries(&self) -> Vec<(NodeId, &T)> {
+        self.values.iter().enumerate()
+            .filter_map(|(index, value)| value.as_ref().map(|value| (NodeId(index), value)))
+            .collect()
+    }
+
+    /// Checks if the table has no recorded values.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if no node has a value, `false` otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.values.iter().all(|value| value.is_none())
+    }
+}
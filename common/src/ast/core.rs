@@ -12,6 +12,7 @@ use crate::ast::node_type::NodeType;
 /// # Fields
 /// * `root` - The root node of the AST, who's children contain the entire structure of the parsed source code.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AST {
     root: ASTNode,
 }
@@ -51,6 +52,7 @@ pub struct AST {
 /// * `element` - The syntactic element this node represents.
 /// * `children` - A vector of child nodes, which further define the structure of the syntax tree.
 #[derive(Debug, Clone, PartialEq, Default, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ASTNode {
     node_type: NodeType,
     children: Vec<ASTNode>, 
@@ -83,6 +85,26 @@ impl AST {
         self.root.clone()
     }
 
+    /// Borrows the root of the `AST` instance without cloning it.
+    /// 
+    /// # Returns
+    ///
+    /// Returns a reference to the given `AST`'s root node.
+    ///
+    pub fn get_root_ref(&self) -> &ASTNode {
+        &self.root
+    }
+
+    /// Mutably borrows the root of the `AST` instance, for passes that rewrite the tree in place.
+    /// 
+    /// # Returns
+    ///
+    /// Returns a mutable reference to the given `AST`'s root node.
+    ///
+    pub fn get_root_mut(&mut self) -> &mut ASTNode {
+        &mut self.root
+    }
+
 }
 
 /// Formats an `AST` starting from a specific node and appends the formatted string to the provided output string.
@@ -159,6 +181,36 @@ impl ASTNode {
         self.children.clone()
     }
 
+    /// Borrows the node type of the given `ASTNode` instance without cloning it.
+    /// 
+    /// # Returns
+    ///
+    /// Returns a reference to the given `ASTNode`'s node type.
+    ///
+    pub fn get_node_type_ref(&self) -> &NodeType {
+        &self.node_type
+    }
+
+    /// Borrows the children of the given `ASTNode` instance without cloning them.
+    /// 
+    /// # Returns
+    ///
+    /// Returns the given `ASTNode`'s children as a slice.
+    ///
+    pub fn get_children_ref(&self) -> &[ASTNode] {
+        &self.children
+    }
+
+    /// Mutably borrows the children of the given `ASTNode` instance, for passes that rewrite the tree in place.
+    /// 
+    /// # Returns
+    ///
+    /// Returns a mutable reference to the given `ASTNode`'s vector of children.
+    ///
+    pub fn get_children_mut(&mut self) -> &mut Vec<ASTNode> {
+        &mut self.children
+    }
+
     /// Sets the children of this `ASTNode` to a specified vector.
     /// 
     /// # Parameters
@@ -5,6 +5,7 @@ use std::fmt;
 
 /// Represents the different data types associated with syntax elements in an `AST`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DataType {
     /// An integer.
     Integer,
@@ -26,6 +27,8 @@ pub enum DataType {
     Struct,
     /// An enumeration.
     Enum,
+    /// A union type, a composite data type whose members overlap in memory.
+    Union,
     /// Represents no type, used in certain contexts where a type is not applicable.
     None,
     /// A void type for functions that do not return a value.
@@ -36,6 +39,92 @@ pub enum DataType {
     Sign,
 }
 
+impl DataType {
+    /// Applies C's default argument promotions, which are used for arguments matching the `...` of a
+    /// variadic function: a `float` is promoted to a `double`, and a `char` or boolean to an `int`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the promoted `DataType`, or the type itself if no promotion applies.
+    pub fn default_argument_promotion(&self) -> DataType {
+        match self {
+            DataType::Float => DataType::Double,
+            DataType::Char | DataType::Boolean => DataType::Integer,
+            other => *other,
+        }
+    }
+
+    /// Checks whether this is an integer type. Characters, booleans and enums are integer types.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if values of this type are integers, `false` otherwise.
+    pub fn is_integer(&self) -> bool {
+        matches!(self, DataType::Integer | DataType::Long | DataType::Boolean | DataType::Char | DataType::Enum
+            | DataType::Unsign | DataType::Sign)
+    }
+
+    /// Checks whether this is an arithmetic type, that is an integer or floating-point type.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if values of this type can be used in arithmetic, `false` otherwise.
+    pub fn is_arithmetic(&self) -> bool {
+        self.is_integer() || matches!(self, DataType::Float | DataType::Double)
+    }
+
+    /// Applies C's integer promotions, which are applied to the operands of arithmetic: a type ranked below
+    /// `int`, such as a `char`, boolean or enum, is promoted to an `int`, and a plain `signed` names an `int`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the promoted `DataType`, or the type itself if no promotion applies.
+    pub fn integer_promotion(&self) -> DataType {
+        match self {
+            DataType::Boolean | DataType::Char | DataType::Enum | DataType::Sign => DataType::Integer,
+            other => *other,
+        }
+    }
+
+    /// Applies C's usual arithmetic conversions, which give the common type of the two operands of a binary
+    /// arithmetic, bitwise or comparison operator. A `double` operand wins over a `float`, which wins over any
+    /// integer; otherwise both operands are promoted and the wider integer type wins, with `long` able to hold
+    /// every `unsigned` value and `unsigned` winning over `int`.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The type of the other operand.
+    ///
+    /// # Returns
+    ///
+    /// Returns the common `DataType`, or `None` if either type is not arithmetic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common::ast::data_type::DataType;
+    ///
+    /// assert_eq!(DataType::Char.usual_arithmetic_conversion(&DataType::Char), Some(DataType::Integer));
+    /// assert_eq!(DataType::Integer.usual_arithmetic_conversion(&DataType::Float), Some(DataType::Float));
+    /// assert_eq!(DataType::Unsign.usual_arithmetic_conversion(&DataType::Long), Some(DataType::Long));
+    /// assert_eq!(DataType::Integer.usual_arithmetic_conversion(&DataType::Struct), None);
+    /// ```
+    pub fn usual_arithmetic_conversion(&self, other: &DataType) -> Option<DataType> {
+        if !self.is_arithmetic() || !other.is_arithmetic() {
+            return None;
+        }
+        let rank = |data_type: DataType| match data_type {
+            DataType::Double => 4,
+            DataType::Float => 3,
+            DataType::Long => 2,
+            DataType::Unsign => 1,
+            _ => 0,
+        };
+        let (left, right) = (self.integer_promotion(), other.integer_promotion());
+        Some(if rank(left) >= rank(right) { left } else { right })
+    }
+}
+
 /// Provides a display implementation for `DataType`.
 ///
 /// # Parameters
@@ -56,6 +145,7 @@ impl fmt::Display for DataType {
             DataType::Function => write!(f, "Function"),
             DataType::Struct => write!(f, "Struct"),
             DataType::Enum => write!(f, "Enum"),
+            DataType::Union => write!(f, "Union"),
             DataType::None => write!(f, "None"),
             DataType::Void => write!(f, "Void"),
             DataType::Unsign => write!(f, "Unsigned"),
@@ -0,0 +1,347 @@
+//! This file defines a desugaring pass that lowers convenience constructs into a smaller core language, so later
+//! phases, analyses and teaching material only have to handle the core forms. Each lowering can be enabled on
+//! its own to show one rewrite at a time:
+//!
+//! * `ForLoop` - `for (init; cond; inc) body` becomes `{ init; while (cond) { body inc; } }`, where a missing
+//!   condition becomes `1` and every `continue` of the loop runs the increment first.
+//! * `DoWhileLoop` - `do body while (cond);` becomes `while (1) { body if (!cond) { break; } }`, where every
+//!   `continue` of the loop runs the check first.
+//! * `CompoundAssignment` - `x op= v` becomes `x = x op v`. The target is duplicated, which is exact because
+//!   assignment targets are identifiers and member accesses without side effects.
+//! * `Increment` - `++x` and `--x` become `x = x + 1` and `x = x - 1`, as do `x++` and `x--` where their value
+//!   is unused: as a statement or a loop increment. A postfix increment whose value is used is left as is,
+//!   since lowering it needs a temporary.
+//! * `ElseIf` - `else if (...)` becomes `else { if (...) }`.
+//!
+//! Loop bodies are kept as nested blocks, so the scopes of their declarations are unchanged.
+
+use crate::ast::{
+    core::{ASTNode, AST},
+    node_type::NodeType,
+    visit::{fold_children, Fold},
+};
+
+/// One rewrite performed by the desugaring pass.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lowering {
+    /// Lowers `for` loops to `while` loops.
+    ForLoop,
+    /// Lowers `do while` loops to `while` loops.
+    DoWhileLoop,
+    /// Lowers compound assignments such as `x += 1` to plain assignments.
+    CompoundAssignment,
+    /// Lowers `++` and `--` to plain assignments.
+    Increment,
+    /// Lowers `else if` chains to nested blocks.
+    ElseIf,
+}
+
+impl Lowering {
+    /// Retrieves every lowering, in the order they are described in this module.
+    ///
+    /// # Returns
+    /// Returns a slice of all `Lowering` variants.
+    pub fn all() -> &'static [Lowering] {
+        &[Lowering::ForLoop, Lowering::DoWhileLoop, Lowering::CompoundAssignment, Lowering::Increment, Lowering::ElseIf]
+    }
+}
+
+/// A `Fold` that applies a set of lowerings to a tree.
+///
+/// # Fields
+/// * `lowerings` - The lowerings this pass applies.
+#[derive(Debug, Clone)]
+pub struct Desugarer {
+    lowerings: Vec<Lowering>,
+}
+
+impl Default for Desugarer {
+    fn default() -> Self {
+        Desugarer::new()
+    }
+}
+
+impl Desugarer {
+    /// Creates a pass applying every lowering.
+    ///
+    /// # Returns
+    /// Returns a new `Desugarer`.
+    pub fn new() -> Self {
+        Desugarer { lowerings: Lowering::all().to_vec() }
+    }
+
+    /// Creates a pass applying only the given lowerings.
+    ///
+    /// # Parameters
+    /// * `lowerings` - The lowerings to apply.
+    ///
+    /// # Returns
+    /// Returns a new `Desugarer`.
+    ///
+    /// # Examples
+    /// ```
+    /// use common::{ast, ast::{desugar::{Desugarer, Lowering}, visit::Fold}};
+    ///
+    /// let tree = ast!(BlockExpression[Assignment[Identifier("x"), Operator("+="), Literal(1)]]);
+    /// let lowered = Desugarer::only(&[Lowering::CompoundAssignment]).fold_ast(tree);
+    /// assert_eq!(lowered, ast!(BlockExpression[
+    ///     Assignment[Identifier("x"), BinaryExpression[Identifier("x"), Operator("+"), Literal(1)]],
+    /// ]));
+    /// ```
+    pub fn only(lowerings: &[Lowering]) -> Self {
+        Desugarer { lowerings: lowerings.to_vec() }
+    }
+
+    /// Checks whether this pass applies a lowering.
+    ///
+    /// # Parameters
+    /// * `lowering` - The lowering to check.
+    ///
+    /// # Returns
+    /// Returns `true` if the lowering is applied, `false` otherwise.
+    pub fn is_enabled(&self, lowering: Lowering) -> bool {
+        self.lowerings.contains(&lowering)
+    }
+
+    /// Lowers postfix increments whose value is unused among a list of statements.
+    fn lower_statements(&self, node: ASTNode) -> ASTNode {
+        if !self.is_enabled(Lowering::Increment) {
+            return node;
+        }
+        let mut node = node;
+        let children = std::mem::take(node.get_children_mut());
+        node.set_children(children.into_iter().map(|child| lower_increment(child, true)).collect());
+        node
+    }
+}
+
+impl Fold for Desugarer {
+    fn fold_for_loop(&mut self, node: ASTNode) -> ASTNode {
+        let node = fold_children(self, node);
+        if self.is_enabled(Lowering::ForLoop) { lower_for(node) } else { node }
+    }
+
+    fn fold_do_while_loop(&mut self, node: ASTNode) -> ASTNode {
+        let node = fold_children(self, node);
+        if self.is_enabled(Lowering::DoWhileLoop) { lower_do_while(node) } else { node }
+    }
+
+    fn fold_assignment(&mut self, node: ASTNode) -> ASTNode {
+        let node = fold_children(self, node);
+        if self.is_enabled(Lowering::CompoundAssignment) { lower_compound(node) } else { node }
+    }
+
+    fn fold_unary_expression(&mut self, node: ASTNode) -> ASTNode {
+        let node = fold_children(self, node);
+        if self.is_enabled(Lowering::Increment) { lower_increment(node, false) } else { node }
+    }
+
+    fn fold_block_expression(&mut self, node: ASTNode) -> ASTNode {
+        let node = fold_children(self, node);
+        self.lower_statements(node)
+    }
+
+    fn fold_top_level_expression(&mut self, node: ASTNode) -> ASTNode {
+        let node = fold_children(self, node);
+        self.lower_statements(node)
+    }
+
+    fn fold_loop_increment(&mut self, node: ASTNode) -> ASTNode {
+        let node = fold_children(self, node);
+        self.lower_statements(node)
+    }
+
+    fn fold_else_statement(&mut self, node: ASTNode) -> ASTNode {
+        let node = fold_children(self, node);
+        if !self.is_enabled(Lowering::ElseIf) {
+            return node;
+        }
+        let mut node = node;
+        let children = std::mem::take(node.get_children_mut());
+        node.set_children(children.into_iter().map(|child| match child.get_node_type_ref() {
+            NodeType::IfStatement => with_children(NodeType::BlockExpression, vec![child]),
+            _ => child,
+        }).collect());
+        node
+    }
+}
+
+/// Applies every lowering to an `AST`.
+///
+/// # Parameters
+/// * `ast` - The tree to lower.
+///
+/// # Returns
+/// Returns the lowered `AST`.
+///
+/// # Examples
+/// ```
+/// use common::{ast, ast::desugar::desugar};
+///
+/// let tree = ast!(BlockExpression[UnaryExpression[Identifier("i"), Operator("++")]]);
+/// assert_eq!(desugar(tree), ast!(BlockExpression[
+///     Assignment[Identifier("i"), BinaryExpression[Identifier("i"), Operator("+"), Literal(1)]],
+/// ]));
+/// ```
+pub fn desugar(ast: AST) -> AST {
+    Desugarer::new().fold_ast(ast)
+}
+
+/// Applies every lowering to a subtree.
+///
+/// # Parameters
+/// * `node` - The root of the subtree to lower.
+///
+/// # Returns
+/// Returns the lowered subtree. A lowered `for` loop with an initializer is returned as a `BlockExpression`.
+pub fn desugar_node(node: ASTNode) -> ASTNode {
+    Desugarer::new().fold(node)
+}
+
+/// Builds a node with the given children.
+fn with_children(node_type: NodeType, children: Vec<ASTNode>) -> ASTNode {
+    let mut node = ASTNode::new(node_type);
+    node.set_children(children);
+    node
+}
+
+/// Builds the literal `1`, used as an always-true condition and as the step of increments.
+fn one() -> ASTNode {
+    ASTNode::new(NodeType::Literal("1".to_string()))
+}
+
+/// Removes and returns the first child of the given kind.
+fn take_child(children: &mut Vec<ASTNode>, kind: fn(&NodeType) -> bool) -> Option<ASTNode> {
+    let index = children.iter().position(|child| kind(child.get_node_type_ref()))?;
+    Some(children.remove(index))
+}
+
+/// Lowers a folded `ForLoop` to a `WhileLoop`, wrapped in a block with its initializer if it has one.
+fn lower_for(node: ASTNode) -> ASTNode {
+    let mut node = node;
+    let mut children = std::mem::take(node.get_children_mut());
+    let initializer = take_child(&mut children, |kind| matches!(kind, NodeType::LoopInitializer));
+    let condition = take_child(&mut children, |kind| matches!(kind, NodeType::Condition));
+    let increment = take_child(&mut children, |kind| matches!(kind, NodeType::LoopIncrement));
+    let body = take_child(&mut children, |kind| matches!(kind, NodeType::BlockExpression));
+
+    let increment: Vec<ASTNode> = increment.map(|increment| increment.get_children()).unwrap_or_default();
+    let mut body = body.unwrap_or_else(|| ASTNode::new(NodeType::BlockExpression));
+    if !increment.is_empty() {
+        let continue_with_increment = |continue_node: ASTNode| {
+            let mut statements = increment.clone();
+            statements.push(continue_node);
+            with_children(NodeType::BlockExpression, statements)
+        };
+        body = replace_continues(body, &continue_with_increment);
+    }
+    let mut loop_body = vec![body];
+    loop_body.extend(increment.iter().cloned());
+
+    let while_loop = with_children(NodeType::WhileLoop, vec![
+        with_children(NodeType::Condition, vec![condition_or_true(condition)]),
+        with_children(NodeType::BlockExpression, loop_body),
+    ]);
+    match initializer.map(|initializer| initializer.get_children()).filter(|statements| !statements.is_empty()) {
+        Some(mut statements) => {
+            statements.push(while_loop);
+            with_children(NodeType::BlockExpression, statements)
+        },
+        None => while_loop,
+    }
+}
+
+/// Lowers a folded `DoWhileLoop` to a `WhileLoop` whose condition is checked at the end of the body.
+fn lower_do_while(node: ASTNode) -> ASTNode {
+    let mut node = node;
+    let mut children = std::mem::take(node.get_children_mut());
+    let body = take_child(&mut children, |kind| matches!(kind, NodeType::BlockExpression))
+        .unwrap_or_else(|| ASTNode::new(NodeType::BlockExpression));
+    let condition = condition_or_true(take_child(&mut children, |kind| matches!(kind, NodeType::Condition)));
+
+    let exit_check = with_children(NodeType::IfStatement, vec![
+        with_children(NodeType::Condition, vec![
+            with_children(NodeType::UnaryExpression, vec![ASTNode::new(NodeType::Operator("!".to_string())), condition]),
+        ]),
+        with_children(NodeType::BlockExpression, vec![ASTNode::new(NodeType::Break)]),
+    ]);
+    let continue_with_check = |continue_node: ASTNode| with_children(NodeType::BlockExpression, vec![exit_check.clone(), continue_node]);
+    let body = replace_continues(body, &continue_with_check);
+
+    with_children(NodeType::WhileLoop, vec![
+        with_children(NodeType::Condition, vec![one()]),
+        with_children(NodeType::BlockExpression, vec![body, exit_check]),
+    ])
+}
+
+/// Retrieves the expression of a loop condition, or `1` if the condition is missing or empty.
+fn condition_or_true(condition: Option<ASTNode>) -> ASTNode {
+    condition.and_then(|condition| condition.get_children().into_iter().next()).unwrap_or_else(one)
+}
+
+/// Replaces every `continue` belonging to the current loop, skipping nested loops, whose `continue`s are
+/// their own.
+fn replace_continues(node: ASTNode, replacement: &dyn Fn(ASTNode) -> ASTNode) -> ASTNode {
+    match node.get_node_type_ref() {
+        NodeType::Continue => replacement(node),
+        NodeType::ForLoop | NodeType::WhileLoop | NodeType::DoWhileLoop | NodeType::FunctionDeclaration => node,
+        _ => {
+            let mut node = node;
+            let children = std::mem::take(node.get_children_mut());
+            node.set_children(children.into_iter().map(|child| replace_continues(child, replacement)).collect());
+            node
+        },
+    }
+}
+
+/// Lowers a compound `Assignment` such as `x += v` to `x = x + v`, leaving other nodes unchanged.
+fn lower_compound(node: ASTNode) -> ASTNode {
+    let children = node.get_children();
+    let (target, operator, value) = match children.as_slice() {
+        [target, operator, value] => match operator.get_node_type_ref() {
+            NodeType::Operator(operator) if operator.len() > 1 && operator.ends_with('=') => (target, operator, value),
+            _ => return node,
+        },
+        _ => return node,
+    };
+    let operand = match target.get_node_type_ref() {
+        NodeType::Variable => target.get_children().into_iter().next().unwrap_or_else(|| target.clone()),
+        _ => target.clone(),
+    };
+    let binary_operator = ASTNode::new(NodeType::Operator(operator[..operator.len() - 1].to_string()));
+    let value = match value.get_node_type_ref() {
+        NodeType::AssignedValue => {
+            let inner = value.get_children().into_iter().next().unwrap_or_else(|| value.clone());
+            with_children(NodeType::AssignedValue, vec![with_children(NodeType::BinaryExpression, vec![operand, binary_operator, inner])])
+        },
+        _ => with_children(NodeType::BinaryExpression, vec![operand, binary_operator, value.clone()]),
+    };
+    with_children(NodeType::Assignment, vec![target.clone(), value])
+}
+
+/// Lowers a `++` or `--` `UnaryExpression` to an assignment. Postfix forms are only lowered when `unused` is
+/// set, since their value is the operand before the update.
+fn lower_increment(node: ASTNode, unused: bool) -> ASTNode {
+    if node.get_node_type_ref() != &NodeType::UnaryExpression {
+        return node;
+    }
+    let children = node.get_children();
+    let (operand, operator) = match children.as_slice() {
+        [operator, operand] if is_step(operator) => (operand, operator),
+        [operand, operator] if unused && is_step(operator) => (operand, operator),
+        _ => return node,
+    };
+    let binary_operator = match operator.get_node_type_ref() {
+        NodeType::Operator(operator) => ASTNode::new(NodeType::Operator(operator[..1].to_string())),
+        _ => return node,
+    };
+    with_children(NodeType::Assignment, vec![
+        operand.clone(),
+        with_children(NodeType::BinaryExpression, vec![operand.clone(), binary_operator, one()]),
+    ])
+}
+
+/// Checks whether a node is the `++` or `--` operator.
+fn is_step(node: &ASTNode) -> bool {
+    matches!(node.get_node_type_ref(), NodeType::Operator(operator) if operator == "++" || operator == "--")
+}
@@ -0,0 +1,200 @@
+//! This file computes a structural diff between two `AST`s, reporting the nodes that were inserted, removed or
+//! changed with the `NodePath` of each, so a failing comparison of two large trees points at the differences
+//! instead of printing both trees in full.
+//!
+//! Children are aligned by the longest common subsequence of their kinds. Aligned nodes are compared
+//! recursively; a node whose payload differs is reported as changed, and a node replaced by one of another kind
+//! is reported as changed without descending further. Unaligned children between two aligned ones are paired up
+//! in order as changes, and any left over are reported as removed or inserted.
+
+use std::fmt;
+
+use crate::ast::{
+    core::{ASTNode, AST},
+    node_type::NodeType,
+    path::NodePath,
+};
+
+/// A single difference between two trees.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AstEdit {
+    /// A subtree present only in the new tree, located by its path in the new tree.
+    Inserted {
+        /// The location of the inserted node in the new tree.
+        path: NodePath,
+        /// The inserted subtree.
+        node: ASTNode,
+    },
+    /// A subtree present only in the old tree, located by its path in the old tree.
+    Removed {
+        /// The location of the removed node in the old tree.
+        path: NodePath,
+        /// The removed subtree.
+        node: ASTNode,
+    },
+    /// A node whose type differs between the trees, located by its path in the new tree.
+    Changed {
+        /// The location of the node in the new tree.
+        path: NodePath,
+        /// The node's type in the old tree.
+        old: NodeType,
+        /// The node's type in the new tree.
+        new: NodeType,
+    },
+}
+
+impl AstEdit {
+    /// Retrieves the location of this edit.
+    ///
+    /// # Returns
+    /// Returns the `NodePath` of the edited node.
+    pub fn get_path(&self) -> &NodePath {
+        match self {
+            AstEdit::Inserted { path, .. } | AstEdit::Removed { path, .. } | AstEdit::Changed { path, .. } => path,
+        }
+    }
+}
+
+impl fmt::Display for AstEdit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AstEdit::Inserted { path, node } => write!(f, "+ {}: {}", path, node.get_node_type_ref()),
+            AstEdit::Removed { path, node } => write!(f, "- {}: {}", path, node.get_node_type_ref()),
+            AstEdit::Changed { path, old, new } => write!(f, "~ {}: {} -> {}", path, old, new),
+        }
+    }
+}
+
+/// Computes the edits that turn one `AST` into another.
+///
+/// # Parameters
+/// * `old` - The original tree, such as the expected tree of a test.
+/// * `new` - The modified tree, such as the tree actually produced.
+///
+/// # Returns
+/// Returns the edits in pre-order, or an empty vector if the trees are equal.
+pub fn diff(old: &AST, new: &AST) -> Vec<AstEdit> {
+    diff_nodes(old.get_root_ref(), new.get_root_ref())
+}
+
+/// Computes the edits that turn one subtree into another, with paths relative to the given roots.
+///
+/// # Parameters
+/// * `old` - The root of the original subtree.
+/// * `new` - The root of the modified subtree.
+///
+/// # Returns
+/// Returns the edits in pre-order, or an empty vector if the subtrees are equal.
+pub fn diff_nodes(old: &ASTNode, new: &ASTNode) -> Vec<AstEdit> {
+    let mut edits = Vec::new();
+    compare(old, new, &NodePath::root(old), &NodePath::root(new), &mut edits);
+    edits
+}
+
+/// Formats a list of edits one per line, as printed by `assert_ast_eq`.
+///
+/// # Parameters
+/// * `edits` - The edits to format.
+///
+/// # Returns
+/// Returns the formatted edits as a `String`.
+pub fn format_diff(edits: &[AstEdit]) -> String {
+    edits.iter().map(|edit| format!("{}\n", edit)).collect()
+}
+
+/// Asserts that two `AST`s are equal, panicking with their structural diff if they are not.
+///
+/// # Parameters
+/// * `expected` - The expected tree.
+/// * `actual` - The tree to check.
+///
+/// # Panics
+/// Panics with one line per edit if the trees differ.
+#[track_caller]
+pub fn assert_ast_eq(expected: &AST, actual: &AST) {
+    let edits = diff(expected, actual);
+    if !edits.is_empty() {
+        panic!("ASTs differ ({} edits, expected -> actual):\n{}", edits.len(), format_diff(&edits));
+    }
+}
+
+/// Compares two aligned nodes and their children.
+fn compare(old: &ASTNode, new: &ASTNode, old_path: &NodePath, new_path: &NodePath, edits: &mut Vec<AstEdit>) {
+    let old_type = old.get_node_type_ref();
+    let new_type = new.get_node_type_ref();
+    if old_type != new_type {
+        edits.push(AstEdit::Changed { path: new_path.clone(), old: old_type.clone(), new: new_type.clone() });
+        if old_type.get_kind_name() != new_type.get_kind_name() {
+            return;
+        }
+    }
+    compare_children(old, new, old_path, new_path, edits);
+}
+
+/// Aligns the children of two nodes and reports their differences.
+fn compare_children(old: &ASTNode, new: &ASTNode, old_path: &NodePath, new_path: &NodePath, edits: &mut Vec<AstEdit>) {
+    let old_children = old.get_children_ref();
+    let new_children = new.get_children_ref();
+    let mut pairs = align(old_children, new_children);
+    pairs.push((old_children.len(), new_children.len()));
+
+    let (mut old_index, mut new_index) = (0, 0);
+    for (old_anchor, new_anchor) in pairs {
+        while old_index < old_anchor && new_index < new_anchor {
+            compare(&old_children[old_index], &new_children[new_index],
+                &old_path.child(&old_children[old_index], old_index), &new_path.child(&new_children[new_index], new_index), edits);
+            old_index += 1;
+            new_index += 1;
+        }
+        while old_index < old_anchor {
+            let node = old_children[old_index].clone();
+            edits.push(AstEdit::Removed { path: old_path.child(&node, old_index), node });
+            old_index += 1;
+        }
+        while new_index < new_anchor {
+            let node = new_children[new_index].clone();
+            edits.push(AstEdit::Inserted { path: new_path.child(&node, new_index), node });
+            new_index += 1;
+        }
+        if old_anchor < old_children.len() && new_anchor < new_children.len() {
+            compare(&old_children[old_anchor], &new_children[new_anchor],
+                &old_path.child(&old_children[old_anchor], old_anchor), &new_path.child(&new_children[new_anchor], new_anchor), edits);
+            old_index = old_anchor + 1;
+            new_index = new_anchor + 1;
+        }
+    }
+}
+
+/// Computes the longest common subsequence of two child lists by node kind.
+///
+/// # Returns
+/// Returns the aligned `(old_index, new_index)` pairs in increasing order.
+fn align(old: &[ASTNode], new: &[ASTNode]) -> Vec<(usize, usize)> {
+    let same_kind = |i: usize, j: usize| old[i].get_node_type_ref().get_kind_name() == new[j].get_node_type_ref().get_kind_name();
+
+    let mut lengths = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lengths[i][j] = if same_kind(i, j) {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut pairs = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if same_kind(i, j) {
+            pairs.push((i, j));
+            i += 1;
+            j += 1;
+        } else if lengths[i + 1][j] >= lengths[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    pairs
+}
@@ -0,0 +1,199 @@
+//! This file renders an `AST` or any subtree as a Graphviz DOT graph, which is far easier to read than the
+//! tab-indented `Display` output once a program grows past a single small function.
+//!
+//! The output can be rendered with `dot -Tsvg ast.dot -o ast.svg`.
+
+use crate::ast::{
+    core::{ASTNode, AST},
+    node_type::NodeType,
+};
+
+/// The broad categories that nodes are coloured by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NodeCategory {
+    /// Function, struct, union and enum declarations, and their parameters and fields.
+    Declaration,
+    /// Blocks, branches, loops, jumps and returns.
+    ControlFlow,
+    /// Operators, calls, assignments and initializations.
+    Expression,
+    /// Identifiers, literals, constants and types.
+    Leaf,
+    /// Wrappers that only group other nodes, such as `Condition` or `AssignedValue`.
+    Structural,
+}
+
+/// Options controlling how a DOT graph is rendered.
+///
+/// # Fields
+/// * `name` - The name of the graph.
+/// * `colored` - Whether nodes are filled with a colour according to their `NodeCategory`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DotOptions {
+    name: String,
+    colored: bool,
+}
+
+impl Default for DotOptions {
+    fn default() -> Self {
+        DotOptions { name: "ast".to_string(), colored: false }
+    }
+}
+
+impl DotOptions {
+    /// Creates a new `DotOptions` instance.
+    ///
+    /// # Parameters
+    /// * `name` - The name of the graph.
+    /// * `colored` - Whether nodes are coloured by category.
+    ///
+    /// # Returns
+    /// Returns the new `DotOptions`.
+    pub fn new(name: &str, colored: bool) -> Self {
+        DotOptions { name: name.to_string(), colored }
+    }
+
+    /// Retrieves the name of the graph.
+    ///
+    /// # Returns
+    /// Returns the graph's name as a `&str`.
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Checks whether nodes are coloured by category.
+    ///
+    /// # Returns
+    /// Returns `true` if nodes are coloured, `false` otherwise.
+    pub fn is_colored(&self) -> bool {
+        self.colored
+    }
+}
+
+impl NodeCategory {
+    /// Classifies a node type.
+    ///
+    /// # Parameters
+    /// * `node_type` - The node type to classify.
+    ///
+    /// # Returns
+    /// Returns the node type's `NodeCategory`.
+    pub fn of(node_type: &NodeType) -> NodeCategory {
+        match node_type {
+            NodeType::FunctionDeclaration | NodeType::StructDeclaration | NodeType::UnionDeclaration
+            | NodeType::EnumDeclaration | NodeType::Parameter | NodeType::VariadicParameter | NodeType::Field
+            | NodeType::Variant | NodeType::BitWidth | NodeType::FlexibleArray => NodeCategory::Declaration,
+            NodeType::BlockExpression | NodeType::IfStatement | NodeType::ElseStatement | NodeType::WhileLoop
+            | NodeType::DoWhileLoop | NodeType::ForLoop | NodeType::SwitchStatement | NodeType::Case
+            | NodeType::Default | NodeType::Break | NodeType::Continue | NodeType::Return => NodeCategory::ControlFlow,
+            NodeType::BinaryExpression | NodeType::UnaryExpression | NodeType::FunctionCall | NodeType::Assignment
+            | NodeType::Initialization | NodeType::MemberAccess => NodeCategory::Expression,
+            NodeType::Identifier(_) | NodeType::Literal(_) | NodeType::Constant(_) | NodeType::Operator(_)
+            | NodeType::Type(_) => NodeCategory::Leaf,
+            _ => NodeCategory::Structural,
+        }
+    }
+
+    /// Retrieves the fill colour used for this category.
+    ///
+    /// # Returns
+    /// Returns an X11 colour name understood by Graphviz.
+    pub fn get_color(&self) -> &'static str {
+        match self {
+            NodeCategory::Declaration => "lightblue",
+            NodeCategory::ControlFlow => "lightsalmon",
+            NodeCategory::Expression => "palegreen",
+            NodeCategory::Leaf => "lightyellow",
+            NodeCategory::Structural => "lightgrey",
+        }
+    }
+}
+
+impl AST {
+    /// Renders this `AST` as a Graphviz DOT graph.
+    ///
+    /// # Parameters
+    /// * `options` - The `DotOptions` to render with.
+    ///
+    /// # Returns
+    /// Returns the DOT source as a `String`.
+    pub fn to_dot(&self, options: &DotOptions) -> String {
+        to_dot(&self.get_root(), options)
+    }
+}
+
+/// Renders an `ASTNode` and its descendants as a Graphviz DOT graph. Nodes are numbered in pre-order, and
+/// each is labelled with its `NodeType`, including the payload of identifiers, literals, operators and types.
+///
+/// # Parameters
+/// * `node` - The root of the subtree to render.
+/// * `options` - The `DotOptions` to render with.
+///
+/// # Returns
+/// Returns the DOT source as a `String`.
+///
+/// # Examples
+/// ```
+/// use common::ast::{core::ASTNode, dot::{to_dot, DotOptions}, node_type::NodeType};
+///
+/// let dot = to_dot(&ASTNode::new(NodeType::Break), &DotOptions::default());
+/// assert!(dot.contains("n0 [label=\"Break\"];"));
+/// ```
+pub fn to_dot(node: &ASTNode, options: &DotOptions) -> String {
+    let mut output = format!("digraph {} {{\n", escape_id(options.get_name()));
+    output.push_str("    node [shape=box, fontname=\"monospace\"];\n");
+    let mut next_id: usize = 0;
+    write_node(node, options, &mut next_id, &mut output);
+    output.push_str("}\n");
+    output
+}
+
+/// Writes a node, its edges and its descendants, returning the node's identifier.
+fn write_node(node: &ASTNode, options: &DotOptions, next_id: &mut usize, output: &mut String) -> usize {
+    let id = *next_id;
+    *next_id += 1;
+
+    let node_type = node.get_node_type();
+    let label = escape_label(&node_type.to_string());
+    if options.is_colored() {
+        output.push_str(&format!("    n{} [label=\"{}\", style=filled, fillcolor={}];\n", id, label, NodeCategory::of(&node_type).get_color()));
+    } else {
+        output.push_str(&format!("    n{} [label=\"{}\"];\n", id, label));
+    }
+
+    for child in node.get_children().iter() {
+        let child_id = write_node(child, options, next_id, output);
+        output.push_str(&format!("    n{} -> n{};\n", id, child_id));
+    }
+    id
+}
+
+/// Escapes a label for use inside a double-quoted DOT string.
+fn escape_label(label: &str) -> String {
+    let mut escaped = String::new();
+    for c in label.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// The DOT keywords, which are matched case-insensitively and cannot be used as unquoted identifiers.
+const KEYWORDS: [&str; 6] = ["node", "edge", "graph", "digraph", "subgraph", "strict"];
+
+/// Quotes a graph name unless it is already a plain DOT identifier that is not a keyword.
+fn escape_id(name: &str) -> String {
+    let is_plain = !name.is_empty()
+        && !name.starts_with(|c: char| c.is_ascii_digit())
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !KEYWORDS.iter().any(|keyword| keyword.eq_ignore_ascii_case(name));
+    if is_plain {
+        name.to_string()
+    } else {
+        format!("\"{}\"", escape_label(name))
+    }
+}
@@ -1,11 +1,41 @@
 //! The `AST` module in the `common` crate encompasses structures and utilities that define and manipulate
 //! the Abstract Syntax Tree (AST) used in SLICC.
 
+/// Defines an arena-backed AST addressed by stable node ids.
+pub mod arena;
+
 /// Defines the core structures of the AST.
 pub mod core;
 
 /// Defines data types used in the AST.
 pub mod data_type;
 
+/// Lowers loops and compound operators to a smaller core language.
+pub mod desugar;
+
+/// Computes structural differences between ASTs.
+pub mod diff;
+
+/// Renders an AST as a Graphviz DOT graph.
+pub mod dot;
+
 /// Defines different types of syntax elements.
 pub mod node_type;
+
+/// Locates nodes of an AST by the path from its root.
+pub mod path;
+
+/// Prints an AST back into formatted C source code.
+pub mod printer;
+
+/// Selects AST nodes with descendant, child and predicate steps.
+pub mod query;
+
+/// Defines a strongly typed view of the AST with conversions to and from `ASTNode`.
+pub mod typed;
+
+/// Checks that AST nodes follow the child-ordering conventions.
+pub mod validate;
+
+/// Defines traits for traversing and transforming an AST.
+pub mod visit;
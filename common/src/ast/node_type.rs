@@ -8,6 +8,7 @@ use std::fmt;
 /// Each element A different kind of syntactic construct that can appear in source code, such as constants, identifiers, 
 /// operators, and control structures. These elements are used to build a tree representation of the code's syntactic structure.
 #[derive(Debug, Clone, PartialEq, Default, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NodeType {
     /// A lack of expression, often used as a placeholder.
     #[default]
@@ -76,6 +77,9 @@ pub enum NodeType {
     /// An enum declaration.
     EnumDeclaration,
 
+    /// A union declaration, whose fields share a single storage location.
+    UnionDeclaration,
+
     /// A module-level expression, often used for scoping.
     ModuleExpression,
 
@@ -100,10 +104,23 @@ pub enum NodeType {
     /// A field in a struct or similar data structure.
     Field,
 
+    /// The width in bits of a struct field declared as a bit-field, such as `flags: unsigned : 3`.
+    BitWidth,
+
+    /// A marker on a trailing struct field declared as a flexible array member, such as `data: char[]`.
+    FlexibleArray,
+
+    /// An access of a struct or union member, such as `packet.header`.
+    MemberAccess,
+
     /// A parameter in function declarations.
     Parameter,
 
-    /// A variable, used in various expressions and statements.
+    /// A trailing `...` in a function declaration's parameter list, marking the function as variadic.
+    VariadicParameter,
+
+    /// A variable, used in various expressions and statements. A variable of struct or union type carries the
+    /// name of the struct or union as an `Identifier` after its `Type`.
     Variable,
 
     /// A binary expression, involving two operands and an operator.
@@ -125,6 +142,79 @@ pub enum NodeType {
     LoopIncrement,
 }
 
+impl NodeType {
+    /// Retrieves the name of this node type's kind, without any payload.
+    ///
+    /// # Returns
+    ///
+    /// Returns the variant's name, such as `"Identifier"` for `NodeType::Identifier("x")`.
+    pub fn get_kind_name(&self) -> &'static str {
+        match self {
+            NodeType::NoExpression => "NoExpression",
+            NodeType::Literal(_) => "Literal",
+            NodeType::Identifier(_) => "Identifier",
+            NodeType::Operator(_) => "Operator",
+            NodeType::Type(_) => "Type",
+            NodeType::Constant(_) => "Constant",
+            NodeType::IfStatement => "IfStatement",
+            NodeType::ElseStatement => "ElseStatement",
+            NodeType::ForLoop => "ForLoop",
+            NodeType::WhileLoop => "WhileLoop",
+            NodeType::DoWhileLoop => "DoWhileLoop",
+            NodeType::Break => "Break",
+            NodeType::Continue => "Continue",
+            NodeType::Return => "Return",
+            NodeType::SwitchStatement => "SwitchStatement",
+            NodeType::Case => "Case",
+            NodeType::Default => "Default",
+            NodeType::Assignment => "Assignment",
+            NodeType::Initialization => "Initialization",
+            NodeType::FunctionDeclaration => "FunctionDeclaration",
+            NodeType::StructDeclaration => "StructDeclaration",
+            NodeType::EnumDeclaration => "EnumDeclaration",
+            NodeType::UnionDeclaration => "UnionDeclaration",
+            NodeType::ModuleExpression => "ModuleExpression",
+            NodeType::TopLevelExpression => "TopLevelExpression",
+            NodeType::BlockExpression => "BlockExpression",
+            NodeType::Condition => "Condition",
+            NodeType::Action => "Action",
+            NodeType::Variant => "Variant",
+            NodeType::AssignedValue => "AssignedValue",
+            NodeType::Field => "Field",
+            NodeType::BitWidth => "BitWidth",
+            NodeType::FlexibleArray => "FlexibleArray",
+            NodeType::MemberAccess => "MemberAccess",
+            NodeType::Parameter => "Parameter",
+            NodeType::VariadicParameter => "VariadicParameter",
+            NodeType::Variable => "Variable",
+            NodeType::BinaryExpression => "BinaryExpression",
+            NodeType::UnaryExpression => "UnaryExpression",
+            NodeType::FunctionCall => "FunctionCall",
+            NodeType::Operand => "Operand",
+            NodeType::LoopInitializer => "LoopInitializer",
+            NodeType::LoopIncrement => "LoopIncrement",
+        }
+    }
+
+    /// Retrieves every kind name, in declaration order.
+    ///
+    /// # Returns
+    ///
+    /// Returns the names of all `NodeType` variants.
+    pub fn get_kind_names() -> &'static [&'static str] {
+        &[
+            "NoExpression", "Literal", "Identifier", "Operator", "Type", "Constant",
+            "IfStatement", "ElseStatement", "ForLoop", "WhileLoop", "DoWhileLoop", "Break",
+            "Continue", "Return", "SwitchStatement", "Case", "Default", "Assignment",
+            "Initialization", "FunctionDeclaration", "StructDeclaration", "EnumDeclaration", "UnionDeclaration", "ModuleExpression",
+            "TopLevelExpression", "BlockExpression", "Condition", "Action", "Variant", "AssignedValue",
+            "Field", "BitWidth", "FlexibleArray", "MemberAccess", "Parameter", "VariadicParameter",
+            "Variable", "BinaryExpression", "UnaryExpression", "FunctionCall", "Operand", "LoopInitializer",
+            "LoopIncrement",
+        ]
+    }
+}
+
 /// Provides a display implementation for `DataType`.
 ///
 /// # Parameters
@@ -0,0 +1,87 @@
+//! This file defines `NodePath`, which locates a node in an `AST` by the kinds and child positions of the
+//! nodes leading to it, and is how the validator and other tree tools point at a node in their reports.
+//!
+//! A path is printed as `root` followed by one `Kind[index]` segment per node from the root down, where
+//! `index` is the node's position among its parent's children and is always `0` for the root, for example
+//! `root/TopLevelExpression[0]/FunctionDeclaration[0]/BlockExpression[3]`.
+
+use std::fmt;
+
+use crate::ast::core::{ASTNode, AST};
+
+/// The location of a node in an `AST`.
+///
+/// # Fields
+/// * `segments` - The kind name and child index of each node from the root down to the located node.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct NodePath {
+    segments: Vec<(&'static str, usize)>,
+}
+
+impl NodePath {
+    /// Creates the path of a root node.
+    ///
+    /// # Parameters
+    /// * `root` - The root node.
+    ///
+    /// # Returns
+    /// Returns a `NodePath` with a single segment.
+    pub fn root(root: &ASTNode) -> Self {
+        NodePath { segments: vec![(root.get_node_type_ref().get_kind_name(), 0)] }
+    }
+
+    /// Extends this path to one of the located node's children.
+    ///
+    /// # Parameters
+    /// * `child` - The child node.
+    /// * `index` - The child's position among its parent's children.
+    ///
+    /// # Returns
+    /// Returns a new `NodePath` locating the child.
+    pub fn child(&self, child: &ASTNode, index: usize) -> Self {
+        let mut segments = self.segments.clone();
+        segments.push((child.get_node_type_ref().get_kind_name(), index));
+        NodePath { segments }
+    }
+
+    /// Retrieves the kind name and child index of each node on this path.
+    ///
+    /// # Returns
+    /// Returns the segments from the root down.
+    pub fn get_segments(&self) -> &[(&'static str, usize)] {
+        &self.segments
+    }
+
+    /// Retrieves the child indices to follow from the root to reach the located node.
+    ///
+    /// # Returns
+    /// Returns one index per segment after the root.
+    pub fn get_indices(&self) -> Vec<usize> {
+        self.segments.iter().skip(1).map(|(_, index)| *index).collect()
+    }
+
+    /// Finds the node this path locates in an `AST`.
+    ///
+    /// # Parameters
+    /// * `ast` - The `AST` to search.
+    ///
+    /// # Returns
+    /// Returns `Some(&ASTNode)` if every index on the path exists, `None` otherwise.
+    pub fn resolve<'a>(&self, ast: &'a AST) -> Option<&'a ASTNode> {
+        let mut current = ast.get_root_ref();
+        for index in self.get_indices() {
+            current = current.get_children_ref().get(index)?;
+        }
+        Some(current)
+    }
+}
+
+impl fmt::Display for NodePath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "root")?;
+        for (kind, index) in &self.segments {
+            write!(f, "/{}[{}]", kind, index)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,577 @@
+//! This file turns an `AST` back into formatted C source code.
+//!
+//! The printer follows the same child-ordering conventions as the parser, and chooses the minimal set of
+//! parentheses for expressions from C's operator precedence, so printing a parsed program and parsing the
+//! result again yields the same tree. Two shapes are only distinguished by the position of their operator:
+//! a `UnaryExpression` whose `Operator` child comes last is a postfix expression such as `i++`, and an
+//! `Assignment` with an `Operator` child between its target and value is a compound assignment such as `x += 1`.
+//!
+//! Struct, union and enum declarations are printed in the parser's grammar for them rather than in C's: the body
+//! sits between the `LBRACE` and `RBRACE` tokens, which the lexer reads from `[` and `]`, and each field is
+//! written as `name: type`, optionally followed by ` : width` for a bit-field or `[]` for a flexible array
+//! member, with commas between fields.
+
+use crate::{
+    ast::{
+        core::{ASTNode, AST},
+        data_type::DataType,
+        node_type::NodeType,
+    },
+    error::ErrorType,
+};
+
+/// The string used for one level of indentation.
+const INDENT: &str = "    ";
+
+/// The precedence of assignments, the loosest binding expressions.
+const ASSIGNMENT_PRECEDENCE: u8 = 1;
+
+/// The precedence of prefix unary expressions.
+const UNARY_PRECEDENCE: u8 = 12;
+
+/// The precedence of postfix expressions, member accesses, calls and primary expressions.
+const POSTFIX_PRECEDENCE: u8 = 13;
+
+/// The `Printer` struct models the process of turning an `AST` into C source code.
+///
+/// # Fields
+/// * `output` - The source code printed so far.
+/// * `depth` - The current indentation depth.
+struct Printer {
+    output: String,
+    depth: usize,
+}
+
+impl AST {
+    /// Prints this `AST` as formatted C source code.
+    ///
+    /// # Returns
+    ///
+    /// Returns the C source code as a `String`, or an `ErrorType` if a node does not follow the expected
+    /// child-ordering conventions.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if a node is missing a required child or cannot be expressed in C.
+    pub fn to_c_source(&self) -> Result<String, ErrorType> {
+        print_node(&self.get_root())
+    }
+}
+
+/// Prints an `ASTNode` and its children as formatted C source code. Module and top-level expressions
+/// print each of their children as a top-level item; any other node prints as a single statement.
+///
+/// # Parameters
+/// * `node` - The node to print.
+///
+/// # Returns
+/// Returns the C source code as a `String`, or an `ErrorType` if the node cannot be printed.
+///
+/// # Errors
+/// * Returns an error if a node is missing a required child or cannot be expressed in C.
+pub fn print_node(node: &ASTNode) -> Result<String, ErrorType> {
+    let mut printer = Printer { output: String::new(), depth: 0 };
+    match node.get_node_type() {
+        NodeType::ModuleExpression | NodeType::TopLevelExpression => {
+            let mut previous_was_declaration = false;
+            for (index, child) in node.get_children().iter().enumerate() {
+                let is_declaration = is_declaration(child);
+                if index > 0 && (is_declaration || previous_was_declaration) {
+                    printer.output.push('\n');
+                }
+                printer.print_statement(child)?;
+                previous_was_declaration = is_declaration;
+            }
+        },
+        _ => printer.print_statement(node)?,
+    }
+    Ok(printer.output)
+}
+
+/// Prints an expression node as C source code, with the minimal parentheses required by precedence.
+///
+/// # Parameters
+/// * `node` - The expression node to print.
+///
+/// # Returns
+/// Returns the expression's source code as a `String`, or an `ErrorType` if it cannot be printed.
+///
+/// # Errors
+/// * Returns an error if the node is not an expression or is missing a required child.
+pub fn print_expression(node: &ASTNode) -> Result<String, ErrorType> {
+    expression(node)
+}
+
+/// Retrieves the C spelling of a data type.
+///
+/// # Parameters
+/// * `data_type` - The data type to spell.
+///
+/// # Returns
+/// Returns the type's C keyword, or an `ErrorType` if the type has no standalone spelling.
+///
+/// # Errors
+/// * Returns an error for aggregate and function types, whose names are not recorded in a `Type` node.
+pub fn type_name(data_type: &DataType) -> Result<&'static str, ErrorType> {
+    match data_type {
+        DataType::Integer => Ok("int"),
+        DataType::Float => Ok("float"),
+        DataType::Double => Ok("double"),
+        DataType::Long => Ok("long"),
+        DataType::Boolean => Ok("bool"),
+        DataType::String => Ok("char *"),
+        DataType::Char => Ok("char"),
+        DataType::Void => Ok("void"),
+        DataType::Unsign => Ok("unsigned"),
+        DataType::Sign => Ok("signed"),
+        DataType::Function | DataType::Struct | DataType::Enum | DataType::Union | DataType::None => Err(ErrorType::DevError {
+            message: format!("Type {} has no standalone C spelling", data_type),
+        }),
+    }
+}
+
+/// Retrieves the precedence of a binary operator, higher values binding tighter.
+///
+/// # Parameters
+/// * `operator` - The operator's spelling.
+///
+/// # Returns
+/// Returns `Some(u8)` with the operator's precedence, or `None` if it is not a binary operator.
+pub fn binary_precedence(operator: &str) -> Option<u8> {
+    match operator {
+        "||" => Some(2),
+        "&&" => Some(3),
+        "|" => Some(4),
+        "^" => Some(5),
+        "&" => Some(6),
+        "==" | "!=" => Some(7),
+        "<" | ">" | "<=" | ">=" => Some(8),
+        "<<" | ">>" => Some(9),
+        "+" | "-" => Some(10),
+        "*" | "/" | "%" => Some(11),
+        _ => None,
+    }
+}
+
+/// Checks whether a node is a declaration that is set apart from its neighbours by a blank line.
+fn is_declaration(node: &ASTNode) -> bool {
+    matches!(node.get_node_type(), NodeType::FunctionDeclaration | NodeType::StructDeclaration | NodeType::UnionDeclaration | NodeType::EnumDeclaration)
+}
+
+/// Builds the error reported for a node that does not follow the child-ordering conventions.
+fn malformed(node: &ASTNode, expected: &str) -> ErrorType {
+    ErrorType::DevError {
+        message: format!("Cannot print {}: expected {}", node.get_node_type(), expected),
+    }
+}
+
+/// Retrieves the name held by an `Identifier` or `Literal` node.
+fn name_of(node: &ASTNode) -> Option<String> {
+    match node.get_node_type() {
+        NodeType::Identifier(name) | NodeType::Literal(name) => Some(name),
+        _ => None,
+    }
+}
+
+/// Retrieves the expression wrapped by a `Condition`, `AssignedValue` or `Operand` node, or the node itself.
+fn unwrap_value(node: &ASTNode) -> Result<ASTNode, ErrorType> {
+    match node.get_node_type() {
+        NodeType::Condition | NodeType::AssignedValue | NodeType::Operand => {
+            node.get_children().first().cloned().ok_or_else(|| malformed(node, "a wrapped expression"))
+        },
+        _ => Ok(node.clone()),
+    }
+}
+
+/// Retrieves the precedence of an expression node.
+fn precedence(node: &ASTNode) -> u8 {
+    match node.get_node_type() {
+        NodeType::Assignment => ASSIGNMENT_PRECEDENCE,
+        NodeType::BinaryExpression => node.get_children().get(1)
+            .and_then(|operator| match operator.get_node_type() {
+                NodeType::Operator(op) => binary_precedence(&op),
+                _ => None,
+            })
+            .unwrap_or(POSTFIX_PRECEDENCE),
+        NodeType::UnaryExpression => match node.get_children().first().map(|child| child.get_node_type()) {
+            Some(NodeType::Operator(_)) => UNARY_PRECEDENCE,
+            _ => POSTFIX_PRECEDENCE,
+        },
+        NodeType::Condition | NodeType::AssignedValue | NodeType::Operand => {
+            unwrap_value(node).map(|inner| precedence(&inner)).unwrap_or(POSTFIX_PRECEDENCE)
+        },
+        _ => POSTFIX_PRECEDENCE,
+    }
+}
+
+/// Prints an expression, wrapping it in parentheses if it binds looser than `minimum`.
+fn expression_at(node: &ASTNode, minimum: u8) -> Result<String, ErrorType> {
+    let printed = expression(node)?;
+    if precedence(node) < minimum {
+        Ok(format!("({})", printed))
+    } else {
+        Ok(printed)
+    }
+}
+
+/// Prints the target of an assignment, which is an identifier, a `Variable` or a member access.
+fn assignment_target(node: &ASTNode) -> Result<String, ErrorType> {
+    match node.get_node_type() {
+        NodeType::Variable => node.get_children().first()
+            .and_then(name_of)
+            .ok_or_else(|| malformed(node, "an Identifier child")),
+        _ => expression_at(node, UNARY_PRECEDENCE),
+    }
+}
+
+/// Prints an expression node without surrounding parentheses.
+fn expression(node: &ASTNode) -> Result<String, ErrorType> {
+    let children = node.get_children();
+    match node.get_node_type() {
+        NodeType::Literal(value) | NodeType::Constant(value) | NodeType::Identifier(value) => Ok(value),
+        NodeType::Type(data_type) => type_name(&data_type).map(|name| name.to_string()),
+        NodeType::Condition | NodeType::AssignedValue | NodeType::Operand => expression(&unwrap_value(node)?),
+        NodeType::Variable => assignment_target(node),
+        NodeType::BinaryExpression => {
+            let (left, operator, right) = match children.as_slice() {
+                [left, operator, right] => (left, operator, right),
+                _ => return Err(malformed(node, "an operand, an Operator and an operand")),
+            };
+            let operator = match operator.get_node_type() {
+                NodeType::Operator(op) => op,
+                _ => return Err(malformed(node, "an Operator as its second child")),
+            };
+            let operator_precedence = binary_precedence(&operator).ok_or_else(|| malformed(node, "a binary operator"))?;
+            Ok(format!("{} {} {}", expression_at(left, operator_precedence)?, operator, expression_at(right, operator_precedence + 1)?))
+        },
+        NodeType::UnaryExpression => match children.as_slice() {
+            [operator, operand] if matches!(operator.get_node_type(), NodeType::Operator(_)) => {
+                let operator = name_of_operator(operator);
+                let operand = expression_at(operand, UNARY_PRECEDENCE)?;
+                let separator = if (operator.ends_with('-') && operand.starts_with('-')) || (operator.ends_with('+') && operand.starts_with('+')) { " " } else { "" };
+                Ok(format!("{}{}{}", operator, separator, operand))
+            },
+            [operand, operator] if matches!(operator.get_node_type(), NodeType::Operator(_)) => {
+                Ok(format!("{}{}", expression_at(operand, POSTFIX_PRECEDENCE)?, name_of_operator(operator)))
+            },
+            _ => Err(malformed(node, "an Operator and an operand")),
+        },
+        NodeType::Assignment => match children.as_slice() {
+            [target, value] => Ok(format!("{} = {}", assignment_target(target)?, expression_at(value, ASSIGNMENT_PRECEDENCE)?)),
+            [target, operator, value] if matches!(operator.get_node_type(), NodeType::Operator(_)) => {
+                Ok(format!("{} {} {}", assignment_target(target)?, name_of_operator(operator), expression_at(value, ASSIGNMENT_PRECEDENCE)?))
+            },
+            _ => Err(malformed(node, "a target and a value")),
+        },
+        NodeType::FunctionCall => {
+            let (callee, arguments) = children.split_first().ok_or_else(|| malformed(node, "an Identifier child"))?;
+            let callee = name_of(callee).ok_or_else(|| malformed(node, "an Identifier as its first child"))?;
+            let arguments = arguments.iter()
+                .map(|argument| expression_at(argument, ASSIGNMENT_PRECEDENCE + 1))
+                .collect::<Result<Vec<String>, ErrorType>>()?;
+            Ok(format!("{}({})", callee, arguments.join(", ")))
+        },
+        NodeType::MemberAccess => match children.as_slice() {
+            [base, member] => {
+                let member = name_of(member).ok_or_else(|| malformed(node, "an Identifier as its member"))?;
+                Ok(format!("{}.{}", expression_at(base, POSTFIX_PRECEDENCE)?, member))
+            },
+            _ => Err(malformed(node, "a base and a member")),
+        },
+        _ => Err(malformed(node, "an expression")),
+    }
+}
+
+/// Retrieves the spelling of an `Operator` node.
+fn name_of_operator(node: &ASTNode) -> String {
+    match node.get_node_type() {
+        NodeType::Operator(op) => op,
+        _ => String::new(),
+    }
+}
+
+impl Printer {
+    /// Appends a line at the current indentation.
+    fn line(&mut self, text: &str) {
+        self.output.push_str(&INDENT.repeat(self.depth));
+        self.output.push_str(text);
+        self.output.push('\n');
+    }
+
+    /// Appends the statements of a block between braces, the opening brace ending `header`.
+    fn braced(&mut self, header: &str, block: Option<&ASTNode>, trailer: &str) -> Result<(), ErrorType> {
+        self.line(&format!("{}{{", if header.is_empty() { String::new() } else { format!("{} ", header) }));
+        self.depth += 1;
+        if let Some(block) = block {
+            for statement in block.get_children().iter() {
+                self.print_statement(statement)?;
+            }
+        }
+        self.depth -= 1;
+        self.line(&format!("}}{}", trailer));
+        Ok(())
+    }
+
+    /// Prints a clause of a statement such as a loop initializer, without its trailing semicolon.
+    fn clause(&self, node: Option<&ASTNode>) -> Result<String, ErrorType> {
+        let inner = match node {
+            Some(node) => match node.get_children().first() {
+                Some(inner) => inner.clone(),
+                None => return Ok(String::new()),
+            },
+            None => return Ok(String::new()),
+        };
+        match inner.get_node_type() {
+            NodeType::Initialization => initialization(&inner),
+            _ => expression(&inner),
+        }
+    }
+
+    /// Prints a node in statement position.
+    fn print_statement(&mut self, node: &ASTNode) -> Result<(), ErrorType> {
+        let children = node.get_children();
+        let find = |kind: fn(&NodeType) -> bool| children.iter().find(|child| kind(&child.get_node_type()));
+
+        match node.get_node_type() {
+            NodeType::NoExpression => Ok(()),
+            NodeType::Break => {
+                self.line("break;");
+                Ok(())
+            },
+            NodeType::Continue => {
+                self.line("continue;");
+                Ok(())
+            },
+            NodeType::Return => {
+                match children.first() {
+                    Some(value) => {
+                        let value = expression(value)?;
+                        self.line(&format!("return {};", value));
+                    },
+                    None => self.line("return;"),
+                }
+                Ok(())
+            },
+            NodeType::Initialization => {
+                let text = initialization(node)?;
+                self.line(&format!("{};", text));
+                Ok(())
+            },
+            NodeType::BlockExpression => self.braced("", Some(node), ""),
+            NodeType::IfStatement => self.print_if(node, ""),
+            NodeType::WhileLoop => {
+                let condition = find(|kind| matches!(kind, NodeType::Condition)).ok_or_else(|| malformed(node, "a Condition child"))?;
+                let header = format!("while ({})", expression(condition)?);
+                match find(|kind| matches!(kind, NodeType::BlockExpression)) {
+                    Some(block) => self.braced(&header, Some(block), ""),
+                    None => {
+                        self.line(&format!("{};", header));
+                        Ok(())
+                    },
+                }
+            },
+            NodeType::DoWhileLoop => {
+                let condition = find(|kind| matches!(kind, NodeType::Condition)).ok_or_else(|| malformed(node, "a Condition child"))?;
+                let trailer = format!(" while ({});", expression(condition)?);
+                self.braced("do", find(|kind| matches!(kind, NodeType::BlockExpression)), &trailer)
+            },
+            NodeType::ForLoop => {
+                let initializer = self.clause(find(|kind| matches!(kind, NodeType::LoopInitializer)))?;
+                let condition = match find(|kind| matches!(kind, NodeType::Condition)) {
+                    Some(condition) if !condition.get_children().is_empty() => expression(condition)?,
+                    _ => String::new(),
+                };
+                let increment = self.clause(find(|kind| matches!(kind, NodeType::LoopIncrement)))?;
+                let header = format!("for ({}; {}; {})", initializer, condition, increment);
+                self.braced(&header, find(|kind| matches!(kind, NodeType::BlockExpression)), "")
+            },
+            NodeType::SwitchStatement => self.print_switch(node),
+            NodeType::FunctionDeclaration => self.print_function(node),
+            NodeType::StructDeclaration => self.print_aggregate(node, "struct"),
+            NodeType::UnionDeclaration => self.print_aggregate(node, "union"),
+            NodeType::EnumDeclaration => {
+                let name = children.first().and_then(name_of).ok_or_else(|| malformed(node, "an Identifier child"))?;
+                self.line(&format!("enum {} [", name));
+                self.depth += 1;
+                let variants: Vec<&ASTNode> = children.iter().skip(1).collect();
+                for (index, variant) in variants.iter().enumerate() {
+                    let variant_name = variant.get_children().first().and_then(name_of).ok_or_else(|| malformed(variant, "an Identifier child"))?;
+                    let separator = if index + 1 < variants.len() { "," } else { "" };
+                    self.line(&format!("{}{}", variant_name, separator));
+                }
+                self.depth -= 1;
+                self.line("];");
+                Ok(())
+            },
+            NodeType::ModuleExpression | NodeType::TopLevelExpression => {
+                for child in children.iter() {
+                    self.print_statement(child)?;
+                }
+                Ok(())
+            },
+            _ => {
+                let text = expression(node)?;
+                self.line(&format!("{};", text));
+                Ok(())
+            },
+        }
+    }
+
+    /// Prints an if statement, with `prefix` placed before the `if` keyword of `else if` chains.
+    fn print_if(&mut self, node: &ASTNode, prefix: &str) -> Result<(), ErrorType> {
+        let children = node.get_children();
+        let condition = children.iter().find(|child| child.get_node_type() == NodeType::Condition).ok_or_else(|| malformed(node, "a Condition child"))?;
+        let then_block = children.iter().find(|child| child.get_node_type() == NodeType::BlockExpression);
+        let else_branch = children.iter().find(|child| child.get_node_type() == NodeType::ElseStatement);
+
+        let header = format!("{}if ({})", prefix, expression(condition)?);
+        match else_branch {
+            None => self.braced(&header, then_block, ""),
+            Some(else_branch) => {
+                self.line(&format!("{} {{", header));
+                self.depth += 1;
+                if let Some(block) = then_block {
+                    for statement in block.get_children().iter() {
+                        self.print_statement(statement)?;
+                    }
+                }
+                self.depth -= 1;
+                let else_body = else_branch.get_children().first().cloned().ok_or_else(|| malformed(else_branch, "a BlockExpression or IfStatement child"))?;
+                match else_body.get_node_type() {
+                    NodeType::IfStatement => self.print_if(&else_body, "} else "),
+                    _ => self.braced("} else", Some(&else_body), ""),
+                }
+            },
+        }
+    }
+
+    /// Prints a switch statement, whose cases print their statements without braces.
+    fn print_switch(&mut self, node: &ASTNode) -> Result<(), ErrorType> {
+        let children = node.get_children();
+        let (scrutinee, rest) = children.split_first().ok_or_else(|| malformed(node, "an expression child"))?;
+        let cases: Vec<ASTNode> = match rest.first() {
+            Some(block) if block.get_node_type() == NodeType::BlockExpression => block.get_children(),
+            _ => rest.to_vec(),
+        };
+
+        self.line(&format!("switch ({}) {{", expression(scrutinee)?));
+        for case in cases.iter() {
+            let case_children = case.get_children();
+            let (label, body) = match case.get_node_type() {
+                NodeType::Case => {
+                    let value = case_children.first().ok_or_else(|| malformed(case, "a value child"))?;
+                    (format!("case {}:", expression(value)?), case_children.get(1))
+                },
+                NodeType::Default => ("default:".to_string(), case_children.first()),
+                _ => return Err(malformed(node, "Case and Default children")),
+            };
+            self.line(&label);
+            self.depth += 1;
+            if let Some(body) = body {
+                for statement in body.get_children().iter() {
+                    self.print_statement(statement)?;
+                }
+            }
+            self.depth -= 1;
+        }
+        self.line("}");
+        Ok(())
+    }
+
+    /// Prints a function declaration, or a prototype if it has no body.
+    fn print_function(&mut self, node: &ASTNode) -> Result<(), ErrorType> {
+        let children = node.get_children();
+        let name = children.first().and_then(name_of).ok_or_else(|| malformed(node, "an Identifier child"))?;
+        let return_type = children.iter()
+            .find_map(|child| match child.get_node_type() {
+                NodeType::Type(data_type) => Some(data_type),
+                _ => None,
+            })
+            .ok_or_else(|| malformed(node, "a Type child"))?;
+
+        let mut parameters: Vec<String> = Vec::new();
+        for child in children.iter() {
+            match child.get_node_type() {
+                NodeType::Parameter => parameters.push(declarator(child)?),
+                NodeType::VariadicParameter => parameters.push("...".to_string()),
+                _ => {},
+            }
+        }
+
+        let header = format!("{} {}({})", type_name(&return_type)?, name, parameters.join(", "));
+        match children.iter().find(|child| child.get_node_type() == NodeType::BlockExpression) {
+            Some(body) => self.braced(&header, Some(body), ""),
+            None => {
+                self.line(&format!("{};", header));
+                Ok(())
+            },
+        }
+    }
+
+    /// Prints a struct or union declaration and its fields, as `name: type` pairs separated by commas.
+    fn print_aggregate(&mut self, node: &ASTNode, keyword: &str) -> Result<(), ErrorType> {
+        let children = node.get_children();
+        let name = children.first().and_then(name_of).ok_or_else(|| malformed(node, "an Identifier child"))?;
+        self.line(&format!("{} {} [", keyword, name));
+        self.depth += 1;
+        let fields = &children[1..];
+        for (index, field) in fields.iter().enumerate() {
+            let field_name = field.get_children().first().and_then(name_of).ok_or_else(|| malformed(field, "a name child"))?;
+            let data_type = match field.get_children().get(1).map(|child| child.get_node_type()) {
+                Some(NodeType::Type(data_type)) => data_type,
+                _ => return Err(malformed(field, "a Type as its second child")),
+            };
+            let mut text = format!("{}: {}", field_name, type_name(&data_type)?);
+            for marker in field.get_children().iter().skip(2) {
+                match marker.get_node_type() {
+                    NodeType::BitWidth => {
+                        let width = marker.get_children().first().map(expression).transpose()?.ok_or_else(|| malformed(marker, "a width child"))?;
+                        text.push_str(&format!(" : {}", width));
+                    },
+                    NodeType::FlexibleArray => text.push_str("[]"),
+                    _ => return Err(malformed(field, "a BitWidth or FlexibleArray marker")),
+                }
+            }
+            let separator = if index + 1 < fields.len() { "," } else { "" };
+            self.line(&format!("{}{}", text, separator));
+        }
+        self.depth -= 1;
+        self.line("];");
+        Ok(())
+    }
+}
+
+/// Prints a `name, type` pair such as a parameter, field or variable as a C declarator like `int x`, or like
+/// `struct Point p` for a variable naming its struct or union.
+fn declarator(node: &ASTNode) -> Result<String, ErrorType> {
+    let children = node.get_children();
+    let name = children.first().and_then(name_of).ok_or_else(|| malformed(node, "a name child"))?;
+    let data_type = match children.get(1).map(|child| child.get_node_type()) {
+        Some(NodeType::Type(data_type)) => data_type,
+        _ => return Err(malformed(node, "a Type as its second child")),
+    };
+    let type_name = match (data_type, children.get(2).and_then(name_of)) {
+        (DataType::Struct, Some(record)) => format!("struct {}", record),
+        (DataType::Union, Some(record)) => format!("union {}", record),
+        _ => type_name(&data_type)?.to_string(),
+    };
+    if type_name.ends_with('*') {
+        Ok(format!("{}{}", type_name, name))
+    } else {
+        Ok(format!("{} {}", type_name, name))
+    }
+}
+
+/// Prints an initialization such as `int x = 5`, without its trailing semicolon.
+fn initialization(node: &ASTNode) -> Result<String, ErrorType> {
+    let children = node.get_children();
+    let (declared, value) = match children.first().map(|child| child.get_node_type()) {
+        Some(NodeType::Variable) => (declarator(&children[0])?, children.get(1)),
+        Some(NodeType::Identifier(_)) => (declarator(node)?, children.get(2)),
+        _ => return Err(malformed(node, "a Variable child")),
+    };
+    match value {
+        Some(value) => Ok(format!("{} = {}", declared, expression_at(value, ASSIGNMENT_PRECEDENCE)?)),
+        None => Ok(declared),
+    }
+}
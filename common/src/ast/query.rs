@@ -0,0 +1,431 @@
+//! This file defines `Query`, a small selector language for finding nodes in an `AST` without hand-writing a
+//! recursive walk.
+//!
+//! A query is a sequence of steps. Each step names a node kind, or `*` for any kind, and may carry predicates in
+//! square brackets. Steps are separated by whitespace, which selects descendants of the nodes matched so far, or
+//! by `>`, which selects their direct children. For example, all assignments to `i` nested in a while loop are
+//! selected by `WhileLoop Assignment[identifier=i]`, and the parameters of functions by
+//! `FunctionDeclaration > Parameter`. The supported predicates are:
+//!
+//! * `identifier=NAME` - the node is `Identifier(NAME)`, or its first identifier child, possibly inside a
+//!   `Variable`, is `NAME`.
+//! * `value=TEXT` - the node's payload, as for a `Literal`, `Operator` or `Constant`, is `TEXT`.
+//! * `has=Kind` - the node has a direct child of the given kind.
+//!
+//! Queries can also be built with `Query::descendants`, `Query::children` and the predicate methods, which
+//! additionally accept arbitrary functions as filters.
+
+use std::fmt;
+
+use crate::{
+    ast::{
+        core::{ASTNode, AST},
+        node_type::NodeType,
+        path::NodePath,
+    },
+    error::ErrorType,
+};
+
+/// How a step relates to the nodes matched by the previous step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    /// Any descendant. For the first step, the root itself or any descendant.
+    Descendant,
+    /// A direct child. For the first step, a child of the root.
+    Child,
+}
+
+/// A condition a node must satisfy to be matched by a step.
+#[derive(Clone)]
+pub enum Predicate {
+    /// The node is, or names, the given identifier.
+    Identifier(String),
+    /// The node's payload is the given text.
+    Value(String),
+    /// The node has a direct child of the given kind.
+    HasChild(&'static str),
+    /// The node satisfies the given function.
+    Filter(fn(&ASTNode) -> bool),
+}
+
+/// One step of a `Query`.
+///
+/// # Fields
+/// * `axis` - How the step relates to the nodes matched by the previous step.
+/// * `kind` - The kind name of the matched nodes, or `None` for any kind.
+/// * `predicates` - The conditions every matched node must satisfy.
+#[derive(Debug, Clone)]
+pub struct Step {
+    axis: Axis,
+    kind: Option<&'static str>,
+    predicates: Vec<Predicate>,
+}
+
+/// A sequence of steps selecting nodes of a tree.
+///
+/// # Fields
+/// * `steps` - The steps, applied in order.
+#[derive(Debug, Clone, Default)]
+pub struct Query {
+    steps: Vec<Step>,
+}
+
+impl fmt::Debug for Predicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Predicate::Identifier(name) => write!(f, "identifier={}", name),
+            Predicate::Value(value) => write!(f, "value={}", value),
+            Predicate::HasChild(kind) => write!(f, "has={}", kind),
+            Predicate::Filter(_) => write!(f, "filter"),
+        }
+    }
+}
+
+impl Predicate {
+    /// Checks whether a node satisfies this predicate.
+    ///
+    /// # Parameters
+    /// * `node` - The node to check.
+    ///
+    /// # Returns
+    /// Returns `true` if the node satisfies the predicate, `false` otherwise.
+    pub fn test(&self, node: &ASTNode) -> bool {
+        match self {
+            Predicate::Identifier(name) => identifier_of(node).is_some_and(|identifier| identifier == name),
+            Predicate::Value(value) => payload_of(node).is_some_and(|payload| &payload == value),
+            Predicate::HasChild(kind) => node.get_children_ref().iter().any(|child| child.get_node_type_ref().get_kind_name() == *kind),
+            Predicate::Filter(filter) => filter(node),
+        }
+    }
+}
+
+impl Step {
+    /// Checks whether a node has this step's kind and satisfies all of its predicates.
+    ///
+    /// # Parameters
+    /// * `node` - The node to check.
+    ///
+    /// # Returns
+    /// Returns `true` if the node matches, `false` otherwise.
+    pub fn matches(&self, node: &ASTNode) -> bool {
+        self.kind.is_none_or(|kind| node.get_node_type_ref().get_kind_name() == kind)
+            && self.predicates.iter().all(|predicate| predicate.test(node))
+    }
+
+    /// Retrieves how this step relates to the previous one.
+    ///
+    /// # Returns
+    /// Returns the step's `Axis`.
+    pub fn get_axis(&self) -> Axis {
+        self.axis
+    }
+}
+
+impl Query {
+    /// Creates a query with no steps, which selects nothing until steps are added.
+    ///
+    /// # Returns
+    /// Returns an empty `Query`.
+    pub fn new() -> Self {
+        Query::default()
+    }
+
+    /// Parses a query from the selector syntax described in this module.
+    ///
+    /// # Parameters
+    /// * `source` - The selector, such as `WhileLoop Assignment[identifier=i]`.
+    ///
+    /// # Returns
+    /// Returns the parsed `Query`, or an `ErrorType` if the selector is malformed.
+    ///
+    /// # Errors
+    /// * Returns an error for an unknown node kind or predicate, an unterminated `[`, or an empty selector.
+    ///
+    /// # Examples
+    /// ```
+    /// use common::{ast, ast::query::Query};
+    ///
+    /// let tree = ast!(TopLevelExpression[
+    ///     Assignment[Identifier("i"), Literal(0)],
+    ///     WhileLoop[
+    ///         Condition[Identifier("i")],
+    ///         BlockExpression[Assignment[Identifier("i"), Literal(1)], Assignment[Identifier("j"), Literal(2)]],
+    ///     ],
+    /// ]);
+    ///
+    /// let query = Query::parse("WhileLoop Assignment[identifier=i]").unwrap();
+    /// assert_eq!(query.find_all(tree.get_root_ref()).len(), 1);
+    /// ```
+    pub fn parse(source: &str) -> Result<Query, ErrorType> {
+        let chars: Vec<char> = source.chars().collect();
+        let mut query = Query::new();
+        let mut position = 0;
+        let mut axis = Axis::Descendant;
+
+        loop {
+            while position < chars.len() && chars[position].is_whitespace() {
+                position += 1;
+            }
+            if position == chars.len() {
+                break;
+            }
+            if chars[position] == '>' {
+                if axis == Axis::Child {
+                    return Err(query_error(source, "expected a node kind after '>'"));
+                }
+                axis = Axis::Child;
+                position += 1;
+                continue;
+            }
+
+            let start = position;
+            while position < chars.len() && (chars[position].is_alphanumeric() || chars[position] == '*') {
+                position += 1;
+            }
+            let name: String = chars[start..position].iter().collect();
+            let kind = match name.as_str() {
+                "" => return Err(query_error(source, &format!("unexpected '{}'", chars[position]))),
+                "*" => None,
+                _ => Some(kind_name(&name).ok_or_else(|| query_error(source, &format!("unknown node kind '{}'", name)))?),
+            };
+
+            let mut predicates = Vec::new();
+            while position < chars.len() && chars[position] == '[' {
+                let end = chars[position..].iter().position(|c| *c == ']').map(|offset| position + offset)
+                    .ok_or_else(|| query_error(source, "unterminated '['"))?;
+                let body: String = chars[position + 1..end].iter().collect();
+                for predicate in body.split(',') {
+                    predicates.push(parse_predicate(source, predicate.trim())?);
+                }
+                position = end + 1;
+            }
+
+            query.steps.push(Step { axis, kind, predicates });
+            axis = Axis::Descendant;
+        }
+
+        if axis == Axis::Child {
+            return Err(query_error(source, "expected a node kind after '>'"));
+        }
+        if query.steps.is_empty() {
+            return Err(query_error(source, "expected at least one step"));
+        }
+        Ok(query)
+    }
+
+    /// Adds a step selecting descendants of the nodes matched so far.
+    ///
+    /// # Parameters
+    /// * `kind` - The kind name to select, or `"*"` for any kind.
+    ///
+    /// # Returns
+    /// Returns the extended `Query`.
+    ///
+    /// # Panics
+    /// Panics if `kind` is not the name of a `NodeType` variant.
+    pub fn descendants(self, kind: &str) -> Self {
+        self.step(Axis::Descendant, kind)
+    }
+
+    /// Adds a step selecting direct children of the nodes matched so far.
+    ///
+    /// # Parameters
+    /// * `kind` - The kind name to select, or `"*"` for any kind.
+    ///
+    /// # Returns
+    /// Returns the extended `Query`.
+    ///
+    /// # Panics
+    /// Panics if `kind` is not the name of a `NodeType` variant.
+    pub fn children(self, kind: &str) -> Self {
+        self.step(Axis::Child, kind)
+    }
+
+    /// Restricts the last step to nodes that are, or name, the given identifier.
+    ///
+    /// # Parameters
+    /// * `name` - The identifier.
+    ///
+    /// # Returns
+    /// Returns the restricted `Query`.
+    pub fn with_identifier(self, name: &str) -> Self {
+        self.with(Predicate::Identifier(name.to_string()))
+    }
+
+    /// Restricts the last step to nodes whose payload is the given text.
+    ///
+    /// # Parameters
+    /// * `value` - The payload text.
+    ///
+    /// # Returns
+    /// Returns the restricted `Query`.
+    pub fn with_value(self, value: &str) -> Self {
+        self.with(Predicate::Value(value.to_string()))
+    }
+
+    /// Restricts the last step to nodes satisfying a function.
+    ///
+    /// # Parameters
+    /// * `filter` - The function nodes must satisfy.
+    ///
+    /// # Returns
+    /// Returns the restricted `Query`.
+    pub fn filter(self, filter: fn(&ASTNode) -> bool) -> Self {
+        self.with(Predicate::Filter(filter))
+    }
+
+    /// Restricts the last step with a predicate.
+    ///
+    /// # Parameters
+    /// * `predicate` - The predicate matched nodes must satisfy.
+    ///
+    /// # Returns
+    /// Returns the restricted `Query`.
+    ///
+    /// # Panics
+    /// Panics if the query has no steps.
+    pub fn with(mut self, predicate: Predicate) -> Self {
+        self.steps.last_mut().expect("Cannot add a predicate to a query without steps").predicates.push(predicate);
+        self
+    }
+
+    /// Retrieves the steps of this query.
+    ///
+    /// # Returns
+    /// Returns the steps as a slice.
+    pub fn get_steps(&self) -> &[Step] {
+        &self.steps
+    }
+
+    /// Finds the nodes selected by this query under a root node.
+    ///
+    /// # Parameters
+    /// * `root` - The node to search from.
+    ///
+    /// # Returns
+    /// Returns the selected nodes in pre-order, each at most once.
+    pub fn find_all<'a>(&self, root: &'a ASTNode) -> Vec<&'a ASTNode> {
+        self.evaluate(root).into_iter().map(|(_, node)| node).collect()
+    }
+
+    /// Finds the first node, in pre-order, selected by this query under a root node.
+    ///
+    /// # Parameters
+    /// * `root` - The node to search from.
+    ///
+    /// # Returns
+    /// Returns `Some(&ASTNode)` with the first selected node, or `None` if nothing matches.
+    pub fn find_first<'a>(&self, root: &'a ASTNode) -> Option<&'a ASTNode> {
+        self.find_all(root).into_iter().next()
+    }
+
+    /// Finds the paths of the nodes selected by this query in an `AST`.
+    ///
+    /// # Parameters
+    /// * `ast` - The tree to search.
+    ///
+    /// # Returns
+    /// Returns the `NodePath` of each selected node in pre-order.
+    pub fn find_paths(&self, ast: &AST) -> Vec<NodePath> {
+        self.evaluate(ast.get_root_ref()).into_iter().map(|(path, _)| path).collect()
+    }
+
+    /// Applies every step in turn, keeping the matched nodes sorted in pre-order and free of duplicates.
+    fn evaluate<'a>(&self, root: &'a ASTNode) -> Vec<(NodePath, &'a ASTNode)> {
+        if self.steps.is_empty() {
+            return Vec::new();
+        }
+
+        let mut current: Vec<(NodePath, &'a ASTNode)> = vec![(NodePath::root(root), root)];
+        for (index, step) in self.steps.iter().enumerate() {
+            let mut next: Vec<(NodePath, &'a ASTNode)> = Vec::new();
+            for (path, node) in &current {
+                match step.axis {
+                    Axis::Child => {
+                        for (child_index, child) in node.get_children_ref().iter().enumerate() {
+                            next.push((path.child(child, child_index), child));
+                        }
+                    },
+                    Axis::Descendant => {
+                        if index == 0 {
+                            next.push((path.clone(), *node));
+                        }
+                        collect_descendants(node, path, &mut next);
+                    },
+                }
+            }
+            next.retain(|(_, node)| step.matches(node));
+            next.sort_by_key(|(path, _)| path.get_indices());
+            next.dedup_by(|(a, _), (b, _)| a.get_indices() == b.get_indices());
+            current = next;
+        }
+        current
+    }
+
+    /// Adds a step with the given axis and kind.
+    fn step(mut self, axis: Axis, kind: &str) -> Self {
+        let kind = match kind {
+            "*" => None,
+            _ => Some(kind_name(kind).unwrap_or_else(|| panic!("Unknown node kind '{}'", kind))),
+        };
+        self.steps.push(Step { axis, kind, predicates: Vec::new() });
+        self
+    }
+}
+
+/// Collects every proper descendant of a node with its path.
+fn collect_descendants<'a>(node: &'a ASTNode, path: &NodePath, output: &mut Vec<(NodePath, &'a ASTNode)>) {
+    for (index, child) in node.get_children_ref().iter().enumerate() {
+        let child_path = path.child(child, index);
+        output.push((child_path.clone(), child));
+        collect_descendants(child, &child_path, output);
+    }
+}
+
+/// Resolves a kind name to its static spelling.
+fn kind_name(name: &str) -> Option<&'static str> {
+    NodeType::get_kind_names().iter().copied().find(|kind| *kind == name)
+}
+
+/// Parses a single predicate such as `identifier=i`.
+fn parse_predicate(source: &str, predicate: &str) -> Result<Predicate, ErrorType> {
+    let (key, value) = predicate.split_once('=').ok_or_else(|| query_error(source, &format!("expected key=value predicate, found '{}'", predicate)))?;
+    let value = value.trim().trim_matches(|c| c == '"' || c == '\'');
+    match key.trim() {
+        "identifier" => Ok(Predicate::Identifier(value.to_string())),
+        "value" => Ok(Predicate::Value(value.to_string())),
+        "has" => Ok(Predicate::HasChild(kind_name(value).ok_or_else(|| query_error(source, &format!("unknown node kind '{}'", value)))?)),
+        other => Err(query_error(source, &format!("unknown predicate '{}'", other))),
+    }
+}
+
+/// Builds the error reported for a malformed selector.
+fn query_error(source: &str, message: &str) -> ErrorType {
+    ErrorType::DevError {
+        message: format!("Invalid query '{}': {}", source, message),
+    }
+}
+
+/// Retrieves the payload of a node as text.
+fn payload_of(node: &ASTNode) -> Option<String> {
+    match node.get_node_type_ref() {
+        NodeType::Literal(value) | NodeType::Identifier(value) | NodeType::Operator(value) | NodeType::Constant(value) => Some(value.clone()),
+        NodeType::Type(data_type) => Some(data_type.to_string()),
+        _ => None,
+    }
+}
+
+/// Retrieves the identifier a node is or names.
+fn identifier_of(node: &ASTNode) -> Option<&str> {
+    if let NodeType::Identifier(name) = node.get_node_type_ref() {
+        return Some(name);
+    }
+    node.get_children_ref().iter().find_map(|child| match child.get_node_type_ref() {
+        NodeType::Identifier(name) => Some(name.as_str()),
+        NodeType::Variable => child.get_children_ref().iter().find_map(|grandchild| match grandchild.get_node_type_ref() {
+            NodeType::Identifier(name) => Some(name.as_str()),
+            _ => None,
+        }),
+        _ => None,
+    })
+}
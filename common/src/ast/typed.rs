@@ -0,0 +1,874 @@
+//! This file defines a strongly typed view of the `AST`, where each construct is a variant with named fields
+//! instead of an `ASTNode` whose children follow an unwritten ordering convention.
+//!
+//! Conversion goes both ways: `from_node` decodes an `ASTNode` and reports any node that breaks the conventions,
+//! and `to_node` rebuilds exactly the `ASTNode` the parser would produce, so a conventional tree survives the
+//! round trip unchanged. The conventions decoded here are:
+//!
+//! * `FunctionDeclaration` - `[Identifier, Parameter*, VariadicParameter?, Type, BlockExpression?]`
+//! * `StructDeclaration` / `UnionDeclaration` - `[Identifier, Field*]`, each `Field` being
+//!   `[Literal(name), Type, BitWidth[Literal]? | FlexibleArray?]`
+//! * `EnumDeclaration` - `[Identifier, Variant[Identifier]*]`
+//! * `Initialization` - `[Variable[Identifier, Type, Identifier?], AssignedValue[value]?]`, the second
+//!   `Identifier` naming the struct or union of a variable of that type
+//! * `Assignment` - `[Identifier, value]`, `[Variable, AssignedValue[value]]`, or either with an `Operator`
+//!   between target and value for compound assignments
+//! * `IfStatement` - `[Condition, BlockExpression, ElseStatement[BlockExpression | IfStatement]?]`
+//! * `WhileLoop` - `[Condition, BlockExpression?]`, `DoWhileLoop` - `[BlockExpression, Condition]`
+//! * `ForLoop` - `[LoopInitializer, Condition, LoopIncrement, BlockExpression]`, each clause possibly empty
+//! * `SwitchStatement` - `[scrutinee, BlockExpression[Case[value, BlockExpression] | Default[BlockExpression]]]`
+//! * `Return` - `[AssignedValue[value]]` or `[]`
+
+use crate::{
+    ast_node,
+    ast::{
+        core::{ASTNode, AST},
+        data_type::DataType,
+        node_type::NodeType,
+    },
+    error::ErrorType,
+};
+
+/// A sequence of statements, such as the body of a function or loop.
+pub type Block = Vec<Stmt>;
+
+/// An expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expr {
+    /// A literal value, such as `5` or `"text"`.
+    Literal(String),
+    /// A named constant.
+    Constant(String),
+    /// A reference to a name.
+    Identifier(String),
+    /// A reference to a declared variable, carrying its type when the tree records one.
+    Variable {
+        /// The name of the variable.
+        name: String,
+        /// The type of the variable, if recorded.
+        data_type: Option<DataType>,
+        /// The name of the struct or union, for a variable of that type.
+        record: Option<String>,
+    },
+    /// A binary operation such as `a + b`.
+    Binary {
+        /// The left operand.
+        left: Box<Expr>,
+        /// The operator's spelling.
+        operator: String,
+        /// The right operand.
+        right: Box<Expr>,
+    },
+    /// A unary operation such as `-a` or `i++`.
+    Unary {
+        /// The operator's spelling.
+        operator: String,
+        /// The operand.
+        operand: Box<Expr>,
+        /// Whether the operator follows its operand.
+        postfix: bool,
+    },
+    /// An assignment such as `x = 1` or `x += 1`.
+    Assignment {
+        /// The assigned target, an `Identifier`, `Variable` or `Member` expression.
+        target: Box<Expr>,
+        /// The operator of a compound assignment such as `+=`, or `None` for a plain assignment.
+        operator: Option<String>,
+        /// The assigned value.
+        value: Box<Expr>,
+    },
+    /// A function call.
+    Call {
+        /// The name of the called function.
+        callee: String,
+        /// The arguments, in order.
+        arguments: Vec<Expr>,
+    },
+    /// An access to a struct or union member such as `point.x`.
+    Member {
+        /// The accessed aggregate.
+        base: Box<Expr>,
+        /// The name of the member.
+        member: String,
+    },
+}
+
+/// A statement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Stmt {
+    /// An empty statement.
+    Empty,
+    /// An expression evaluated for its effect.
+    Expr(Expr),
+    /// A variable declaration with an optional initial value.
+    Initialization {
+        /// The name of the variable.
+        name: String,
+        /// The type of the variable.
+        data_type: DataType,
+        /// The name of the struct or union, for a variable of that type.
+        record: Option<String>,
+        /// The initial value, if any.
+        value: Option<Expr>,
+    },
+    /// A nested block.
+    Block(Block),
+    /// An if statement.
+    If {
+        /// The condition.
+        condition: Expr,
+        /// The statements run when the condition holds.
+        then_block: Block,
+        /// The else branch, either a `Stmt::Block` or, for `else if`, a `Stmt::If`.
+        else_branch: Option<Box<Stmt>>,
+    },
+    /// A while loop.
+    While {
+        /// The condition.
+        condition: Expr,
+        /// The loop body, or `None` for a loop without one.
+        body: Option<Block>,
+    },
+    /// A do-while loop.
+    DoWhile {
+        /// The loop body.
+        body: Block,
+        /// The condition.
+        condition: Expr,
+    },
+    /// A for loop.
+    For {
+        /// The initializer, an `Initialization` or `Expr` statement, if any.
+        initializer: Option<Box<Stmt>>,
+        /// The condition, if any.
+        condition: Option<Expr>,
+        /// The increment, if any.
+        increment: Option<Expr>,
+        /// The loop body.
+        body: Block,
+    },
+    /// A switch statement.
+    Switch {
+        /// The value switched on.
+        scrutinee: Expr,
+        /// The case and default arms, in order.
+        arms: Vec<SwitchArm>,
+    },
+    /// A break statement.
+    Break,
+    /// A continue statement.
+    Continue,
+    /// A return statement with an optional value.
+    Return(Option<Expr>),
+    /// A declaration.
+    Decl(Decl),
+}
+
+/// An arm of a switch statement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SwitchArm {
+    /// A `case` arm.
+    Case {
+        /// The value matched.
+        value: Expr,
+        /// The statements of the arm.
+        body: Block,
+    },
+    /// The `default` arm.
+    Default {
+        /// The statements of the arm.
+        body: Block,
+    },
+}
+
+/// A declaration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Decl {
+    /// A function declaration, or a prototype if it has no body.
+    Function {
+        /// The name of the function.
+        name: String,
+        /// The parameters as `(name, type)` pairs, in order.
+        parameters: Vec<(String, DataType)>,
+        /// Whether the function accepts further arguments (`...`).
+        variadic: bool,
+        /// The return type.
+        return_type: DataType,
+        /// The body, or `None` for a prototype.
+        body: Option<Block>,
+    },
+    /// A struct declaration.
+    Struct {
+        /// The name of the struct.
+        name: String,
+        /// The fields, in order.
+        fields: Vec<Field>,
+    },
+    /// A union declaration.
+    Union {
+        /// The name of the union.
+        name: String,
+        /// The fields, in order.
+        fields: Vec<Field>,
+    },
+    /// An enum declaration.
+    Enum {
+        /// The name of the enum.
+        name: String,
+        /// The names of the variants, in order.
+        variants: Vec<String>,
+    },
+}
+
+/// A field of a struct or union.
+///
+/// # Fields
+/// * `name` - The name of the field.
+/// * `data_type` - The type of the field, or of the elements of a flexible array member.
+/// * `bit_width` - The width of a bit-field, or `None` for an ordinary field.
+/// * `flexible` - Whether the field is a flexible array member.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Field {
+    name: String,
+    data_type: DataType,
+    bit_width: Option<u32>,
+    flexible: bool,
+}
+
+/// A typed view of an `AST` whose root is a `TopLevelExpression`.
+///
+/// # Fields
+/// * `items` - The top-level statements and declarations, in order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Program {
+    items: Vec<Stmt>,
+}
+
+impl Field {
+    /// Creates a new `Field`.
+    ///
+    /// # Parameters
+    /// * `name` - The name of the field.
+    /// * `data_type` - The type of the field.
+    /// * `bit_width` - The width of a bit-field, or `None`.
+    /// * `flexible` - Whether the field is a flexible array member.
+    ///
+    /// # Returns
+    /// Returns the new `Field`.
+    pub fn new(name: &str, data_type: DataType, bit_width: Option<u32>, flexible: bool) -> Self {
+        Field { name: name.to_string(), data_type, bit_width, flexible }
+    }
+
+    /// Retrieves the name of this field.
+    ///
+    /// # Returns
+    /// Returns the field's name as a `&str`.
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Retrieves the type of this field.
+    ///
+    /// # Returns
+    /// Returns the field's `DataType`.
+    pub fn get_data_type(&self) -> DataType {
+        self.data_type
+    }
+
+    /// Retrieves the width of this bit-field.
+    ///
+    /// # Returns
+    /// Returns `Some(u32)` for a bit-field, `None` otherwise.
+    pub fn get_bit_width(&self) -> Option<u32> {
+        self.bit_width
+    }
+
+    /// Checks if this field is a flexible array member.
+    ///
+    /// # Returns
+    /// Returns `true` for a flexible array member, `false` otherwise.
+    pub fn is_flexible_array(&self) -> bool {
+        self.flexible
+    }
+}
+
+impl Program {
+    /// Creates a new `Program`.
+    ///
+    /// # Parameters
+    /// * `items` - The top-level statements and declarations.
+    ///
+    /// # Returns
+    /// Returns the new `Program`.
+    pub fn new(items: Vec<Stmt>) -> Self {
+        Program { items }
+    }
+
+    /// Retrieves the top-level items of this program.
+    ///
+    /// # Returns
+    /// Returns the items as a slice.
+    pub fn get_items(&self) -> &[Stmt] {
+        &self.items
+    }
+
+    /// Decodes an `AST` whose root is a `TopLevelExpression`.
+    ///
+    /// # Parameters
+    /// * `ast` - The `AST` to decode.
+    ///
+    /// # Returns
+    /// Returns the typed `Program`, or an `ErrorType` if a node breaks the conventions.
+    ///
+    /// # Errors
+    /// * Returns an error if the root is not a `TopLevelExpression` or any node breaks the conventions.
+    pub fn from_ast(ast: &AST) -> Result<Program, ErrorType> {
+        let root = ast.get_root_ref();
+        if root.get_node_type_ref() != &NodeType::TopLevelExpression {
+            return Err(malformed(root, "a TopLevelExpression root"));
+        }
+        Ok(Program { items: block_from_children(root.get_children_ref())? })
+    }
+
+    /// Rebuilds the `AST` for this program.
+    ///
+    /// # Returns
+    /// Returns an `AST` rooted at a `TopLevelExpression`.
+    pub fn to_ast(&self) -> AST {
+        let mut root = ast_node!(TopLevelExpression);
+        root.set_children(self.items.iter().map(Stmt::to_node).collect());
+        AST::new(root)
+    }
+}
+
+impl Expr {
+    /// Decodes an expression node.
+    ///
+    /// # Parameters
+    /// * `node` - The node to decode.
+    ///
+    /// # Returns
+    /// Returns the typed `Expr`, or an `ErrorType` if the node is not a conventional expression.
+    ///
+    /// # Errors
+    /// * Returns an error if the node is not an expression or its children break the conventions.
+    pub fn from_node(node: &ASTNode) -> Result<Expr, ErrorType> {
+        let children = node.get_children_ref();
+        match node.get_node_type_ref() {
+            NodeType::Literal(value) => Ok(Expr::Literal(value.clone())),
+            NodeType::Constant(value) => Ok(Expr::Constant(value.clone())),
+            NodeType::Identifier(name) => Ok(Expr::Identifier(name.clone())),
+            NodeType::Variable => match children {
+                [name] => Ok(Expr::Variable { name: name_of(name, node)?, data_type: None, record: None }),
+                [_, _] | [_, _, _] => {
+                    let (name, data_type, record) = declared_variable(node)?;
+                    Ok(Expr::Variable { name, data_type: Some(data_type), record })
+                },
+                _ => Err(malformed(node, "an Identifier, an optional Type and an optional Identifier")),
+            },
+            NodeType::BinaryExpression => match children {
+                [left, operator, right] => Ok(Expr::Binary {
+                    left: Box::new(Expr::from_node(left)?),
+                    operator: operator_of(operator).ok_or_else(|| malformed(node, "an Operator as its second child"))?,
+                    right: Box::new(Expr::from_node(right)?),
+                }),
+                _ => Err(malformed(node, "an operand, an Operator and an operand")),
+            },
+            NodeType::UnaryExpression => match children {
+                [operator, operand] if operator_of(operator).is_some() => Ok(Expr::Unary {
+                    operator: operator_of(operator).unwrap_or_default(),
+                    operand: Box::new(Expr::from_node(operand)?),
+                    postfix: false,
+                }),
+                [operand, operator] if operator_of(operator).is_some() => Ok(Expr::Unary {
+                    operator: operator_of(operator).unwrap_or_default(),
+                    operand: Box::new(Expr::from_node(operand)?),
+                    postfix: true,
+                }),
+                _ => Err(malformed(node, "an Operator and an operand")),
+            },
+            NodeType::Assignment => {
+                let (target, operator, value) = match children {
+                    [target, value] => (target, None, value),
+                    [target, operator, value] => (target, Some(operator_of(operator).ok_or_else(|| malformed(node, "an Operator between target and value"))?), value),
+                    _ => return Err(malformed(node, "a target and a value")),
+                };
+                let target = Expr::from_node(target)?;
+                let value = match (&target, value.get_node_type_ref()) {
+                    (Expr::Variable { .. }, NodeType::AssignedValue) => unwrap_single(value)?,
+                    (Expr::Variable { .. }, _) => return Err(malformed(node, "an AssignedValue after a Variable target")),
+                    _ => Expr::from_node(value)?,
+                };
+                Ok(Expr::Assignment { target: Box::new(target), operator, value: Box::new(value) })
+            },
+            NodeType::FunctionCall => {
+                let (callee, arguments) = children.split_first().ok_or_else(|| malformed(node, "an Identifier child"))?;
+                Ok(Expr::Call {
+                    callee: name_of(callee, node)?,
+                    arguments: arguments.iter().map(Expr::from_node).collect::<Result<Vec<Expr>, ErrorType>>()?,
+                })
+            },
+            NodeType::MemberAccess => match children {
+                [base, member] => Ok(Expr::Member { base: Box::new(Expr::from_node(base)?), member: name_of(member, node)? }),
+                _ => Err(malformed(node, "a base and a member")),
+            },
+            _ => Err(malformed(node, "an expression")),
+        }
+    }
+
+    /// Rebuilds the `ASTNode` for this expression.
+    ///
+    /// # Returns
+    /// Returns the conventional `ASTNode`.
+    pub fn to_node(&self) -> ASTNode {
+        match self {
+            Expr::Literal(value) => ASTNode::new(NodeType::Literal(value.clone())),
+            Expr::Constant(value) => ASTNode::new(NodeType::Constant(value.clone())),
+            Expr::Identifier(name) => ASTNode::new(NodeType::Identifier(name.clone())),
+            Expr::Variable { name, data_type, record } => {
+                let mut variable = ast_node!(Variable[Identifier(name)]);
+                if let Some(data_type) = data_type {
+                    variable.add_child(ASTNode::new(NodeType::Type(*data_type)));
+                    if let Some(record) = record {
+                        variable.add_child(ast_node!(Identifier(record)));
+                    }
+                }
+                variable
+            },
+            Expr::Binary { left, operator, right } => {
+                ast_node!(BinaryExpression[{ left.to_node() }, Operator(operator), { right.to_node() }])
+            },
+            Expr::Unary { operator, operand, postfix } => if *postfix {
+                ast_node!(UnaryExpression[{ operand.to_node() }, Operator(operator)])
+            } else {
+                ast_node!(UnaryExpression[Operator(operator), { operand.to_node() }])
+            },
+            Expr::Assignment { target, operator, value } => {
+                let mut assignment = ast_node!(Assignment[{ target.to_node() }]);
+                if let Some(operator) = operator {
+                    assignment.add_child(ast_node!(Operator(operator)));
+                }
+                match target.as_ref() {
+                    Expr::Variable { .. } => assignment.add_child(ast_node!(AssignedValue[{ value.to_node() }])),
+                    _ => assignment.add_child(value.to_node()),
+                }
+                assignment
+            },
+            Expr::Call { callee, arguments } => {
+                let mut call = ast_node!(FunctionCall[Identifier(callee)]);
+                call.add_children(arguments.iter().map(Expr::to_node).collect());
+                call
+            },
+            Expr::Member { base, member } => ast_node!(MemberAccess[{ base.to_node() }, Identifier(member)]),
+        }
+    }
+}
+
+impl Stmt {
+    /// Decodes a node in statement position. Declarations decode to `Stmt::Decl`, and any expression to `Stmt::Expr`.
+    ///
+    /// # Parameters
+    /// * `node` - The node to decode.
+    ///
+    /// # Returns
+    /// Returns the typed `Stmt`, or an `ErrorType` if the node breaks the conventions.
+    ///
+    /// # Errors
+    /// * Returns an error if the node or any of its descendants breaks the conventions.
+    pub fn from_node(node: &ASTNode) -> Result<Stmt, ErrorType> {
+        let children = node.get_children_ref();
+        match node.get_node_type_ref() {
+            NodeType::NoExpression => Ok(Stmt::Empty),
+            NodeType::Break => Ok(Stmt::Break),
+            NodeType::Continue => Ok(Stmt::Continue),
+            NodeType::Return => match children {
+                [] => Ok(Stmt::Return(None)),
+                [value] if value.get_node_type_ref() == &NodeType::AssignedValue => Ok(Stmt::Return(Some(unwrap_single(value)?))),
+                _ => Err(malformed(node, "a single AssignedValue child")),
+            },
+            NodeType::Initialization => match children {
+                [variable] => {
+                    let (name, data_type, record) = declared_variable(variable)?;
+                    Ok(Stmt::Initialization { name, data_type, record, value: None })
+                },
+                [variable, value] if value.get_node_type_ref() == &NodeType::AssignedValue => {
+                    let (name, data_type, record) = declared_variable(variable)?;
+                    Ok(Stmt::Initialization { name, data_type, record, value: Some(unwrap_single(value)?) })
+                },
+                _ => Err(malformed(node, "a Variable and an optional AssignedValue")),
+            },
+            NodeType::BlockExpression => Ok(Stmt::Block(block_from_children(children)?)),
+            NodeType::IfStatement => {
+                let (condition, then_block, else_branch) = match children {
+                    [condition, then_block] => (condition, then_block, None),
+                    [condition, then_block, else_branch] => (condition, then_block, Some(else_branch)),
+                    _ => return Err(malformed(node, "a Condition, a BlockExpression and an optional ElseStatement")),
+                };
+                let else_branch = match else_branch {
+                    Some(else_branch) => match else_branch.get_children_ref() {
+                        [inner] if else_branch.get_node_type_ref() == &NodeType::ElseStatement
+                            && matches!(inner.get_node_type_ref(), NodeType::BlockExpression | NodeType::IfStatement) => Some(Box::new(Stmt::from_node(inner)?)),
+                        _ => return Err(malformed(else_branch, "an ElseStatement holding a BlockExpression or IfStatement")),
+                    },
+                    None => None,
+                };
+                Ok(Stmt::If { condition: condition_of(condition)?, then_block: block_of(then_block)?, else_branch })
+            },
+            NodeType::WhileLoop => match children {
+                [condition] => Ok(Stmt::While { condition: condition_of(condition)?, body: None }),
+                [condition, body] => Ok(Stmt::While { condition: condition_of(condition)?, body: Some(block_of(body)?) }),
+                _ => Err(malformed(node, "a Condition and an optional BlockExpression")),
+            },
+            NodeType::DoWhileLoop => match children {
+                [body, condition] => Ok(Stmt::DoWhile { body: block_of(body)?, condition: condition_of(condition)? }),
+                _ => Err(malformed(node, "a BlockExpression and a Condition")),
+            },
+            NodeType::ForLoop => match children {
+                [initializer, condition, increment, body] => {
+                    expect_kind(initializer, NodeType::LoopInitializer)?;
+                    expect_kind(condition, NodeType::Condition)?;
+                    expect_kind(increment, NodeType::LoopIncrement)?;
+                    Ok(Stmt::For {
+                        initializer: optional_single(initializer)?.map(Stmt::from_node).transpose()?.map(Box::new),
+                        condition: optional_single(condition)?.map(Expr::from_node).transpose()?,
+                        increment: optional_single(increment)?.map(Expr::from_node).transpose()?,
+                        body: block_of(body)?,
+                    })
+                },
+                _ => Err(malformed(node, "a LoopInitializer, a Condition, a LoopIncrement and a BlockExpression")),
+            },
+            NodeType::SwitchStatement => match children {
+                [scrutinee, block] if block.get_node_type_ref() == &NodeType::BlockExpression => {
+                    let arms = block.get_children_ref().iter().map(|arm| match (arm.get_node_type_ref(), arm.get_children_ref()) {
+                        (NodeType::Case, [value, body]) => Ok(SwitchArm::Case { value: Expr::from_node(value)?, body: block_of(body)? }),
+                        (NodeType::Default, [body]) => Ok(SwitchArm::Default { body: block_of(body)? }),
+                        _ => Err(malformed(arm, "a Case with a value and a BlockExpression, or a Default with a BlockExpression")),
+                    }).collect::<Result<Vec<SwitchArm>, ErrorType>>()?;
+                    Ok(Stmt::Switch { scrutinee: Expr::from_node(scrutinee)?, arms })
+                },
+                _ => Err(malformed(node, "a scrutinee and a BlockExpression of arms")),
+            },
+            NodeType::FunctionDeclaration | NodeType::StructDeclaration | NodeType::UnionDeclaration | NodeType::EnumDeclaration => {
+                Ok(Stmt::Decl(Decl::from_node(node)?))
+            },
+            _ => Ok(Stmt::Expr(Expr::from_node(node)?)),
+        }
+    }
+
+    /// Rebuilds the `ASTNode` for this statement.
+    ///
+    /// # Returns
+    /// Returns the conventional `ASTNode`.
+    pub fn to_node(&self) -> ASTNode {
+        match self {
+            Stmt::Empty => ASTNode::new(NodeType::NoExpression),
+            Stmt::Expr(expr) => expr.to_node(),
+            Stmt::Initialization { name, data_type, record, value } => {
+                let variable = Expr::Variable { name: name.clone(), data_type: Some(*data_type), record: record.clone() }.to_node();
+                match value {
+                    Some(value) => ast_node!(Initialization[{ variable }, AssignedValue[{ value.to_node() }]]),
+                    None => ast_node!(Initialization[{ variable }]),
+                }
+            },
+            Stmt::Block(block) => block_node(block),
+            Stmt::If { condition, then_block, else_branch } => {
+                let mut if_statement = ast_node!(IfStatement[Condition[{ condition.to_node() }], { block_node(then_block) }]);
+                if let Some(else_branch) = else_branch {
+                    if_statement.add_child(ast_node!(ElseStatement[{ else_branch.to_node() }]));
+                }
+                if_statement
+            },
+            Stmt::While { condition, body } => match body {
+                Some(body) => ast_node!(WhileLoop[Condition[{ condition.to_node() }], { block_node(body) }]),
+                None => ast_node!(WhileLoop[Condition[{ condition.to_node() }]]),
+            },
+            Stmt::DoWhile { body, condition } => ast_node!(DoWhileLoop[{ block_node(body) }, Condition[{ condition.to_node() }]]),
+            Stmt::For { initializer, condition, increment, body } => {
+                let mut clauses = [ast_node!(LoopInitializer), ast_node!(Condition), ast_node!(LoopIncrement)];
+                clauses[0].set_children(initializer.iter().map(|initializer| initializer.to_node()).collect());
+                clauses[1].set_children(condition.iter().map(Expr::to_node).collect());
+                clauses[2].set_children(increment.iter().map(Expr::to_node).collect());
+                let [initializer, condition, increment] = clauses;
+                ast_node!(ForLoop[{ initializer }, { condition }, { increment }, { block_node(body) }])
+            },
+            Stmt::Switch { scrutinee, arms } => {
+                let mut arms_node = ast_node!(BlockExpression);
+                arms_node.set_children(arms.iter().map(|arm| match arm {
+                    SwitchArm::Case { value, body } => ast_node!(Case[{ value.to_node() }, { block_node(body) }]),
+                    SwitchArm::Default { body } => ast_node!(Default[{ block_node(body) }]),
+                }).collect());
+                ast_node!(SwitchStatement[{ scrutinee.to_node() }, { arms_node }])
+            },
+            Stmt::Break => ast_node!(Break),
+            Stmt::Continue => ast_node!(Continue),
+            Stmt::Return(value) => match value {
+                Some(value) => ast_node!(Return[AssignedValue[{ value.to_node() }]]),
+                None => ast_node!(Return),
+            },
+            Stmt::Decl(decl) => decl.to_node(),
+        }
+    }
+}
+
+impl Decl {
+    /// Decodes a declaration node.
+    ///
+    /// # Parameters
+    /// * `node` - The node to decode.
+    ///
+    /// # Returns
+    /// Returns the typed `Decl`, or an `ErrorType` if the node breaks the conventions.
+    ///
+    /// # Errors
+    /// * Returns an error if the node is not a declaration or any of its descendants breaks the conventions.
+    pub fn from_node(node: &ASTNode) -> Result<Decl, ErrorType> {
+        let children = node.get_children_ref();
+        let (name, rest) = children.split_first().ok_or_else(|| malformed(node, "an Identifier child"))?;
+        let name = name_of(name, node)?;
+        match node.get_node_type_ref() {
+            NodeType::FunctionDeclaration => {
+                let mut parameters: Vec<(String, DataType)> = Vec::new();
+                let mut variadic = false;
+                let mut return_type: Option<DataType> = None;
+                let mut body: Option<Block> = None;
+                for child in rest {
+                    match (child.get_node_type_ref(), return_type) {
+                        (NodeType::Parameter, None) if !variadic => parameters.push(declared(child)?),
+                        (NodeType::VariadicParameter, None) if !variadic => variadic = true,
+                        (NodeType::Type(data_type), None) => return_type = Some(*data_type),
+                        (NodeType::BlockExpression, Some(_)) if body.is_none() => body = Some(block_of(child)?),
+                        _ => return Err(malformed(node, "an Identifier, Parameters, an optional VariadicParameter, a Type and an optional BlockExpression")),
+                    }
+                }
+                let return_type = return_type.ok_or_else(|| malformed(node, "a return Type"))?;
+                Ok(Decl::Function { name, parameters, variadic, return_type, body })
+            },
+            NodeType::StructDeclaration => Ok(Decl::Struct { name, fields: rest.iter().map(field_of).collect::<Result<Vec<Field>, ErrorType>>()? }),
+            NodeType::UnionDeclaration => Ok(Decl::Union { name, fields: rest.iter().map(field_of).collect::<Result<Vec<Field>, ErrorType>>()? }),
+            NodeType::EnumDeclaration => {
+                let variants = rest.iter().map(|variant| match variant.get_children_ref() {
+                    [variant_name] if variant.get_node_type_ref() == &NodeType::Variant => name_of(variant_name, variant),
+                    _ => Err(malformed(variant, "a Variant holding an Identifier")),
+                }).collect::<Result<Vec<String>, ErrorType>>()?;
+                Ok(Decl::Enum { name, variants })
+            },
+            _ => Err(malformed(node, "a declaration")),
+        }
+    }
+
+    /// Rebuilds the `ASTNode` for this declaration.
+    ///
+    /// # Returns
+    /// Returns the conventional `ASTNode`.
+    pub fn to_node(&self) -> ASTNode {
+        match self {
+            Decl::Function { name, parameters, variadic, return_type, body } => {
+                let mut function = ast_node!(FunctionDeclaration[Identifier(name)]);
+                for (parameter_name, data_type) in parameters {
+                    function.add_child(ast_node!(Parameter[Identifier(parameter_name), { ASTNode::new(NodeType::Type(*data_type)) }]));
+                }
+                if *variadic {
+                    function.add_child(ast_node!(VariadicParameter));
+                }
+                function.add_child(ASTNode::new(NodeType::Type(*return_type)));
+                if let Some(body) = body {
+                    function.add_child(block_node(body));
+                }
+                function
+            },
+            Decl::Struct { name, fields } => aggregate_node(NodeType::StructDeclaration, name, fields),
+            Decl::Union { name, fields } => aggregate_node(NodeType::UnionDeclaration, name, fields),
+            Decl::Enum { name, variants } => {
+                let mut enumeration = ast_node!(EnumDeclaration[Identifier(name)]);
+                enumeration.add_children(variants.iter().map(|variant| ast_node!(Variant[Identifier(variant)])).collect());
+                enumeration
+            },
+        }
+    }
+}
+
+impl TryFrom<&ASTNode> for Expr {
+    type Error = ErrorType;
+
+    fn try_from(node: &ASTNode) -> Result<Self, Self::Error> {
+        Expr::from_node(node)
+    }
+}
+
+impl TryFrom<&ASTNode> for Stmt {
+    type Error = ErrorType;
+
+    fn try_from(node: &ASTNode) -> Result<Self, Self::Error> {
+        Stmt::from_node(node)
+    }
+}
+
+impl TryFrom<&ASTNode> for Decl {
+    type Error = ErrorType;
+
+    fn try_from(node: &ASTNode) -> Result<Self, Self::Error> {
+        Decl::from_node(node)
+    }
+}
+
+impl From<&Expr> for ASTNode {
+    fn from(expr: &Expr) -> Self {
+        expr.to_node()
+    }
+}
+
+impl From<&Stmt> for ASTNode {
+    fn from(stmt: &Stmt) -> Self {
+        stmt.to_node()
+    }
+}
+
+impl From<&Decl> for ASTNode {
+    fn from(decl: &Decl) -> Self {
+        decl.to_node()
+    }
+}
+
+/// Builds a `BlockExpression` holding the given statements.
+fn block_node(block: &[Stmt]) -> ASTNode {
+    let mut node = ast_node!(BlockExpression);
+    node.set_children(block.iter().map(Stmt::to_node).collect());
+    node
+}
+
+/// Builds a struct or union declaration node.
+fn aggregate_node(node_type: NodeType, name: &str, fields: &[Field]) -> ASTNode {
+    let mut aggregate = ASTNode::new(node_type);
+    aggregate.add_child(ast_node!(Identifier(name)));
+    for field in fields {
+        let mut field_node = ast_node!(Field[Literal(field.name), { ASTNode::new(NodeType::Type(field.data_type)) }]);
+        if let Some(width) = field.bit_width {
+            field_node.add_child(ast_node!(BitWidth[Literal(width)]));
+        }
+        if field.flexible {
+            field_node.add_child(ast_node!(FlexibleArray));
+        }
+        aggregate.add_child(field_node);
+    }
+    aggregate
+}
+
+/// Builds the error reported for a node that breaks the conventions.
+fn malformed(node: &ASTNode, expected: &str) -> ErrorType {
+    ErrorType::DevError {
+        message: format!("Cannot convert {} to a typed node: expected {}", node.get_node_type_ref(), expected),
+    }
+}
+
+/// Checks that a node is of the given kind.
+fn expect_kind(node: &ASTNode, kind: NodeType) -> Result<(), ErrorType> {
+    if node.get_node_type_ref() == &kind {
+        Ok(())
+    } else {
+        Err(malformed(node, &format!("a {}", kind)))
+    }
+}
+
+/// Retrieves the operator held by an `Operator` node.
+fn operator_of(node: &ASTNode) -> Option<String> {
+    match node.get_node_type_ref() {
+        NodeType::Operator(operator) => Some(operator.clone()),
+        _ => None,
+    }
+}
+
+/// Retrieves the name held by an `Identifier` or `Literal` node that is a child of `parent`.
+fn name_of(node: &ASTNode, parent: &ASTNode) -> Result<String, ErrorType> {
+    match node.get_node_type_ref() {
+        NodeType::Identifier(name) | NodeType::Literal(name) => Ok(name.clone()),
+        _ => Err(malformed(parent, "an Identifier")),
+    }
+}
+
+/// Retrieves the data type held by a `Type` node that is a child of `parent`.
+fn type_of(node: &ASTNode, parent: &ASTNode) -> Result<DataType, ErrorType> {
+    match node.get_node_type_ref() {
+        NodeType::Type(data_type) => Ok(*data_type),
+        _ => Err(malformed(parent, "a Type")),
+    }
+}
+
+/// Decodes a `[name, Type]` pair such as a `Parameter`.
+fn declared(node: &ASTNode) -> Result<(String, DataType), ErrorType> {
+    match node.get_children_ref() {
+        [name, data_type] => Ok((name_of(name, node)?, type_of(data_type, node)?)),
+        _ => Err(malformed(node, "a name and a Type")),
+    }
+}
+
+/// Decodes a `Variable` holding a name, a `Type` and, for a struct or union, the `Identifier` naming it.
+fn declared_variable(node: &ASTNode) -> Result<(String, DataType, Option<String>), ErrorType> {
+    expect_kind(node, NodeType::Variable)?;
+    match node.get_children_ref() {
+        [name, data_type] => Ok((name_of(name, node)?, type_of(data_type, node)?, None)),
+        [name, data_type, record] => match record.get_node_type_ref() {
+            NodeType::Identifier(record) => Ok((name_of(name, node)?, type_of(data_type, node)?, Some(record.clone()))),
+            _ => Err(malformed(node, "an Identifier naming the struct or union")),
+        },
+        _ => Err(malformed(node, "a name, a Type and an optional Identifier")),
+    }
+}
+
+/// Decodes the single expression wrapped by a node such as `AssignedValue`.
+fn unwrap_single(node: &ASTNode) -> Result<Expr, ErrorType> {
+    match node.get_children_ref() {
+        [inner] => Expr::from_node(inner),
+        _ => Err(malformed(node, "a single expression child")),
+    }
+}
+
+/// Retrieves the child of a clause node that holds at most one child.
+fn optional_single(node: &ASTNode) -> Result<Option<&ASTNode>, ErrorType> {
+    match node.get_children_ref() {
+        [] => Ok(None),
+        [inner] => Ok(Some(inner)),
+        _ => Err(malformed(node, "at most one child")),
+    }
+}
+
+/// Decodes a `Condition` node.
+fn condition_of(node: &ASTNode) -> Result<Expr, ErrorType> {
+    expect_kind(node, NodeType::Condition)?;
+    unwrap_single(node)
+}
+
+/// Decodes a `BlockExpression` node.
+fn block_of(node: &ASTNode) -> Result<Block, ErrorType> {
+    expect_kind(node, NodeType::BlockExpression)?;
+    block_from_children(node.get_children_ref())
+}
+
+/// Decodes a sequence of statement nodes.
+fn block_from_children(children: &[ASTNode]) -> Result<Block, ErrorType> {
+    children.iter().map(Stmt::from_node).collect()
+}
+
+/// Decodes a `Field` node.
+fn field_of(node: &ASTNode) -> Result<Field, ErrorType> {
+    expect_kind(node, NodeType::Field)?;
+    let children = node.get_children_ref();
+    let (name, data_type, marker) = match children {
+        [name, data_type] => (name, data_type, None),
+        [name, data_type, marker] => (name, data_type, Some(marker)),
+        _ => return Err(malformed(node, "a name, a Type and an optional BitWidth or FlexibleArray")),
+    };
+    let mut field = Field::new(&name_of(name, node)?, type_of(data_type, node)?, None, false);
+    match marker.map(|marker| (marker.get_node_type_ref(), marker.get_children_ref())) {
+        None => {},
+        Some((NodeType::FlexibleArray, [])) => field.flexible = true,
+        Some((NodeType::BitWidth, [width])) => {
+            let width = match width.get_node_type_ref() {
+                NodeType::Literal(width) => width.parse::<u32>().ok(),
+                _ => None,
+            };
+            field.bit_width = Some(width.ok_or_else(|| malformed(node, "a BitWidth holding an integer Literal"))?);
+        },
+        Some(_) => return Err(malformed(node, "a BitWidth or FlexibleArray marker")),
+    }
+    Ok(field)
+}
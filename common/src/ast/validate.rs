@@ -0,0 +1,381 @@
+//! This file checks that every node of an `AST` has the children its `NodeType` expects, in the expected order.
+//!
+//! The phases decode nodes by position, so a hand-built tree with a child out of place otherwise fails much later
+//! as a panic deep inside a phase. Each kind has one or more schemas, each a sequence of slots that accept a
+//! class of node kinds once, optionally, or repeatedly. A node is valid if its children match any of its schemas.
+//! Every violation is reported with the `NodePath` of the offending node, so one run lists all of them.
+
+use std::fmt;
+
+use crate::{
+    ast::{
+        core::{ASTNode, AST},
+        node_type::NodeType,
+        path::NodePath,
+    },
+    error::ErrorType,
+};
+
+/// A node whose children do not match any schema of its kind.
+///
+/// # Fields
+/// * `path` - The location of the node.
+/// * `message` - Describes the expected and found children.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    path: NodePath,
+    message: String,
+}
+
+/// How many consecutive children a slot accepts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Repeat {
+    /// Exactly one child.
+    One,
+    /// Zero or one child.
+    Optional,
+    /// Any number of children.
+    Many,
+}
+
+/// A position in a schema.
+///
+/// # Fields
+/// * `description` - The name of the accepted kinds, as shown in violation messages.
+/// * `accepts` - Checks whether a child's kind fits the slot.
+/// * `repeat` - How many consecutive children the slot accepts.
+#[derive(Clone, Copy)]
+struct Slot {
+    description: &'static str,
+    accepts: fn(&NodeType) -> bool,
+    repeat: Repeat,
+}
+
+impl Violation {
+    /// Retrieves the location of the offending node.
+    ///
+    /// # Returns
+    /// Returns the node's `NodePath`.
+    pub fn get_path(&self) -> &NodePath {
+        &self.path
+    }
+
+    /// Retrieves the description of this violation.
+    ///
+    /// # Returns
+    /// Returns the message as a `&str`.
+    pub fn get_message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+impl From<Violation> for ErrorType {
+    fn from(violation: Violation) -> Self {
+        ErrorType::InvalidAST {
+            path: violation.path.to_string(),
+            message: violation.message,
+        }
+    }
+}
+
+/// Validates every node of an `AST`.
+///
+/// # Parameters
+/// * `ast` - The `AST` to validate.
+///
+/// # Returns
+/// Returns every `Violation` found, in pre-order, or an empty vector if the tree is valid.
+pub fn validate(ast: &AST) -> Vec<Violation> {
+    validate_node(ast.get_root_ref())
+}
+
+/// Validates a node and its descendants, with paths relative to `node`.
+///
+/// # Parameters
+/// * `node` - The root of the subtree to validate.
+///
+/// # Returns
+/// Returns every `Violation` found, in pre-order.
+pub fn validate_node(node: &ASTNode) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    check(node, &NodePath::root(node), &mut violations);
+    violations
+}
+
+/// Validates an `AST` as a pre-pass to a phase.
+///
+/// # Parameters
+/// * `ast` - The `AST` to validate.
+///
+/// # Returns
+/// Returns `Ok(())` if the tree is valid.
+///
+/// # Errors
+/// * Returns an `ErrorType::InvalidAST` for every violation found.
+pub fn check_ast(ast: &AST) -> Result<(), Vec<ErrorType>> {
+    let violations = validate(ast);
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(violations.into_iter().map(ErrorType::from).collect())
+    }
+}
+
+/// Validates a node and recurses into its children.
+fn check(node: &ASTNode, path: &NodePath, violations: &mut Vec<Violation>) {
+    let children = node.get_children_ref();
+    let schemas = schemas(node.get_node_type_ref());
+    if !schemas.iter().any(|schema| matches_schema(schema, children)) {
+        let expected: Vec<String> = schemas.iter().map(|schema| describe_schema(schema)).collect();
+        let found: Vec<&str> = children.iter().map(|child| child.get_node_type_ref().get_kind_name()).collect();
+        violations.push(Violation {
+            path: path.clone(),
+            message: format!(
+                "{} expects children {} but found [{}]",
+                node.get_node_type_ref().get_kind_name(),
+                expected.join(" or "),
+                found.join(", "),
+            ),
+        });
+    }
+
+    for (index, child) in children.iter().enumerate() {
+        check(child, &path.child(child, index), violations);
+    }
+}
+
+/// Checks whether a sequence of children fits a schema. Slots are matched greedily, which is unambiguous
+/// because no repeated slot accepts the kinds of the slot after it.
+fn matches_schema(schema: &[Slot], children: &[ASTNode]) -> bool {
+    let mut position = 0;
+    let fits = |position: usize, slot: &Slot| children.get(position).is_some_and(|child| (slot.accepts)(child.get_node_type_ref()));
+    for slot in schema {
+        match slot.repeat {
+            Repeat::One => {
+                if !fits(position, slot) {
+                    return false;
+                }
+                position += 1;
+            },
+            Repeat::Optional => {
+                if fits(position, slot) {
+                    position += 1;
+                }
+            },
+            Repeat::Many => {
+                while fits(position, slot) {
+                    position += 1;
+                }
+            },
+        }
+    }
+    position == children.len()
+}
+
+/// Describes a schema such as `[Identifier, Parameter*, Type]`.
+fn describe_schema(schema: &[Slot]) -> String {
+    let slots: Vec<String> = schema.iter().map(|slot| match slot.repeat {
+        Repeat::One => slot.description.to_string(),
+        Repeat::Optional => format!("{}?", slot.description),
+        Repeat::Many => format!("{}*", slot.description),
+    }).collect();
+    format!("[{}]", slots.join(", "))
+}
+
+/// Builds a slot accepting exactly one child.
+const fn one(description: &'static str, accepts: fn(&NodeType) -> bool) -> Slot {
+    Slot { description, accepts, repeat: Repeat::One }
+}
+
+/// Builds a slot accepting zero or one child.
+const fn optional(description: &'static str, accepts: fn(&NodeType) -> bool) -> Slot {
+    Slot { description, accepts, repeat: Repeat::Optional }
+}
+
+/// Builds a slot accepting any number of children.
+const fn many(description: &'static str, accepts: fn(&NodeType) -> bool) -> Slot {
+    Slot { description, accepts, repeat: Repeat::Many }
+}
+
+/// Checks whether a kind can appear where a value is expected.
+fn is_expression(kind: &NodeType) -> bool {
+    matches!(kind, NodeType::Literal(_) | NodeType::Identifier(_) | NodeType::Constant(_) | NodeType::BinaryExpression
+        | NodeType::UnaryExpression | NodeType::FunctionCall | NodeType::MemberAccess | NodeType::Assignment
+        | NodeType::Variable | NodeType::Operand)
+}
+
+/// Checks whether a kind can appear in a block.
+fn is_statement(kind: &NodeType) -> bool {
+    is_expression(kind) || matches!(kind, NodeType::Initialization | NodeType::IfStatement | NodeType::WhileLoop
+        | NodeType::DoWhileLoop | NodeType::ForLoop | NodeType::SwitchStatement | NodeType::Break | NodeType::Continue
+        | NodeType::Return | NodeType::BlockExpression | NodeType::NoExpression | NodeType::FunctionDeclaration
+        | NodeType::StructDeclaration | NodeType::UnionDeclaration | NodeType::EnumDeclaration)
+}
+
+/// Checks whether a kind can appear in a block, including the arms of a switch.
+fn is_block_item(kind: &NodeType) -> bool {
+    is_statement(kind) || matches!(kind, NodeType::Case | NodeType::Default)
+}
+
+/// Checks whether a kind can appear in a module.
+fn is_module_item(kind: &NodeType) -> bool {
+    is_statement(kind) || matches!(kind, NodeType::TopLevelExpression)
+}
+
+/// Checks whether a kind can be assigned to.
+fn is_assignment_target(kind: &NodeType) -> bool {
+    matches!(kind, NodeType::Identifier(_) | NodeType::Variable | NodeType::MemberAccess)
+}
+
+/// Checks whether a kind can hold an assigned value.
+fn is_assigned_value(kind: &NodeType) -> bool {
+    is_expression(kind) || matches!(kind, NodeType::AssignedValue)
+}
+
+/// Checks whether a kind can open a for loop.
+fn is_loop_initializer(kind: &NodeType) -> bool {
+    is_expression(kind) || matches!(kind, NodeType::Initialization)
+}
+
+/// Checks whether a kind can name a declaration.
+fn is_identifier(kind: &NodeType) -> bool {
+    matches!(kind, NodeType::Identifier(_))
+}
+
+/// Checks whether a kind can name a field.
+fn is_field_name(kind: &NodeType) -> bool {
+    matches!(kind, NodeType::Literal(_) | NodeType::Identifier(_))
+}
+
+/// Checks whether a kind can label a switch case.
+fn is_case_label(kind: &NodeType) -> bool {
+    matches!(kind, NodeType::Literal(_) | NodeType::Constant(_) | NodeType::Identifier(_))
+}
+
+/// Checks whether a kind is a `Literal`.
+fn is_literal(kind: &NodeType) -> bool {
+    matches!(kind, NodeType::Literal(_))
+}
+
+/// Checks whether a kind is a `Type`.
+fn is_type(kind: &NodeType) -> bool {
+    matches!(kind, NodeType::Type(_))
+}
+
+/// Checks whether a kind is an `Operator`.
+fn is_operator(kind: &NodeType) -> bool {
+    matches!(kind, NodeType::Operator(_))
+}
+
+/// Checks whether a kind is a `BlockExpression`.
+fn is_block(kind: &NodeType) -> bool {
+    matches!(kind, NodeType::BlockExpression)
+}
+
+/// Checks whether a kind is a `Condition`.
+fn is_condition(kind: &NodeType) -> bool {
+    matches!(kind, NodeType::Condition)
+}
+
+/// Checks whether a kind can follow `else`.
+fn is_else_body(kind: &NodeType) -> bool {
+    matches!(kind, NodeType::BlockExpression | NodeType::IfStatement)
+}
+
+/// Checks whether a kind is a field marker.
+fn is_field_marker(kind: &NodeType) -> bool {
+    matches!(kind, NodeType::BitWidth | NodeType::FlexibleArray)
+}
+
+const EMPTY: &[&[Slot]] = &[&[]];
+const MODULE: &[&[Slot]] = &[&[many("Statement", is_module_item)]];
+const TOP_LEVEL: &[&[Slot]] = &[&[many("Statement", is_statement)]];
+const BLOCK: &[&[Slot]] = &[&[many("Statement", is_block_item)]];
+const FUNCTION: &[&[Slot]] = &[&[
+    one("Identifier", is_identifier),
+    many("Parameter", |kind| matches!(kind, NodeType::Parameter)),
+    optional("VariadicParameter", |kind| matches!(kind, NodeType::VariadicParameter)),
+    one("Type", is_type),
+    optional("BlockExpression", is_block),
+]];
+const NAME_AND_TYPE: &[&[Slot]] = &[&[one("Identifier", is_identifier), one("Type", is_type)]];
+const AGGREGATE: &[&[Slot]] = &[&[one("Identifier", is_identifier), many("Field", |kind| matches!(kind, NodeType::Field))]];
+const FIELD: &[&[Slot]] = &[&[one("Literal", is_field_name), one("Type", is_type), optional("BitWidth|FlexibleArray", is_field_marker)]];
+const BIT_WIDTH: &[&[Slot]] = &[&[one("Literal", is_literal)]];
+const ENUM: &[&[Slot]] = &[&[one("Identifier", is_identifier), many("Variant", |kind| matches!(kind, NodeType::Variant))]];
+const SINGLE_IDENTIFIER: &[&[Slot]] = &[&[one("Identifier", is_identifier)]];
+const INITIALIZATION: &[&[Slot]] = &[&[one("Variable", |kind| matches!(kind, NodeType::Variable)), optional("AssignedValue", |kind| matches!(kind, NodeType::AssignedValue))]];
+const VARIABLE: &[&[Slot]] = &[
+    &[one("Identifier", is_identifier), optional("Type", is_type)],
+    &[one("Identifier", is_identifier), one("Type", is_type), one("Identifier", is_identifier)],
+];
+const SINGLE_EXPRESSION: &[&[Slot]] = &[&[one("Expression", is_expression)]];
+const OPTIONAL_EXPRESSION: &[&[Slot]] = &[&[optional("Expression", is_expression)]];
+const ASSIGNMENT: &[&[Slot]] = &[&[one("Identifier|Variable|MemberAccess", is_assignment_target), optional("Operator", is_operator), one("Expression", is_assigned_value)]];
+const BINARY: &[&[Slot]] = &[&[one("Expression", is_expression), one("Operator", is_operator), one("Expression", is_expression)]];
+const UNARY: &[&[Slot]] = &[
+    &[one("Operator", is_operator), one("Expression", is_expression)],
+    &[one("Expression", is_expression), one("Operator", is_operator)],
+];
+const CALL: &[&[Slot]] = &[&[one("Identifier", is_identifier), many("Expression", is_expression)]];
+const MEMBER_ACCESS: &[&[Slot]] = &[&[one("Expression", is_expression), one("Identifier", is_identifier)]];
+const IF: &[&[Slot]] = &[&[one("Condition", is_condition), one("BlockExpression", is_block), optional("ElseStatement", |kind| matches!(kind, NodeType::ElseStatement))]];
+const ELSE: &[&[Slot]] = &[&[one("BlockExpression|IfStatement", is_else_body)]];
+const WHILE: &[&[Slot]] = &[&[one("Condition", is_condition), optional("BlockExpression", is_block)]];
+const DO_WHILE: &[&[Slot]] = &[&[one("BlockExpression", is_block), one("Condition", is_condition)]];
+const FOR: &[&[Slot]] = &[&[
+    one("LoopInitializer", |kind| matches!(kind, NodeType::LoopInitializer)),
+    one("Condition", is_condition),
+    one("LoopIncrement", |kind| matches!(kind, NodeType::LoopIncrement)),
+    one("BlockExpression", is_block),
+]];
+const LOOP_INITIALIZER: &[&[Slot]] = &[&[optional("Initialization|Expression", is_loop_initializer)]];
+const SWITCH: &[&[Slot]] = &[&[one("Expression", is_expression), one("BlockExpression", is_block)]];
+const CASE: &[&[Slot]] = &[&[one("Literal", is_case_label), one("BlockExpression", is_block)]];
+const DEFAULT: &[&[Slot]] = &[&[one("BlockExpression", is_block)]];
+const RETURN: &[&[Slot]] = &[&[optional("AssignedValue", |kind| matches!(kind, NodeType::AssignedValue))]];
+const ACTION: &[&[Slot]] = &[&[many("Statement", is_statement)]];
+
+/// Retrieves the schemas a node of the given kind may match.
+fn schemas(kind: &NodeType) -> &'static [&'static [Slot]] {
+    match kind {
+        NodeType::NoExpression | NodeType::Literal(_) | NodeType::Identifier(_) | NodeType::Operator(_) | NodeType::Type(_)
+        | NodeType::Constant(_) | NodeType::Break | NodeType::Continue | NodeType::FlexibleArray | NodeType::VariadicParameter => EMPTY,
+        NodeType::ModuleExpression => MODULE,
+        NodeType::TopLevelExpression => TOP_LEVEL,
+        NodeType::BlockExpression => BLOCK,
+        NodeType::FunctionDeclaration => FUNCTION,
+        NodeType::Parameter => NAME_AND_TYPE,
+        NodeType::StructDeclaration | NodeType::UnionDeclaration => AGGREGATE,
+        NodeType::Field => FIELD,
+        NodeType::BitWidth => BIT_WIDTH,
+        NodeType::EnumDeclaration => ENUM,
+        NodeType::Variant => SINGLE_IDENTIFIER,
+        NodeType::Initialization => INITIALIZATION,
+        NodeType::Variable => VARIABLE,
+        NodeType::AssignedValue | NodeType::Operand => SINGLE_EXPRESSION,
+        NodeType::Condition | NodeType::LoopIncrement => OPTIONAL_EXPRESSION,
+        NodeType::Assignment => ASSIGNMENT,
+        NodeType::BinaryExpression => BINARY,
+        NodeType::UnaryExpression => UNARY,
+        NodeType::FunctionCall => CALL,
+        NodeType::MemberAccess => MEMBER_ACCESS,
+        NodeType::IfStatement => IF,
+        NodeType::ElseStatement => ELSE,
+        NodeType::WhileLoop => WHILE,
+        NodeType::DoWhileLoop => DO_WHILE,
+        NodeType::ForLoop => FOR,
+        NodeType::LoopInitializer => LOOP_INITIALIZER,
+        NodeType::SwitchStatement => SWITCH,
+        NodeType::Case => CASE,
+        NodeType::Default => DEFAULT,
+        NodeType::Return => RETURN,
+        NodeType::Action => ACTION,
+    }
+}
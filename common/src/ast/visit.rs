@@ -0,0 +1,1011 @@
+//! This file defines traits for traversing and transforming an `AST` without rewriting the recursive match
+//! over `NodeType` that every phase otherwise carries.
+//!
+//! Each trait has one method per node kind, whose default implementation walks the node's children in order.
+//! An analysis overrides only the kinds it cares about, calling `walk_children`, `walk_children_mut` or
+//! `fold_children` from its override to keep descending. The `pre_` and `post_` hooks run around every node
+//! regardless of its kind.
+//!
+//! * `Visitor` reads the tree through shared references.
+//! * `VisitorMut` edits the tree in place through mutable references.
+//! * `Fold` consumes the tree and rebuilds it, so nodes can be replaced by nodes of a different kind.
+
+use crate::ast::{
+    core::{ASTNode, AST},
+    node_type::NodeType,
+};
+
+/// Traverses an `AST` through shared references.
+///
+/// # Examples
+/// ```
+/// use common::ast::{core::{ASTNode, AST}, node_type::NodeType, visit::{walk_children, Visitor}};
+///
+/// struct IdentifierCounter {
+///     count: usize,
+/// }
+///
+/// impl Visitor for IdentifierCounter {
+///     fn visit_identifier(&mut self, node: &ASTNode) {
+///         self.count += 1;
+///         walk_children(self, node);
+///     }
+/// }
+///
+/// let mut root = ASTNode::new(NodeType::BinaryExpression);
+/// root.add_children(vec![
+///     ASTNode::new(NodeType::Identifier("a".to_string())),
+///     ASTNode::new(NodeType::Operator("+".to_string())),
+///     ASTNode::new(NodeType::Identifier("b".to_string())),
+/// ]);
+///
+/// let mut counter = IdentifierCounter { count: 0 };
+/// counter.visit_ast(&AST::new(root));
+/// assert_eq!(counter.count, 2);
+/// ```
+pub trait Visitor {
+    /// Visits every node of an `AST`, starting at its root.
+    ///
+    /// # Parameters
+    /// * `ast` - The `AST` to visit.
+    fn visit_ast(&mut self, ast: &AST) {
+        self.visit(ast.get_root_ref());
+    }
+
+    /// Visits a node: runs `pre_visit`, dispatches to the method for the node's kind unless `pre_visit`
+    /// returned `false`, then runs `post_visit`.
+    ///
+    /// # Parameters
+    /// * `node` - The node to visit.
+    fn visit(&mut self, node: &ASTNode) {
+        if self.pre_visit(node) {
+            dispatch_visit(self, node);
+        }
+        self.post_visit(node);
+    }
+
+    /// Runs before a node of any kind is visited.
+    ///
+    /// # Parameters
+    /// * `node` - The node about to be visited.
+    ///
+    /// # Returns
+    /// Returns `true` to visit the node and its children, or `false` to skip them.
+    fn pre_visit(&mut self, node: &ASTNode) -> bool {
+        let _ = node;
+        true
+    }
+
+    /// Runs after a node of any kind and its children have been visited.
+    ///
+    /// # Parameters
+    /// * `node` - The node that was visited.
+    fn post_visit(&mut self, node: &ASTNode) {
+        let _ = node;
+    }
+
+    /// Visits a `NoExpression` node. By default, visits its children.
+    fn visit_no_expression(&mut self, node: &ASTNode) {
+        walk_children(self, node);
+    }
+
+    /// Visits a `Literal` node. By default, visits its children.
+    fn visit_literal(&mut self, node: &ASTNode) {
+        walk_children(self, node);
+    }
+
+    /// Visits an `Identifier` node. By default, visits its children.
+    fn visit_identifier(&mut self, node: &ASTNode) {
+        walk_children(self, node);
+    }
+
+    /// Visits an `Operator` node. By default, visits its children.
+    fn visit_operator(&mut self, node: &ASTNode) {
+        walk_children(self, node);
+    }
+
+    /// Visits a `Type` node. By default, visits its children.
+    fn visit_type(&mut self, node: &ASTNode) {
+        walk_children(self, node);
+    }
+
+    /// Visits a `Constant` node. By default, visits its children.
+    fn visit_constant(&mut self, node: &ASTNode) {
+        walk_children(self, node);
+    }
+
+    /// Visits an `IfStatement` node. By default, visits its children.
+    fn visit_if_statement(&mut self, node: &ASTNode) {
+        walk_children(self, node);
+    }
+
+    /// Visits an `ElseStatement` node. By default, visits its children.
+    fn visit_else_statement(&mut self, node: &ASTNode) {
+        walk_children(self, node);
+    }
+
+    /// Visits a `ForLoop` node. By default, visits its children.
+    fn visit_for_loop(&mut self, node: &ASTNode) {
+        walk_children(self, node);
+    }
+
+    /// Visits a `WhileLoop` node. By default, visits its children.
+    fn visit_while_loop(&mut self, node: &ASTNode) {
+        walk_children(self, node);
+    }
+
+    /// Visits a `DoWhileLoop` node. By default, visits its children.
+    fn visit_do_while_loop(&mut self, node: &ASTNode) {
+        walk_children(self, node);
+    }
+
+    /// Visits a `Break` node. By default, visits its children.
+    fn visit_break(&mut self, node: &ASTNode) {
+        walk_children(self, node);
+    }
+
+    /// Visits a `Continue` node. By default, visits its children.
+    fn visit_continue(&mut self, node: &ASTNode) {
+        walk_children(self, node);
+    }
+
+    /// Visits a `Return` node. By default, visits its children.
+    fn visit_return(&mut self, node: &ASTNode) {
+        walk_children(self, node);
+    }
+
+    /// Visits a `SwitchStatement` node. By default, visits its children.
+    fn visit_switch_statement(&mut self, node: &ASTNode) {
+        walk_children(self, node);
+    }
+
+    /// Visits a `Case` node. By default, visits its children.
+    fn visit_case(&mut self, node: &ASTNode) {
+        walk_children(self, node);
+    }
+
+    /// Visits a `Default` node. By default, visits its children.
+    fn visit_default(&mut self, node: &ASTNode) {
+        walk_children(self, node);
+    }
+
+    /// Visits an `Assignment` node. By default, visits its children.
+    fn visit_assignment(&mut self, node: &ASTNode) {
+        walk_children(self, node);
+    }
+
+    /// Visits an `Initialization` node. By default, visits its children.
+    fn visit_initialization(&mut self, node: &ASTNode) {
+        walk_children(self, node);
+    }
+
+    /// Visits a `FunctionDeclaration` node. By default, visits its children.
+    fn visit_function_declaration(&mut self, node: &ASTNode) {
+        walk_children(self, node);
+    }
+
+    /// Visits a `StructDeclaration` node. By default, visits its children.
+    fn visit_struct_declaration(&mut self, node: &ASTNode) {
+        walk_children(self, node);
+    }
+
+    /// Visits an `EnumDeclaration` node. By default, visits its children.
+    fn visit_enum_declaration(&mut self, node: &ASTNode) {
+        walk_children(self, node);
+    }
+
+    /// Visits a `UnionDeclaration` node. By default, visits its children.
+    fn visit_union_declaration(&mut self, node: &ASTNode) {
+        walk_children(self, node);
+    }
+
+    /// Visits a `ModuleExpression` node. By default, visits its children.
+    fn visit_module_expression(&mut self, node: &ASTNode) {
+        walk_children(self, node);
+    }
+
+    /// Visits a `TopLevelExpression` node. By default, visits its children.
+    fn visit_top_level_expression(&mut self, node: &ASTNode) {
+        walk_children(self, node);
+    }
+
+    /// Visits a `BlockExpression` node. By default, visits its children.
+    fn visit_block_expression(&mut self, node: &ASTNode) {
+        walk_children(self, node);
+    }
+
+    /// Visits a `Condition` node. By default, visits its children.
+    fn visit_condition(&mut self, node: &ASTNode) {
+        walk_children(self, node);
+    }
+
+    /// Visits an `Action` node. By default, visits its children.
+    fn visit_action(&mut self, node: &ASTNode) {
+        walk_children(self, node);
+    }
+
+    /// Visits a `Variant` node. By default, visits its children.
+    fn visit_variant(&mut self, node: &ASTNode) {
+        walk_children(self, node);
+    }
+
+    /// Visits an `AssignedValue` node. By default, visits its children.
+    fn visit_assigned_value(&mut self, node: &ASTNode) {
+        walk_children(self, node);
+    }
+
+    /// Visits a `Field` node. By default, visits its children.
+    fn visit_field(&mut self, node: &ASTNode) {
+        walk_children(self, node);
+    }
+
+    /// Visits a `BitWidth` node. By default, visits its children.
+    fn visit_bit_width(&mut self, node: &ASTNode) {
+        walk_children(self, node);
+    }
+
+    /// Visits a `FlexibleArray` node. By default, visits its children.
+    fn visit_flexible_array(&mut self, node: &ASTNode) {
+        walk_children(self, node);
+    }
+
+    /// Visits a `MemberAccess` node. By default, visits its children.
+    fn visit_member_access(&mut self, node: &ASTNode) {
+        walk_children(self, node);
+    }
+
+    /// Visits a `Parameter` node. By default, visits its children.
+    fn visit_parameter(&mut self, node: &ASTNode) {
+        walk_children(self, node);
+    }
+
+    /// Visits a `VariadicParameter` node. By default, visits its children.
+    fn visit_variadic_parameter(&mut self, node: &ASTNode) {
+        walk_children(self, node);
+    }
+
+    /// Visits a `Variable` node. By default, visits its children.
+    fn visit_variable(&mut self, node: &ASTNode) {
+        walk_children(self, node);
+    }
+
+    /// Visits a `BinaryExpression` node. By default, visits its children.
+    fn visit_binary_expression(&mut self, node: &ASTNode) {
+        walk_children(self, node);
+    }
+
+    /// Visits a `UnaryExpression` node. By default, visits its children.
+    fn visit_unary_expression(&mut self, node: &ASTNode) {
+        walk_children(self, node);
+    }
+
+    /// Visits a `FunctionCall` node. By default, visits its children.
+    fn visit_function_call(&mut self, node: &ASTNode) {
+        walk_children(self, node);
+    }
+
+    /// Visits an `Operand` node. By default, visits its children.
+    fn visit_operand(&mut self, node: &ASTNode) {
+        walk_children(self, node);
+    }
+
+    /// Visits a `LoopInitializer` node. By default, visits its children.
+    fn visit_loop_initializer(&mut self, node: &ASTNode) {
+        walk_children(self, node);
+    }
+
+    /// Visits a `LoopIncrement` node. By default, visits its children.
+    fn visit_loop_increment(&mut self, node: &ASTNode) {
+        walk_children(self, node);
+    }
+}
+
+/// Traverses an `AST` through mutable references, editing it in place.
+pub trait VisitorMut {
+    /// Visits every node of an `AST`, starting at its root.
+    ///
+    /// # Parameters
+    /// * `ast` - The `AST` to visit.
+    fn visit_ast_mut(&mut self, ast: &mut AST) {
+        self.visit_mut(ast.get_root_mut());
+    }
+
+    /// Visits a node: runs `pre_visit_mut`, dispatches to the method for the node's kind unless
+    /// `pre_visit_mut` returned `false`, then runs `post_visit_mut`.
+    ///
+    /// # Parameters
+    /// * `node` - The node to visit.
+    fn visit_mut(&mut self, node: &mut ASTNode) {
+        if self.pre_visit_mut(node) {
+            dispatch_visit_mut(self, node);
+        }
+        self.post_visit_mut(node);
+    }
+
+    /// Runs before a node of any kind is visited.
+    ///
+    /// # Parameters
+    /// * `node` - The node about to be visited.
+    ///
+    /// # Returns
+    /// Returns `true` to visit the node and its children, or `false` to skip them.
+    fn pre_visit_mut(&mut self, node: &mut ASTNode) -> bool {
+        let _ = node;
+        true
+    }
+
+    /// Runs after a node of any kind and its children have been visited.
+    ///
+    /// # Parameters
+    /// * `node` - The node that was visited.
+    fn post_visit_mut(&mut self, node: &mut ASTNode) {
+        let _ = node;
+    }
+
+    /// Visits a `NoExpression` node. By default, visits its children.
+    fn visit_no_expression_mut(&mut self, node: &mut ASTNode) {
+        walk_children_mut(self, node);
+    }
+
+    /// Visits a `Literal` node. By default, visits its children.
+    fn visit_literal_mut(&mut self, node: &mut ASTNode) {
+        walk_children_mut(self, node);
+    }
+
+    /// Visits an `Identifier` node. By default, visits its children.
+    fn visit_identifier_mut(&mut self, node: &mut ASTNode) {
+        walk_children_mut(self, node);
+    }
+
+    /// Visits an `Operator` node. By default, visits its children.
+    fn visit_operator_mut(&mut self, node: &mut ASTNode) {
+        walk_children_mut(self, node);
+    }
+
+    /// Visits a `Type` node. By default, visits its children.
+    fn visit_type_mut(&mut self, node: &mut ASTNode) {
+        walk_children_mut(self, node);
+    }
+
+    /// Visits a `Constant` node. By default, visits its children.
+    fn visit_constant_mut(&mut self, node: &mut ASTNode) {
+        walk_children_mut(self, node);
+    }
+
+    /// Visits an `IfStatement` node. By default, visits its children.
+    fn visit_if_statement_mut(&mut self, node: &mut ASTNode) {
+        walk_children_mut(self, node);
+    }
+
+    /// Visits an `ElseStatement` node. By default, visits its children.
+    fn visit_else_statement_mut(&mut self, node: &mut ASTNode) {
+        walk_children_mut(self, node);
+    }
+
+    /// Visits a `ForLoop` node. By default, visits its children.
+    fn visit_for_loop_mut(&mut self, node: &mut ASTNode) {
+        walk_children_mut(self, node);
+    }
+
+    /// Visits a `WhileLoop` node. By default, visits its children.
+    fn visit_while_loop_mut(&mut self, node: &mut ASTNode) {
+        walk_children_mut(self, node);
+    }
+
+    /// Visits a `DoWhileLoop` node. By default, visits its children.
+    fn visit_do_while_loop_mut(&mut self, node: &mut ASTNode) {
+        walk_children_mut(self, node);
+    }
+
+    /// Visits a `Break` node. By default, visits its children.
+    fn visit_break_mut(&mut self, node: &mut ASTNode) {
+        walk_children_mut(self, node);
+    }
+
+    /// Visits a `Continue` node. By default, visits its children.
+    fn visit_continue_mut(&mut self, node: &mut ASTNode) {
+        walk_children_mut(self, node);
+    }
+
+    /// Visits a `Return` node. By default, visits its children.
+    fn visit_return_mut(&mut self, node: &mut ASTNode) {
+        walk_children_mut(self, node);
+    }
+
+    /// Visits a `SwitchStatement` node. By default, visits its children.
+    fn visit_switch_statement_mut(&mut self, node: &mut ASTNode) {
+        walk_children_mut(self, node);
+    }
+
+    /// Visits a `Case` node. By default, visits its children.
+    fn visit_case_mut(&mut self, node: &mut ASTNode) {
+        walk_children_mut(self, node);
+    }
+
+    /// Visits a `Default` node. By default, visits its children.
+    fn visit_default_mut(&mut self, node: &mut ASTNode) {
+        walk_children_mut(self, node);
+    }
+
+    /// Visits an `Assignment` node. By default, visits its children.
+    fn visit_assignment_mut(&mut self, node: &mut ASTNode) {
+        walk_children_mut(self, node);
+    }
+
+    /// Visits an `Initialization` node. By default, visits its children.
+    fn visit_initialization_mut(&mut self, node: &mut ASTNode) {
+        walk_children_mut(self, node);
+    }
+
+    /// Visits a `FunctionDeclaration` node. By default, visits its children.
+    fn visit_function_declaration_mut(&mut self, node: &mut ASTNode) {
+        walk_children_mut(self, node);
+    }
+
+    /// Visits a `StructDeclaration` node. By default, visits its children.
+    fn visit_struct_declaration_mut(&mut self, node: &mut ASTNode) {
+        walk_children_mut(self, node);
+    }
+
+    /// Visits an `EnumDeclaration` node. By default, visits its children.
+    fn visit_enum_declaration_mut(&mut self, node: &mut ASTNode) {
+        walk_children_mut(self, node);
+    }
+
+    /// Visits a `UnionDeclaration` node. By default, visits its children.
+    fn visit_union_declaration_mut(&mut self, node: &mut ASTNode) {
+        walk_children_mut(self, node);
+    }
+
+    /// Visits a `ModuleExpression` node. By default, visits its children.
+    fn visit_module_expression_mut(&mut self, node: &mut ASTNode) {
+        walk_children_mut(self, node);
+    }
+
+    /// Visits a `TopLevelExpression` node. By default, visits its children.
+    fn visit_top_level_expression_mut(&mut self, node: &mut ASTNode) {
+        walk_children_mut(self, node);
+    }
+
+    /// Visits a `BlockExpression` node. By default, visits its children.
+    fn visit_block_expression_mut(&mut self, node: &mut ASTNode) {
+        walk_children_mut(self, node);
+    }
+
+    /// Visits a `Condition` node. By default, visits its children.
+    fn visit_condition_mut(&mut self, node: &mut ASTNode) {
+        walk_children_mut(self, node);
+    }
+
+    /// Visits an `Action` node. By default, visits its children.
+    fn visit_action_mut(&mut self, node: &mut ASTNode) {
+        walk_children_mut(self, node);
+    }
+
+    /// Visits a `Variant` node. By default, visits its children.
+    fn visit_variant_mut(&mut self, node: &mut ASTNode) {
+        walk_children_mut(self, node);
+    }
+
+    /// Visits an `AssignedValue` node. By default, visits its children.
+    fn visit_assigned_value_mut(&mut self, node: &mut ASTNode) {
+        walk_children_mut(self, node);
+    }
+
+    /// Visits a `Field` node. By default, visits its children.
+    fn visit_field_mut(&mut self, node: &mut ASTNode) {
+        walk_children_mut(self, node);
+    }
+
+    /// Visits a `BitWidth` node. By default, visits its children.
+    fn visit_bit_width_mut(&mut self, node: &mut ASTNode) {
+        walk_children_mut(self, node);
+    }
+
+    /// Visits a `FlexibleArray` node. By default, visits its children.
+    fn visit_flexible_array_mut(&mut self, node: &mut ASTNode) {
+        walk_children_mut(self, node);
+    }
+
+    /// Visits a `MemberAccess` node. By default, visits its children.
+    fn visit_member_access_mut(&mut self, node: &mut ASTNode) {
+        walk_children_mut(self, node);
+    }
+
+    /// Visits a `Parameter` node. By default, visits its children.
+    fn visit_parameter_mut(&mut self, node: &mut ASTNode) {
+        walk_children_mut(self, node);
+    }
+
+    /// Visits a `VariadicParameter` node. By default, visits its children.
+    fn visit_variadic_parameter_mut(&mut self, node: &mut ASTNode) {
+        walk_children_mut(self, node);
+    }
+
+    /// Visits a `Variable` node. By default, visits its children.
+    fn visit_variable_mut(&mut self, node: &mut ASTNode) {
+        walk_children_mut(self, node);
+    }
+
+    /// Visits a `BinaryExpression` node. By default, visits its children.
+    fn visit_binary_expression_mut(&mut self, node: &mut ASTNode) {
+        walk_children_mut(self, node);
+    }
+
+    /// Visits a `UnaryExpression` node. By default, visits its children.
+    fn visit_unary_expression_mut(&mut self, node: &mut ASTNode) {
+        walk_children_mut(self, node);
+    }
+
+    /// Visits a `FunctionCall` node. By default, visits its children.
+    fn visit_function_call_mut(&mut self, node: &mut ASTNode) {
+        walk_children_mut(self, node);
+    }
+
+    /// Visits an `Operand` node. By default, visits its children.
+    fn visit_operand_mut(&mut self, node: &mut ASTNode) {
+        walk_children_mut(self, node);
+    }
+
+    /// Visits a `LoopInitializer` node. By default, visits its children.
+    fn visit_loop_initializer_mut(&mut self, node: &mut ASTNode) {
+        walk_children_mut(self, node);
+    }
+
+    /// Visits a `LoopIncrement` node. By default, visits its children.
+    fn visit_loop_increment_mut(&mut self, node: &mut ASTNode) {
+        walk_children_mut(self, node);
+    }
+}
+
+/// Consumes an `AST` and rebuilds it, allowing nodes to be replaced by nodes of any kind.
+pub trait Fold {
+    /// Folds an `AST`, starting at its root.
+    ///
+    /// # Parameters
+    /// * `ast` - The `AST` to fold.
+    ///
+    /// # Returns
+    /// Returns the rebuilt `AST`.
+    fn fold_ast(&mut self, ast: AST) -> AST {
+        let mut ast = ast;
+        let root = std::mem::take(ast.get_root_mut());
+        AST::new(self.fold(root))
+    }
+
+    /// Folds a node: runs `pre_fold`, dispatches to the method for the kind of the node it returned, then
+    /// runs `post_fold` on the result.
+    ///
+    /// # Parameters
+    /// * `node` - The node to fold.
+    ///
+    /// # Returns
+    /// Returns the rebuilt node.
+    fn fold(&mut self, node: ASTNode) -> ASTNode {
+        let node = self.pre_fold(node);
+        let node = dispatch_fold(self, node);
+        self.post_fold(node)
+    }
+
+    /// Runs before a node of any kind is folded, and may replace it.
+    ///
+    /// # Parameters
+    /// * `node` - The node about to be folded.
+    ///
+    /// # Returns
+    /// Returns the node to fold in its place.
+    fn pre_fold(&mut self, node: ASTNode) -> ASTNode {
+        node
+    }
+
+    /// Runs after a node of any kind and its children have been folded, and may replace it.
+    ///
+    /// # Parameters
+    /// * `node` - The folded node.
+    ///
+    /// # Returns
+    /// Returns the final node.
+    fn post_fold(&mut self, node: ASTNode) -> ASTNode {
+        node
+    }
+
+    /// Folds a `NoExpression` node. By default, folds its children.
+    fn fold_no_expression(&mut self, node: ASTNode) -> ASTNode {
+        fold_children(self, node)
+    }
+
+    /// Folds a `Literal` node. By default, folds its children.
+    fn fold_literal(&mut self, node: ASTNode) -> ASTNode {
+        fold_children(self, node)
+    }
+
+    /// Folds an `Identifier` node. By default, folds its children.
+    fn fold_identifier(&mut self, node: ASTNode) -> ASTNode {
+        fold_children(self, node)
+    }
+
+    /// Folds an `Operator` node. By default, folds its children.
+    fn fold_operator(&mut self, node: ASTNode) -> ASTNode {
+        fold_children(self, node)
+    }
+
+    /// Folds a `Type` node. By default, folds its children.
+    fn fold_type(&mut self, node: ASTNode) -> ASTNode {
+        fold_children(self, node)
+    }
+
+    /// Folds a `Constant` node. By default, folds its children.
+    fn fold_constant(&mut self, node: ASTNode) -> ASTNode {
+        fold_children(self, node)
+    }
+
+    /// Folds an `IfStatement` node. By default, folds its children.
+    fn fold_if_statement(&mut self, node: ASTNode) -> ASTNode {
+        fold_children(self, node)
+    }
+
+    /// Folds an `ElseStatement` node. By default, folds its children.
+    fn fold_else_statement(&mut self, node: ASTNode) -> ASTNode {
+        fold_children(self, node)
+    }
+
+    /// Folds a `ForLoop` node. By default, folds its children.
+    fn fold_for_loop(&mut self, node: ASTNode) -> ASTNode {
+        fold_children(self, node)
+    }
+
+    /// Folds a `WhileLoop` node. By default, folds its children.
+    fn fold_while_loop(&mut self, node: ASTNode) -> ASTNode {
+        fold_children(self, node)
+    }
+
+    /// Folds a `DoWhileLoop` node. By default, folds its children.
+    fn fold_do_while_loop(&mut self, node: ASTNode) -> ASTNode {
+        fold_children(self, node)
+    }
+
+    /// Folds a `Break` node. By default, folds its children.
+    fn fold_break(&mut self, node: ASTNode) -> ASTNode {
+        fold_children(self, node)
+    }
+
+    /// Folds a `Continue` node. By default, folds its children.
+    fn fold_continue(&mut self, node: ASTNode) -> ASTNode {
+        fold_children(self, node)
+    }
+
+    /// Folds a `Return` node. By default, folds its children.
+    fn fold_return(&mut self, node: ASTNode) -> ASTNode {
+        fold_children(self, node)
+    }
+
+    /// Folds a `SwitchStatement` node. By default, folds its children.
+    fn fold_switch_statement(&mut self, node: ASTNode) -> ASTNode {
+        fold_children(self, node)
+    }
+
+    /// Folds a `Case` node. By default, folds its children.
+    fn fold_case(&mut self, node: ASTNode) -> ASTNode {
+        fold_children(self, node)
+    }
+
+    /// Folds a `Default` node. By default, folds its children.
+    fn fold_default(&mut self, node: ASTNode) -> ASTNode {
+        fold_children(self, node)
+    }
+
+    /// Folds an `Assignment` node. By default, folds its children.
+    fn fold_assignment(&mut self, node: ASTNode) -> ASTNode {
+        fold_children(self, node)
+    }
+
+    /// Folds an `Initialization` node. By default, folds its children.
+    fn fold_initialization(&mut self, node: ASTNode) -> ASTNode {
+        fold_children(self, node)
+    }
+
+    /// Folds a `FunctionDeclaration` node. By default, folds its children.
+    fn fold_function_declaration(&mut self, node: ASTNode) -> ASTNode {
+        fold_children(self, node)
+    }
+
+    /// Folds a `StructDeclaration` node. By default, folds its children.
+    fn fold_struct_declaration(&mut self, node: ASTNode) -> ASTNode {
+        fold_children(self, node)
+    }
+
+    /// Folds an `EnumDeclaration` node. By default, folds its children.
+    fn fold_enum_declaration(&mut self, node: ASTNode) -> ASTNode {
+        fold_children(self, node)
+    }
+
+    /// Folds a `UnionDeclaration` node. By default, folds its children.
+    fn fold_union_declaration(&mut self, node: ASTNode) -> ASTNode {
+        fold_children(self, node)
+    }
+
+    /// Folds a `ModuleExpression` node. By default, folds its children.
+    fn fold_module_expression(&mut self, node: ASTNode) -> ASTNode {
+        fold_children(self, node)
+    }
+
+    /// Folds a `TopLevelExpression` node. By default, folds its children.
+    fn fold_top_level_expression(&mut self, node: ASTNode) -> ASTNode {
+        fold_children(self, node)
+    }
+
+    /// Folds a `BlockExpression` node. By default, folds its children.
+    fn fold_block_expression(&mut self, node: ASTNode) -> ASTNode {
+        fold_children(self, node)
+    }
+
+    /// Folds a `Condition` node. By default, folds its children.
+    fn fold_condition(&mut self, node: ASTNode) -> ASTNode {
+        fold_children(self, node)
+    }
+
+    /// Folds an `Action` node. By default, folds its children.
+    fn fold_action(&mut self, node: ASTNode) -> ASTNode {
+        fold_children(self, node)
+    }
+
+    /// Folds a `Variant` node. By default, folds its children.
+    fn fold_variant(&mut self, node: ASTNode) -> ASTNode {
+        fold_children(self, node)
+    }
+
+    /// Folds an `AssignedValue` node. By default, folds its children.
+    fn fold_assigned_value(&mut self, node: ASTNode) -> ASTNode {
+        fold_children(self, node)
+    }
+
+    /// Folds a `Field` node. By default, folds its children.
+    fn fold_field(&mut self, node: ASTNode) -> ASTNode {
+        fold_children(self, node)
+    }
+
+    /// Folds a `BitWidth` node. By default, folds its children.
+    fn fold_bit_width(&mut self, node: ASTNode) -> ASTNode {
+        fold_children(self, node)
+    }
+
+    /// Folds a `FlexibleArray` node. By default, folds its children.
+    fn fold_flexible_array(&mut self, node: ASTNode) -> ASTNode {
+        fold_children(self, node)
+    }
+
+    /// Folds a `MemberAccess` node. By default, folds its children.
+    fn fold_member_access(&mut self, node: ASTNode) -> ASTNode {
+        fold_children(self, node)
+    }
+
+    /// Folds a `Parameter` node. By default, folds its children.
+    fn fold_parameter(&mut self, node: ASTNode) -> ASTNode {
+        fold_children(self, node)
+    }
+
+    /// Folds a `VariadicParameter` node. By default, folds its children.
+    fn fold_variadic_parameter(&mut self, node: ASTNode) -> ASTNode {
+        fold_children(self, node)
+    }
+
+    /// Folds a `Variable` node. By default, folds its children.
+    fn fold_variable(&mut self, node: ASTNode) -> ASTNode {
+        fold_children(self, node)
+    }
+
+    /// Folds a `BinaryExpression` node. By default, folds its children.
+    fn fold_binary_expression(&mut self, node: ASTNode) -> ASTNode {
+        fold_children(self, node)
+    }
+
+    /// Folds a `UnaryExpression` node. By default, folds its children.
+    fn fold_unary_expression(&mut self, node: ASTNode) -> ASTNode {
+        fold_children(self, node)
+    }
+
+    /// Folds a `FunctionCall` node. By default, folds its children.
+    fn fold_function_call(&mut self, node: ASTNode) -> ASTNode {
+        fold_children(self, node)
+    }
+
+    /// Folds an `Operand` node. By default, folds its children.
+    fn fold_operand(&mut self, node: ASTNode) -> ASTNode {
+        fold_children(self, node)
+    }
+
+    /// Folds a `LoopInitializer` node. By default, folds its children.
+    fn fold_loop_initializer(&mut self, node: ASTNode) -> ASTNode {
+        fold_children(self, node)
+    }
+
+    /// Folds a `LoopIncrement` node. By default, folds its children.
+    fn fold_loop_increment(&mut self, node: ASTNode) -> ASTNode {
+        fold_children(self, node)
+    }
+}
+
+/// Visits each child of a node in order.
+///
+/// # Parameters
+/// * `visitor` - The visitor to visit the children with.
+/// * `node` - The node whose children are visited.
+pub fn walk_children<V: Visitor + ?Sized>(visitor: &mut V, node: &ASTNode) {
+    for child in node.get_children_ref() {
+        visitor.visit(child);
+    }
+}
+
+/// Visits each child of a node in order through mutable references.
+///
+/// # Parameters
+/// * `visitor` - The visitor to visit the children with.
+/// * `node` - The node whose children are visited.
+pub fn walk_children_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut ASTNode) {
+    for child in node.get_children_mut().iter_mut() {
+        visitor.visit_mut(child);
+    }
+}
+
+/// Folds each child of a node in order, replacing the node's children with the results.
+///
+/// # Parameters
+/// * `folder` - The folder to fold the children with.
+/// * `node` - The node whose children are folded.
+///
+/// # Returns
+/// Returns the node with its folded children.
+pub fn fold_children<F: Fold + ?Sized>(folder: &mut F, node: ASTNode) -> ASTNode {
+    let mut node = node;
+    let children = std::mem::take(node.get_children_mut());
+    let folded: Vec<ASTNode> = children.into_iter().map(|child| folder.fold(child)).collect();
+    node.set_children(folded);
+    node
+}
+
+/// Calls the `Visitor` method matching the kind of a node.
+fn dispatch_visit<V: Visitor + ?Sized>(visitor: &mut V, node: &ASTNode) {
+    match node.get_node_type_ref() {
+        NodeType::NoExpression => visitor.visit_no_expression(node),
+        NodeType::Literal(_) => visitor.visit_literal(node),
+        NodeType::Identifier(_) => visitor.visit_identifier(node),
+        NodeType::Operator(_) => visitor.visit_operator(node),
+        NodeType::Type(_) => visitor.visit_type(node),
+        NodeType::Constant(_) => visitor.visit_constant(node),
+        NodeType::IfStatement => visitor.visit_if_statement(node),
+        NodeType::ElseStatement => visitor.visit_else_statement(node),
+        NodeType::ForLoop => visitor.visit_for_loop(node),
+        NodeType::WhileLoop => visitor.visit_while_loop(node),
+        NodeType::DoWhileLoop => visitor.visit_do_while_loop(node),
+        NodeType::Break => visitor.visit_break(node),
+        NodeType::Continue => visitor.visit_continue(node),
+        NodeType::Return => visitor.visit_return(node),
+        NodeType::SwitchStatement => visitor.visit_switch_statement(node),
+        NodeType::Case => visitor.visit_case(node),
+        NodeType::Default => visitor.visit_default(node),
+        NodeType::Assignment => visitor.visit_assignment(node),
+        NodeType::Initialization => visitor.visit_initialization(node),
+        NodeType::FunctionDeclaration => visitor.visit_function_declaration(node),
+        NodeType::StructDeclaration => visitor.visit_struct_declaration(node),
+        NodeType::EnumDeclaration => visitor.visit_enum_declaration(node),
+        NodeType::UnionDeclaration => visitor.visit_union_declaration(node),
+        NodeType::ModuleExpression => visitor.visit_module_expression(node),
+        NodeType::TopLevelExpression => visitor.visit_top_level_expression(node),
+        NodeType::BlockExpression => visitor.visit_block_expression(node),
+        NodeType::Condition => visitor.visit_condition(node),
+        NodeType::Action => visitor.visit_action(node),
+        NodeType::Variant => visitor.visit_variant(node),
+        NodeType::AssignedValue => visitor.visit_assigned_value(node),
+        NodeType::Field => visitor.visit_field(node),
+        NodeType::BitWidth => visitor.visit_bit_width(node),
+        NodeType::FlexibleArray => visitor.visit_flexible_array(node),
+        NodeType::MemberAccess => visitor.visit_member_access(node),
+        NodeType::Parameter => visitor.visit_parameter(node),
+        NodeType::VariadicParameter => visitor.visit_variadic_parameter(node),
+        NodeType::Variable => visitor.visit_variable(node),
+        NodeType::BinaryExpression => visitor.visit_binary_expression(node),
+        NodeType::UnaryExpression => visitor.visit_unary_expression(node),
+        NodeType::FunctionCall => visitor.visit_function_call(node),
+        NodeType::Operand => visitor.visit_operand(node),
+        NodeType::LoopInitializer => visitor.visit_loop_initializer(node),
+        NodeType::LoopIncrement => visitor.visit_loop_increment(node),
+    }
+}
+
+/// Calls the `VisitorMut` method matching the kind of a node.
+fn dispatch_visit_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut ASTNode) {
+    match node.get_node_type_ref() {
+        NodeType::NoExpression => visitor.visit_no_expression_mut(node),
+        NodeType::Literal(_) => visitor.visit_literal_mut(node),
+        NodeType::Identifier(_) => visitor.visit_identifier_mut(node),
+        NodeType::Operator(_) => visitor.visit_operator_mut(node),
+        NodeType::Type(_) => visitor.visit_type_mut(node),
+        NodeType::Constant(_) => visitor.visit_constant_mut(node),
+        NodeType::IfStatement => visitor.visit_if_statement_mut(node),
+        NodeType::ElseStatement => visitor.visit_else_statement_mut(node),
+        NodeType::ForLoop => visitor.visit_for_loop_mut(node),
+        NodeType::WhileLoop => visitor.visit_while_loop_mut(node),
+        NodeType::DoWhileLoop => visitor.visit_do_while_loop_mut(node),
+        NodeType::Break => visitor.visit_break_mut(node),
+        NodeType::Continue => visitor.visit_continue_mut(node),
+        NodeType::Return => visitor.visit_return_mut(node),
+        NodeType::SwitchStatement => visitor.visit_switch_statement_mut(node),
+        NodeType::Case => visitor.visit_case_mut(node),
+        NodeType::Default => visitor.visit_default_mut(node),
+        NodeType::Assignment => visitor.visit_assignment_mut(node),
+        NodeType::Initialization => visitor.visit_initialization_mut(node),
+        NodeType::FunctionDeclaration => visitor.visit_function_declaration_mut(node),
+        NodeType::StructDeclaration => visitor.visit_struct_declaration_mut(node),
+        NodeType::EnumDeclaration => visitor.visit_enum_declaration_mut(node),
+        NodeType::UnionDeclaration => visitor.visit_union_declaration_mut(node),
+        NodeType::ModuleExpression => visitor.visit_module_expression_mut(node),
+        NodeType::TopLevelExpression => visitor.visit_top_level_expression_mut(node),
+        NodeType::BlockExpression => visitor.visit_block_expression_mut(node),
+        NodeType::Condition => visitor.visit_condition_mut(node),
+        NodeType::Action => visitor.visit_action_mut(node),
+        NodeType::Variant => visitor.visit_variant_mut(node),
+        NodeType::AssignedValue => visitor.visit_assigned_value_mut(node),
+        NodeType::Field => visitor.visit_field_mut(node),
+        NodeType::BitWidth => visitor.visit_bit_width_mut(node),
+        NodeType::FlexibleArray => visitor.visit_flexible_array_mut(node),
+        NodeType::MemberAccess => visitor.visit_member_access_mut(node),
+        NodeType::Parameter => visitor.visit_parameter_mut(node),
+        NodeType::VariadicParameter => visitor.visit_variadic_parameter_mut(node),
+        NodeType::Variable => visitor.visit_variable_mut(node),
+        NodeType::BinaryExpression => visitor.visit_binary_expression_mut(node),
+        NodeType::UnaryExpression => visitor.visit_unary_expression_mut(node),
+        NodeType::FunctionCall => visitor.visit_function_call_mut(node),
+        NodeType::Operand => visitor.visit_operand_mut(node),
+        NodeType::LoopInitializer => visitor.visit_loop_initializer_mut(node),
+        NodeType::LoopIncrement => visitor.visit_loop_increment_mut(node),
+    }
+}
+
+/// Calls the `Fold` method matching the kind of a node.
+fn dispatch_fold<F: Fold + ?Sized>(folder: &mut F, node: ASTNode) -> ASTNode {
+    match node.get_node_type_ref() {
+        NodeType::NoExpression => folder.fold_no_expression(node),
+        NodeType::Literal(_) => folder.fold_literal(node),
+        NodeType::Identifier(_) => folder.fold_identifier(node),
+        NodeType::Operator(_) => folder.fold_operator(node),
+        NodeType::Type(_) => folder.fold_type(node),
+        NodeType::Constant(_) => folder.fold_constant(node),
+        NodeType::IfStatement => folder.fold_if_statement(node),
+        NodeType::ElseStatement => folder.fold_else_statement(node),
+        NodeType::ForLoop => folder.fold_for_loop(node),
+        NodeType::WhileLoop => folder.fold_while_loop(node),
+        NodeType::DoWhileLoop => folder.fold_do_while_loop(node),
+        NodeType::Break => folder.fold_break(node),
+        NodeType::Continue => folder.fold_continue(node),
+        NodeType::Return => folder.fold_return(node),
+        NodeType::SwitchStatement => folder.fold_switch_statement(node),
+        NodeType::Case => folder.fold_case(node),
+        NodeType::Default => folder.fold_default(node),
+        NodeType::Assignment => folder.fold_assignment(node),
+        NodeType::Initialization => folder.fold_initialization(node),
+        NodeType::FunctionDeclaration => folder.fold_function_declaration(node),
+        NodeType::StructDeclaration => folder.fold_struct_declaration(node),
+        NodeType::EnumDeclaration => folder.fold_enum_declaration(node),
+        NodeType::UnionDeclaration => folder.fold_union_declaration(node),
+        NodeType::ModuleExpression => folder.fold_module_expression(node),
+        NodeType::TopLevelExpression => folder.fold_top_level_expression(node),
+        NodeType::BlockExpression => folder.fold_block_expression(node),
+        NodeType::Condition => folder.fold_condition(node),
+        NodeType::Action => folder.fold_action(node),
+        NodeType::Variant => folder.fold_variant(node),
+        NodeType::AssignedValue => folder.fold_assigned_value(node),
+        NodeType::Field => folder.fold_field(node),
+        NodeType::BitWidth => folder.fold_bit_width(node),
+        NodeType::FlexibleArray => folder.fold_flexible_array(node),
+        NodeType::MemberAccess => folder.fold_member_access(node),
+        NodeType::Parameter => folder.fold_parameter(node),
+        NodeType::VariadicParameter => folder.fold_variadic_parameter(node),
+        NodeType::Variable => folder.fold_variable(node),
+        NodeType::BinaryExpression => folder.fold_binary_expression(node),
+        NodeType::UnaryExpression => folder.fold_unary_expression(node),
+        NodeType::FunctionCall => folder.fold_function_call(node),
+        NodeType::Operand => folder.fold_operand(node),
+        NodeType::LoopInitializer => folder.fold_loop_initializer(node),
+        NodeType::LoopIncrement => folder.fold_loop_increment(node),
+    }
+}
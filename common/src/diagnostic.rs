@@ -0,0 +1,401 @@
+//! This file defines `Diagnostic`, the uniform shape in which every phase reports errors and warnings: a
+//! severity, a stable code, a message, a primary `Span`, secondary labelled spans, notes and help text, and
+//! optional fix-its that edit the source.
+//!
+//! Phases keep returning `ErrorType`. Each variant maps onto a diagnostic with `Diagnostic::from`, and a phase
+//! that knows where an error occurred wraps the located diagnostic back into an `ErrorType` with
+//! `ErrorType::from`, so renderers only ever deal with diagnostics.
+
+use std::fmt;
+
+use crate::{error::ErrorType, fix::Fix, source::Span};
+
+/// How serious a diagnostic is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Severity {
+    /// A problem that stops compilation.
+    Error,
+    /// A likely mistake that does not stop compilation.
+    Warning,
+    /// Additional information, usually attached to another diagnostic.
+    Note,
+}
+
+/// A secondary span with a message, such as "first declared here".
+///
+/// # Fields
+/// * `span` - The labelled range.
+/// * `message` - The text shown next to the range.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Label {
+    span: Span,
+    message: String,
+}
+
+/// An error, warning or note reported by a phase.
+///
+/// # Fields
+/// * `severity` - How serious the diagnostic is.
+/// * `code` - The stable code identifying the kind of diagnostic, such as `E0002`.
+/// * `message` - The main message.
+/// * `span` - The primary location, if known.
+/// * `span_label` - The text shown next to the primary location, if any.
+/// * `labels` - Secondary labelled locations.
+/// * `notes` - Additional facts about the diagnostic.
+/// * `help` - Suggestions for fixing the problem.
+/// * `fixes` - Concrete edits fixing the problem.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Diagnostic {
+    severity: Severity,
+    code: Option<String>,
+    message: String,
+    span: Option<Span>,
+    span_label: Option<String>,
+    labels: Vec<Label>,
+    notes: Vec<String>,
+    help: Vec<String>,
+    fixes: Vec<Fix>,
+}
+
+impl Severity {
+    /// Retrieves the lowercase name of this severity, as printed before a message.
+    ///
+    /// # Returns
+    /// Returns `"error"`, `"warning"` or `"note"`.
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Note => "note",
+        }
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.get_name())
+    }
+}
+
+impl Label {
+    /// Creates a label.
+    ///
+    /// # Parameters
+    /// * `span` - The labelled range.
+    /// * `message` - The text shown next to the range.
+    ///
+    /// # Returns
+    /// Returns a new `Label`.
+    pub fn new(span: Span, message: &str) -> Self {
+        Label { span, message: message.to_string() }
+    }
+
+    /// Retrieves the labelled range.
+    ///
+    /// # Returns
+    /// Returns the label's `Span`.
+    pub fn get_span(&self) -> Span {
+        self.span
+    }
+
+    /// Retrieves the text of this label.
+    ///
+    /// # Returns
+    /// Returns the label's message.
+    pub fn get_message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Diagnostic {
+    /// Creates a diagnostic without code, location, labels, notes or help.
+    ///
+    /// # Parameters
+    /// * `severity` - How serious the diagnostic is.
+    /// * `message` - The main message.
+    ///
+    /// # Returns
+    /// Returns a new `Diagnostic`.
+    pub fn new(severity: Severity, message: &str) -> Self {
+        Diagnostic {
+            severity,
+            code: None,
+            message: message.to_string(),
+            span: None,
+            span_label: None,
+            labels: Vec::new(),
+            notes: Vec::new(),
+            help: Vec::new(),
+            fixes: Vec::new(),
+        }
+    }
+
+    /// Creates an error.
+    ///
+    /// # Parameters
+    /// * `message` - The main message.
+    ///
+    /// # Returns
+    /// Returns a new `Diagnostic` with `Severity::Error`.
+    ///
+    /// # Examples
+    /// ```
+    /// use common::{diagnostic::Diagnostic, source::SourceManager, source::Span};
+    ///
+    /// let mut sources = SourceManager::new();
+    /// let file = sources.add_file("main.c", "int x = y;\n".to_string());
+    ///
+    /// let diagnostic = Diagnostic::error("cannot find variable `y` in this scope")
+    ///     .with_code("E0002")
+    ///     .with_span(Span::new(file, 8, 9), "not found in this scope")
+    ///     .with_help("declare `y` before using it");
+    /// assert_eq!(diagnostic.to_string(),
+    ///     "error[E0002]: cannot find variable `y` in this scope\n  --> file#0[8..9]: not found in this scope\n  = help: declare `y` before using it");
+    /// ```
+    pub fn error(message: &str) -> Self {
+        Diagnostic::new(Severity::Error, message)
+    }
+
+    /// Creates a warning.
+    ///
+    /// # Parameters
+    /// * `message` - The main message.
+    ///
+    /// # Returns
+    /// Returns a new `Diagnostic` with `Severity::Warning`.
+    pub fn warning(message: &str) -> Self {
+        Diagnostic::new(Severity::Warning, message)
+    }
+
+    /// Creates a note.
+    ///
+    /// # Parameters
+    /// * `message` - The main message.
+    ///
+    /// # Returns
+    /// Returns a new `Diagnostic` with `Severity::Note`.
+    pub fn note(message: &str) -> Self {
+        Diagnostic::new(Severity::Note, message)
+    }
+
+    /// Sets the stable code of this diagnostic.
+    ///
+    /// # Parameters
+    /// * `code` - The code, such as `E0002`.
+    ///
+    /// # Returns
+    /// Returns the updated `Diagnostic`.
+    pub fn with_code(mut self, code: &str) -> Self {
+        self.code = Some(code.to_string());
+        self
+    }
+
+    /// Sets the primary location of this diagnostic.
+    ///
+    /// # Parameters
+    /// * `span` - The location.
+    /// * `label` - The text shown next to the location, or an empty string for none.
+    ///
+    /// # Returns
+    /// Returns the updated `Diagnostic`.
+    pub fn with_span(mut self, span: Span, label: &str) -> Self {
+        self.span = Some(span);
+        self.span_label = if label.is_empty() { None } else { Some(label.to_string()) };
+        self
+    }
+
+    /// Adds a secondary labelled location.
+    ///
+    /// # Parameters
+    /// * `span` - The location.
+    /// * `message` - The text shown next to the location.
+    ///
+    /// # Returns
+    /// Returns the updated `Diagnostic`.
+    pub fn with_label(mut self, span: Span, message: &str) -> Self {
+        self.labels.push(Label::new(span, message));
+        self
+    }
+
+    /// Adds a note.
+    ///
+    /// # Parameters
+    /// * `note` - The note.
+    ///
+    /// # Returns
+    /// Returns the updated `Diagnostic`.
+    pub fn with_note(mut self, note: &str) -> Self {
+        self.notes.push(note.to_string());
+        self
+    }
+
+    /// Adds help text.
+    ///
+    /// # Parameters
+    /// * `help` - The help text.
+    ///
+    /// # Returns
+    /// Returns the updated `Diagnostic`.
+    pub fn with_help(mut self, help: &str) -> Self {
+        self.help.push(help.to_string());
+        self
+    }
+
+    /// Adds a fix-it, shown as help and applied by `sicc fix` if it is machine-applicable.
+    ///
+    /// # Parameters
+    /// * `fix` - The fix.
+    ///
+    /// # Returns
+    /// Returns the updated `Diagnostic`.
+    pub fn with_fix(mut self, fix: Fix) -> Self {
+        self.fixes.push(fix);
+        self
+    }
+
+    /// Retrieves the severity of this diagnostic.
+    ///
+    /// # Returns
+    /// Returns the diagnostic's `Severity`.
+    pub fn get_severity(&self) -> Severity {
+        self.severity
+    }
+
+    /// Retrieves the stable code of this diagnostic.
+    ///
+    /// # Returns
+    /// Returns `Some(&str)` with the code, or `None` if it has none.
+    pub fn get_code(&self) -> Option<&str> {
+        self.code.as_deref()
+    }
+
+    /// Retrieves the main message of this diagnostic.
+    ///
+    /// # Returns
+    /// Returns the message.
+    pub fn get_message(&self) -> &str {
+        &self.message
+    }
+
+    /// Retrieves the primary location of this diagnostic.
+    ///
+    /// # Returns
+    /// Returns `Some(Span)`, or `None` if the location is unknown.
+    pub fn get_span(&self) -> Option<Span> {
+        self.span
+    }
+
+    /// Retrieves the text shown next to the primary location.
+    ///
+    /// # Returns
+    /// Returns `Some(&str)` with the label, or `None` if there is none.
+    pub fn get_span_label(&self) -> Option<&str> {
+        self.span_label.as_deref()
+    }
+
+    /// Retrieves the secondary labelled locations.
+    ///
+    /// # Returns
+    /// Returns the labels in the order they were added.
+    pub fn get_labels(&self) -> &[Label] {
+        &self.labels
+    }
+
+    /// Retrieves the notes.
+    ///
+    /// # Returns
+    /// Returns the notes in the order they were added.
+    pub fn get_notes(&self) -> &[String] {
+        &self.notes
+    }
+
+    /// Retrieves the help text.
+    ///
+    /// # Returns
+    /// Returns the help text in the order it was added.
+    pub fn get_help(&self) -> &[String] {
+        &self.help
+    }
+
+    /// Retrieves the fix-its of this diagnostic.
+    ///
+    /// # Returns
+    /// Returns the fixes in the order they were added.
+    pub fn get_fixes(&self) -> &[Fix] {
+        &self.fixes
+    }
+
+    /// Checks whether this diagnostic stops compilation.
+    ///
+    /// # Returns
+    /// Returns `true` for `Severity::Error`, `false` otherwise.
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    /// Formats the diagnostic without source text, for contexts where no `SourceManager` is available. Spans
+    /// are shown as `file#N[start..end]`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.severity)?;
+        if let Some(code) = &self.code {
+            write!(f, "[{}]", code)?;
+        }
+        write!(f, ": {}", self.message)?;
+        if let Some(span) = &self.span {
+            write!(f, "\n  --> {}", span)?;
+            if let Some(label) = &self.span_label {
+                write!(f, ": {}", label)?;
+            }
+        }
+        for label in &self.labels {
+            write!(f, "\n  ... {}: {}", label.span, label.message)?;
+        }
+        for note in &self.notes {
+            write!(f, "\n  = note: {}", note)?;
+        }
+        for help in &self.help {
+            write!(f, "\n  = help: {}", help)?;
+        }
+        for fix in &self.fixes {
+            write!(f, "\n  = help: {}", fix.get_message())?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for Diagnostic {}
+
+impl From<ErrorType> for Diagnostic {
+    /// Maps an `ErrorType` onto a diagnostic with its stable code. A wrapped diagnostic is returned unchanged.
+    fn from(error: ErrorType) -> Self {
+        let code = error.get_code().to_string();
+        match error {
+            ErrorType::Diagnostic { diagnostic } => *diagnostic,
+            ErrorType::InvalidAST { path, message } => Diagnostic::error(&format!("malformed AST: {}", message))
+                .with_code(&code)
+                .with_note(&format!("the offending node is at {}", path)),
+            ErrorType::Redeclaration { ref name, ref previous, ref path } => {
+                let location = path.as_ref().map(|path| format!(", at {}", path)).unwrap_or_default();
+                Diagnostic::error(&error.to_string())
+                    .with_code(&code)
+                    .with_note(&format!("`{}` was first declared in this scope as {}{}", name, previous, location))
+            },
+            ErrorType::DevError { .. } => Diagnostic::error(&error.to_string())
+                .with_code(&code)
+                .with_note("this is a bug in the compiler rather than in the program being compiled"),
+            error => Diagnostic::error(&error.to_string()).with_code(&code),
+        }
+    }
+}
+
+impl From<Diagnostic> for ErrorType {
+    /// Wraps a diagnostic so it can be returned by phases that report `ErrorType`.
+    fn from(diagnostic: Diagnostic) -> Self {
+        ErrorType::Diagnostic { diagnostic: Box::new(diagnostic) }
+    }
+}
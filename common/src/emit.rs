@@ -0,0 +1,371 @@
+//! This file emits diagnostics in machine-readable formats for editors and review bots: one JSON object per
+//! line, or a SARIF 2.1.0 document. Spans are resolved through the `SourceManager` into a file name and a range
+//! of 1-based lines and columns, with columns counted in Unicode code points.
+//!
+//! A JSON line has the following shape, where `file` and `range` are `null` for a diagnostic without a
+//! location:
+//!
+//! ```text
+//! {"severity":"error","code":"E0002","message":"...","file":"main.c",
+//!  "range":{"start":{"line":1,"column":9,"offset":8},"end":{"line":1,"column":10,"offset":9}},
+//!  "label":"...","labels":[{"message":"...","file":"main.c","range":{...}}],"notes":["..."],"help":["..."],
+//!  "fixes":[{"message":"...","applicability":"machine-applicable",
+//!            "edits":[{"file":"main.c","range":{...},"replacement":"..."}]}]}
+//! ```
+
+use std::{fmt, str::FromStr};
+
+use crate::{
+    diagnostic::{Diagnostic, Severity},
+    error::ErrorType,
+    fix::Fix,
+    source::{SourceManager, Span},
+};
+
+/// The SARIF version emitted by `to_sarif`.
+const SARIF_VERSION: &str = "2.1.0";
+
+/// The schema of the SARIF version emitted by `to_sarif`.
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// The format diagnostics are reported in, as chosen by the driver's `--error-format` switch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ErrorFormat {
+    /// Rendered for people, with source snippets.
+    #[default]
+    Human,
+    /// One JSON object per diagnostic, one per line.
+    Json,
+    /// A single SARIF 2.1.0 document.
+    Sarif,
+}
+
+/// A resolved position as its line, column and byte offset.
+type Position = (usize, usize, usize);
+
+/// A JSON value, printed compactly.
+enum Json {
+    Null,
+    Number(usize),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(&'static str, Json)>),
+}
+
+impl FromStr for ErrorFormat {
+    type Err = ErrorType;
+
+    /// Parses the value of an `--error-format` switch.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "human" => Ok(ErrorFormat::Human),
+            "json" => Ok(ErrorFormat::Json),
+            "sarif" => Ok(ErrorFormat::Sarif),
+            _ => Err(ErrorType::from(Diagnostic::error(&format!("invalid error format `{}`, expected one of human, json, sarif", value)))),
+        }
+    }
+}
+
+impl fmt::Display for ErrorFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorFormat::Human => write!(f, "human"),
+            ErrorFormat::Json => write!(f, "json"),
+            ErrorFormat::Sarif => write!(f, "sarif"),
+        }
+    }
+}
+
+impl fmt::Display for Json {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Json::Null => write!(f, "null"),
+            Json::Number(number) => write!(f, "{}", number),
+            Json::String(text) => {
+                write!(f, "\"")?;
+                for c in text.chars() {
+                    match c {
+                        '"' => write!(f, "\\\"")?,
+                        '\\' => write!(f, "\\\\")?,
+                        '\n' => write!(f, "\\n")?,
+                        '\r' => write!(f, "\\r")?,
+                        '\t' => write!(f, "\\t")?,
+                        c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+                        c => write!(f, "{}", c)?,
+                    }
+                }
+                write!(f, "\"")
+            },
+            Json::Array(items) => {
+                write!(f, "[")?;
+                for (index, item) in items.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                write!(f, "]")
+            },
+            Json::Object(fields) => {
+                write!(f, "{{")?;
+                for (index, (key, value)) in fields.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}:{}", Json::String(key.to_string()), value)?;
+                }
+                write!(f, "}}")
+            },
+        }
+    }
+}
+
+/// Emits a diagnostic as a single-line JSON object.
+///
+/// # Parameters
+/// * `diagnostic` - The diagnostic to emit.
+/// * `sources` - The files its spans refer to.
+///
+/// # Returns
+/// Returns the JSON object, without a trailing newline.
+///
+/// # Examples
+/// ```
+/// use common::{diagnostic::Diagnostic, emit::to_json_line, source::{SourceManager, Span}};
+///
+/// let mut sources = SourceManager::new();
+/// let file = sources.add_file("main.c", "int x = y;\n".to_string());
+/// let diagnostic = Diagnostic::error("cannot find variable `y` in this scope")
+///     .with_code("E0002")
+///     .with_span(Span::new(file, 8, 9), "");
+///
+/// let line = to_json_line(&diagnostic, &sources);
+/// assert!(line.starts_with(r#"{"severity":"error","code":"E0002","message":"cannot find variable `y` in this scope","file":"main.c","#));
+/// assert!(line.contains(r#""start":{"line":1,"column":9,"offset":8}"#));
+/// ```
+pub fn to_json_line(diagnostic: &Diagnostic, sources: &SourceManager) -> String {
+    let span = diagnostic.get_span();
+    let labels = diagnostic.get_labels().iter().map(|label| Json::Object(vec![
+        ("message", Json::String(label.get_message().to_string())),
+        ("file", file_json(Some(label.get_span()), sources)),
+        ("range", range_json(Some(label.get_span()), sources)),
+    ])).collect();
+
+    Json::Object(vec![
+        ("severity", Json::String(diagnostic.get_severity().get_name().to_string())),
+        ("code", diagnostic.get_code().map_or(Json::Null, |code| Json::String(code.to_string()))),
+        ("message", Json::String(diagnostic.get_message().to_string())),
+        ("file", file_json(span, sources)),
+        ("range", range_json(span, sources)),
+        ("label", diagnostic.get_span_label().map_or(Json::Null, |label| Json::String(label.to_string()))),
+        ("labels", Json::Array(labels)),
+        ("notes", strings_json(diagnostic.get_notes())),
+        ("help", strings_json(diagnostic.get_help())),
+        ("fixes", Json::Array(diagnostic.get_fixes().iter().map(|fix| fix_json(fix, sources)).collect())),
+    ]).to_string()
+}
+
+/// Emits diagnostics as JSON lines.
+///
+/// # Parameters
+/// * `diagnostics` - The diagnostics to emit.
+/// * `sources` - The files their spans refer to.
+///
+/// # Returns
+/// Returns one JSON object per diagnostic, each followed by a newline.
+pub fn to_json_lines(diagnostics: &[Diagnostic], sources: &SourceManager) -> String {
+    diagnostics.iter().map(|diagnostic| format!("{}\n", to_json_line(diagnostic, sources))).collect()
+}
+
+/// Emits diagnostics as a SARIF 2.1.0 log with a single run. Every distinct code becomes a rule, secondary
+/// labels become related locations, fix-its become fixes, and notes and help are kept in the result's properties.
+///
+/// # Parameters
+/// * `diagnostics` - The diagnostics to emit.
+/// * `sources` - The files their spans refer to.
+/// * `tool_version` - The version of the compiler, reported as the version of the tool.
+///
+/// # Returns
+/// Returns the SARIF document as a single line of JSON.
+pub fn to_sarif(diagnostics: &[Diagnostic], sources: &SourceManager, tool_version: &str) -> String {
+    let mut rule_ids: Vec<&str> = diagnostics.iter().filter_map(Diagnostic::get_code).collect();
+    rule_ids.sort_unstable();
+    rule_ids.dedup();
+    let rules = rule_ids.iter().map(|id| Json::Object(vec![("id", Json::String(id.to_string()))])).collect();
+
+    let results = diagnostics.iter().map(|diagnostic| {
+        let mut result = Vec::new();
+        if let Some(code) = diagnostic.get_code() {
+            result.push(("ruleId", Json::String(code.to_string())));
+        }
+        result.push(("level", Json::String(sarif_level(diagnostic.get_severity()).to_string())));
+        result.push(("message", sarif_message(diagnostic.get_message())));
+        let locations: Vec<Json> = diagnostic.get_span().and_then(|span| sarif_location(span, sources, None)).into_iter().collect();
+        result.push(("locations", Json::Array(locations)));
+        let related: Vec<Json> = diagnostic.get_labels().iter().enumerate()
+            .filter_map(|(index, label)| sarif_location(label.get_span(), sources, Some((index, label.get_message()))))
+            .collect();
+        if !related.is_empty() {
+            result.push(("relatedLocations", Json::Array(related)));
+        }
+        let fixes: Vec<Json> = diagnostic.get_fixes().iter().map(|fix| sarif_fix(fix, sources)).collect();
+        if !fixes.is_empty() {
+            result.push(("fixes", Json::Array(fixes)));
+        }
+        if !diagnostic.get_notes().is_empty() || !diagnostic.get_help().is_empty() {
+            result.push(("properties", Json::Object(vec![
+                ("notes", strings_json(diagnostic.get_notes())),
+                ("help", strings_json(diagnostic.get_help())),
+            ])));
+        }
+        Json::Object(result)
+    }).collect();
+
+    let artifacts = sources.get_files().iter()
+        .map(|file| Json::Object(vec![("location", Json::Object(vec![("uri", sarif_uri(file.get_name()))]))]))
+        .collect();
+
+    Json::Object(vec![
+        ("$schema", Json::String(SARIF_SCHEMA.to_string())),
+        ("version", Json::String(SARIF_VERSION.to_string())),
+        ("runs", Json::Array(vec![Json::Object(vec![
+            ("tool", Json::Object(vec![("driver", Json::Object(vec![
+                ("name", Json::String("sicc".to_string())),
+                ("version", Json::String(tool_version.to_string())),
+                ("rules", Json::Array(rules)),
+            ]))])),
+            ("columnKind", Json::String("unicodeCodePoints".to_string())),
+            ("artifacts", Json::Array(artifacts)),
+            ("results", Json::Array(results)),
+        ])])),
+    ]).to_string()
+}
+
+/// Builds the `file` field of a JSON line.
+fn file_json(span: Option<Span>, sources: &SourceManager) -> Json {
+    span.and_then(|span| sources.get_name(span.get_file()))
+        .map_or(Json::Null, |name| Json::String(name.to_string()))
+}
+
+/// Builds the `range` field of a JSON line.
+fn range_json(span: Option<Span>, sources: &SourceManager) -> Json {
+    let (start, end) = match span.and_then(|span| resolve(span, sources)) {
+        Some(range) => range,
+        None => return Json::Null,
+    };
+    let position = |(line, column, offset): Position| Json::Object(vec![
+        ("line", Json::Number(line)),
+        ("column", Json::Number(column)),
+        ("offset", Json::Number(offset)),
+    ]);
+    Json::Object(vec![("start", position(start)), ("end", position(end))])
+}
+
+/// Builds an array of strings.
+fn strings_json(strings: &[String]) -> Json {
+    Json::Array(strings.iter().map(|text| Json::String(text.clone())).collect())
+}
+
+/// Builds a fix-it of a JSON line.
+fn fix_json(fix: &Fix, sources: &SourceManager) -> Json {
+    let edits = fix.get_edits().iter().map(|edit| Json::Object(vec![
+        ("file", file_json(Some(edit.get_span()), sources)),
+        ("range", range_json(Some(edit.get_span()), sources)),
+        ("replacement", Json::String(edit.get_replacement().to_string())),
+    ])).collect();
+    Json::Object(vec![
+        ("message", Json::String(fix.get_message().to_string())),
+        ("applicability", Json::String(fix.get_applicability().get_name().to_string())),
+        ("edits", Json::Array(edits)),
+    ])
+}
+
+/// Resolves a span to the line, column and offset of its start and end. An end that does not resolve is
+/// replaced by the start.
+fn resolve(span: Span, sources: &SourceManager) -> Option<(Position, Position)> {
+    let start = sources.get_location(span.get_file(), span.get_start())?;
+    let (end, end_offset) = match sources.get_location(span.get_file(), span.get_end()) {
+        Some(end) => (end, span.get_end()),
+        None => (start, span.get_start()),
+    };
+    Some((
+        (start.get_line(), start.get_column(), span.get_start()),
+        (end.get_line(), end.get_column(), end_offset),
+    ))
+}
+
+/// Retrieves the SARIF level of a severity.
+fn sarif_level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Note => "note",
+    }
+}
+
+/// Builds a SARIF message object.
+fn sarif_message(text: &str) -> Json {
+    Json::Object(vec![("text", Json::String(text.to_string()))])
+}
+
+/// Builds a SARIF artifact URI from a file name, as a relative reference with every byte other than an
+/// unreserved character or `/` percent-encoded, so that names with spaces, `#` or `%` stay valid URIs.
+fn sarif_uri(name: &str) -> Json {
+    let mut uri = String::with_capacity(name.len());
+    for byte in name.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~' | b'/') {
+            uri.push(char::from(byte));
+        } else {
+            uri += &format!("%{:02X}", byte);
+        }
+    }
+    Json::String(uri)
+}
+
+/// Builds a SARIF fix, with one artifact change per edit. Edits whose span does not resolve are left out.
+fn sarif_fix(fix: &Fix, sources: &SourceManager) -> Json {
+    let changes = fix.get_edits().iter().filter_map(|edit| {
+        let span = edit.get_span();
+        let name = sources.get_name(span.get_file())?;
+        Some(Json::Object(vec![
+            ("artifactLocation", Json::Object(vec![("uri", sarif_uri(name))])),
+            ("replacements", Json::Array(vec![Json::Object(vec![
+                ("deletedRegion", sarif_region(span, sources)?),
+                ("insertedContent", Json::Object(vec![("text", Json::String(edit.get_replacement().to_string()))])),
+            ])])),
+        ]))
+    }).collect();
+    Json::Object(vec![
+        ("description", sarif_message(fix.get_message())),
+        ("artifactChanges", Json::Array(changes)),
+    ])
+}
+
+/// Builds a SARIF region.
+fn sarif_region(span: Span, sources: &SourceManager) -> Option<Json> {
+    let ((start_line, start_column, _), (end_line, end_column, _)) = resolve(span, sources)?;
+    Some(Json::Object(vec![
+        ("startLine", Json::Number(start_line)),
+        ("startColumn", Json::Number(start_column)),
+        ("endLine", Json::Number(end_line)),
+        ("endColumn", Json::Number(end_column)),
+    ]))
+}
+
+/// Builds a SARIF location, with an id and message when it is a related location.
+fn sarif_location(span: Span, sources: &SourceManager, related: Option<(usize, &str)>) -> Option<Json> {
+    let name = sources.get_name(span.get_file())?;
+    let region = sarif_region(span, sources)?;
+    let mut location = Vec::new();
+    if let Some((id, _)) = related {
+        location.push(("id", Json::Number(id)));
+    }
+    location.push(("physicalLocation", Json::Object(vec![
+        ("artifactLocation", Json::Object(vec![("uri", sarif_uri(name))])),
+        ("region", region),
+    ])));
+    if let Some((_, message)) = related {
+        location.push(("message", sarif_message(message)));
+    }
+    Some(Json::Object(location))
+}
@@ -1,5 +1,9 @@
 //! This file defines the errors that can occur during the compilation process.
 
+use std::fmt;
+
+use crate::diagnostic::Diagnostic;
+
 /// Defines types of errors that can occur during compilation.
 ///
 /// Each error represents a different kind of issue that can be encountered during the lexing, parsing, analysis, 
@@ -52,13 +56,81 @@ pub enum ErrorType {
         token: String,
     },
 
+    /// Occurs due to a node of an `AST` whose children break the child-ordering conventions.
+    InvalidAST {
+        /// The path from the root to the offending node.
+        path: String,
+        /// Describes the expected and found children.
+        message: String,
+    },
+
+    /// Occurs due to a name being declared twice in the same scope.
+    Redeclaration {
+        /// The name declared more than once.
+        name: String,
+        /// Describes what the name was first declared as, such as "a variable of type `int`".
+        previous: String,
+        /// The path from the root to the first declaration, if known.
+        path: Option<String>,
+    },
+
     /// A placeholder error for development use.
     DevError {
         /// A message describing what needs to be addressed.
         message: String,
     },
+
+    /// A fully described diagnostic, with its location, labels, notes and help.
+    Diagnostic {
+        /// The diagnostic being reported.
+        diagnostic: Box<Diagnostic>,
+    },
+}
+
+impl ErrorType {
+    /// Retrieves the stable code identifying the kind of this error, as shown in diagnostics.
+    ///
+    /// # Returns
+    /// Returns the code, such as `E0002` for an `UndefinedVariable`. A wrapped `Diagnostic` without a code
+    /// reports `E0000`.
+    pub fn get_code(&self) -> &str {
+        match self {
+            ErrorType::DevError { .. } => "E0000",
+            ErrorType::TypeMismatch { .. } => "E0001",
+            ErrorType::UndefinedVariable { .. } => "E0002",
+            ErrorType::UnsupportedOperator { .. } => "E0003",
+            ErrorType::SyntaxError { .. } => "E0004",
+            ErrorType::DivisionByZero { .. } => "E0005",
+            ErrorType::InvalidAssignment { .. } => "E0006",
+            ErrorType::UnrecognizedToken { .. } => "E0007",
+            ErrorType::InvalidAST { .. } => "E0008",
+            ErrorType::Redeclaration { .. } => "E0009",
+            ErrorType::Diagnostic { diagnostic } => diagnostic.get_code().unwrap_or("E0000"),
+        }
+    }
 }
 
+impl fmt::Display for ErrorType {
+    /// Formats the message of the error, without its code or location.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorType::TypeMismatch { left_type, right_type } => write!(f, "mismatched types `{}` and `{}`", left_type, right_type),
+            ErrorType::UndefinedVariable { variable_name } => write!(f, "cannot find variable `{}` in this scope", variable_name),
+            ErrorType::UnsupportedOperator { operator, operand_type } => write!(f, "operator `{}` cannot be applied to type `{}`", operator, operand_type),
+            ErrorType::SyntaxError { message } => write!(f, "{}", message),
+            ErrorType::DivisionByZero { operation } => write!(f, "division by zero in `{}`", operation),
+            ErrorType::InvalidAssignment { target } => write!(f, "invalid assignment target `{}`", target),
+            ErrorType::UnrecognizedToken { token } => write!(f, "unrecognized token `{}`", token),
+            ErrorType::InvalidAST { path, message } => write!(f, "malformed AST at {}: {}", path, message),
+            ErrorType::Redeclaration { name, .. } => write!(f, "redeclaration of `{}` in the same scope", name),
+            ErrorType::DevError { message } => write!(f, "internal compiler error: {}", message),
+            ErrorType::Diagnostic { diagnostic } => write!(f, "{}", diagnostic.get_message()),
+        }
+    }
+}
+
+impl std::error::Error for ErrorType {}
+
 impl From<ErrorType> for Vec<ErrorType> {
     /// Converts an `ErrorType` into a vector containing that error.
     fn from(err: ErrorType) -> Self {
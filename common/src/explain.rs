@@ -0,0 +1,74 @@
+//! This file holds the long-form explanation of every error code, as shown by `sicc --explain E0xxx`. Each
+//! explanation says what the error means, gives a minimal failing example and shows the fix. The explanations
+//! are written in Markdown in the `explanations` directory and embedded in the compiler when it is built.
+
+/// The explanation of every error code, sorted by code.
+const EXPLANATIONS: &[(&str, &str)] = &[
+    ("E0000", include_str!("explanations/E0000.md")),
+    ("E0001", include_str!("explanations/E0001.md")),
+    ("E0002", include_str!("explanations/E0002.md")),
+    ("E0003", include_str!("explanations/E0003.md")),
+    ("E0004", include_str!("explanations/E0004.md")),
+    ("E0005", include_str!("explanations/E0005.md")),
+    ("E0006", include_str!("explanations/E0006.md")),
+    ("E0007", include_str!("explanations/E0007.md")),
+    ("E0008", include_str!("explanations/E0008.md")),
+    ("E0009", include_str!("explanations/E0009.md")),
+];
+
+/// Normalizes an error code as typed by a user, so that `e2`, `0002` and `E0002` all name the same code.
+///
+/// # Parameters
+/// * `code` - The code to normalize.
+///
+/// # Returns
+/// Returns the code as an `E` followed by four digits, or `None` if it is not a number with an optional `E`.
+///
+/// # Examples
+/// ```
+/// use common::explain::normalize_code;
+///
+/// assert_eq!(normalize_code("e2"), Some("E0002".to_string()));
+/// assert_eq!(normalize_code("E0006"), Some("E0006".to_string()));
+/// assert_eq!(normalize_code("TypeMismatch"), None);
+/// ```
+pub fn normalize_code(code: &str) -> Option<String> {
+    let code = code.trim();
+    let digits = code.strip_prefix(['E', 'e']).unwrap_or(code);
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let number: usize = digits.parse().ok()?;
+    Some(format!("E{:04}", number))
+}
+
+/// Retrieves the explanation of an error code.
+///
+/// # Parameters
+/// * `code` - The code to explain, in any form accepted by `normalize_code`.
+///
+/// # Returns
+/// Returns the Markdown explanation, or `None` if the code is not known.
+///
+/// # Examples
+/// ```
+/// use common::{error::ErrorType, explain::explain};
+///
+/// let error = ErrorType::InvalidAssignment { target: "5".to_string() };
+/// assert!(explain(error.get_code()).unwrap().contains("can be assigned to"));
+/// assert_eq!(explain("E9999"), None);
+/// ```
+pub fn explain(code: &str) -> Option<&'static str> {
+    let code = normalize_code(code)?;
+    EXPLANATIONS.iter()
+        .find(|(known, _)| *known == code)
+        .map(|(_, explanation)| *explanation)
+}
+
+/// Retrieves every error code that has an explanation.
+///
+/// # Returns
+/// Returns the codes in ascending order.
+pub fn get_codes() -> Vec<&'static str> {
+    EXPLANATIONS.iter().map(|(code, _)| *code).collect()
+}
@@ -0,0 +1,498 @@
+//! This file defines fix-its: concrete text edits attached to a diagnostic, such as inserting a missing `;` or
+//! replacing a misspelled name. Fixes marked machine-applicable can be applied without review by `sicc fix`,
+//! which either rewrites the source files in place or prints the edits as a unified diff.
+//!
+//! When several fixes overlap, the first one reported wins and the others are left for a later run, so applying
+//! fixes never produces text that no single diagnostic asked for.
+//!
+//! No phase attaches fixes yet: the nodes of an `AST` carry no spans, so the resolver's "did you mean" helps
+//! have nowhere to point an edit. The constructors here are the infrastructure those phases will use once
+//! nodes are located in the source.
+
+use std::collections::BTreeMap;
+
+use crate::{
+    diagnostic::Diagnostic,
+    error::ErrorType,
+    source::{FileId, Provenance, SourceManager, Span},
+};
+
+/// The number of unchanged lines shown around each change in a unified diff.
+const DIFF_CONTEXT: usize = 3;
+
+/// How confident the compiler is that a fix is what the programmer meant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Applicability {
+    /// The fix is certainly correct and can be applied without review.
+    MachineApplicable,
+    /// The fix is probably correct, but should be reviewed before it is applied.
+    MaybeIncorrect,
+}
+
+/// Replaces the text of a span. An empty span inserts, and an empty replacement deletes.
+///
+/// # Fields
+/// * `span` - The range being replaced.
+/// * `replacement` - The text replacing it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Edit {
+    span: Span,
+    replacement: String,
+}
+
+/// A set of edits that together fix the problem reported by a diagnostic.
+///
+/// # Fields
+/// * `message` - Describes the fix, such as "insert the missing `;`".
+/// * `edits` - The edits making up the fix, applied all together or not at all.
+/// * `applicability` - How confident the compiler is in the fix.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Fix {
+    message: String,
+    edits: Vec<Edit>,
+    applicability: Applicability,
+}
+
+/// The new text of every file changed by fixes, ordered by file.
+pub type FixedFiles = Vec<(FileId, String)>;
+
+/// Identifies a fix by the position of its diagnostic in a list and its own position among that diagnostic's
+/// fixes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FixId {
+    diagnostic: usize,
+    fix: usize,
+}
+
+/// A line of a diff.
+enum Line<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+impl Applicability {
+    /// Retrieves the name of the applicability, as emitted in JSON.
+    ///
+    /// # Returns
+    /// Returns `"machine-applicable"` or `"maybe-incorrect"`.
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Applicability::MachineApplicable => "machine-applicable",
+            Applicability::MaybeIncorrect => "maybe-incorrect",
+        }
+    }
+}
+
+impl Edit {
+    /// Creates an edit replacing the text of a span.
+    ///
+    /// # Parameters
+    /// * `span` - The range being replaced.
+    /// * `replacement` - The text replacing it.
+    ///
+    /// # Returns
+    /// Returns a new `Edit`.
+    pub fn new(span: Span, replacement: &str) -> Self {
+        Edit { span, replacement: replacement.to_string() }
+    }
+
+    /// Creates an edit inserting text at an offset.
+    ///
+    /// # Parameters
+    /// * `file` - The file to insert into.
+    /// * `offset` - The byte offset to insert at.
+    /// * `text` - The text to insert.
+    ///
+    /// # Returns
+    /// Returns a new `Edit` with an empty span.
+    pub fn insert(file: FileId, offset: usize, text: &str) -> Self {
+        Edit::new(Span::new(file, offset, offset), text)
+    }
+
+    /// Retrieves the range being replaced.
+    ///
+    /// # Returns
+    /// Returns the span of the edit.
+    pub fn get_span(&self) -> Span {
+        self.span
+    }
+
+    /// Retrieves the text replacing the range.
+    ///
+    /// # Returns
+    /// Returns the replacement.
+    pub fn get_replacement(&self) -> &str {
+        &self.replacement
+    }
+}
+
+impl FixId {
+    /// Creates a fix id.
+    ///
+    /// # Parameters
+    /// * `diagnostic` - The index of the diagnostic carrying the fix.
+    /// * `fix` - The index of the fix among the fixes of that diagnostic.
+    ///
+    /// # Returns
+    /// Returns a new `FixId`.
+    pub fn new(diagnostic: usize, fix: usize) -> Self {
+        FixId { diagnostic, fix }
+    }
+
+    /// Retrieves the index of the diagnostic carrying the fix.
+    ///
+    /// # Returns
+    /// Returns the index of the diagnostic.
+    pub fn get_diagnostic(&self) -> usize {
+        self.diagnostic
+    }
+
+    /// Retrieves the index of the fix among the fixes of its diagnostic.
+    ///
+    /// # Returns
+    /// Returns the index of the fix.
+    pub fn get_fix(&self) -> usize {
+        self.fix
+    }
+}
+
+impl Fix {
+    /// Creates a fix.
+    ///
+    /// # Parameters
+    /// * `message` - Describes the fix.
+    /// * `edits` - The edits making up the fix.
+    /// * `applicability` - How confident the compiler is in the fix.
+    ///
+    /// # Returns
+    /// Returns a new `Fix`.
+    pub fn new(message: &str, edits: Vec<Edit>, applicability: Applicability) -> Self {
+        Fix { message: message.to_string(), edits, applicability }
+    }
+
+    /// Creates a machine-applicable fix inserting a `;` right after a span, such as the last token of a
+    /// statement.
+    ///
+    /// # Parameters
+    /// * `after` - The span the `;` goes after.
+    ///
+    /// # Returns
+    /// Returns a new `Fix`.
+    pub fn insert_semicolon(after: Span) -> Self {
+        let edit = Edit::insert(after.get_file(), after.get_end(), ";");
+        Fix::new("insert the missing `;`", vec![edit], Applicability::MachineApplicable)
+    }
+
+    /// Creates a machine-applicable fix adding a `return` statement before the closing brace of a function.
+    /// A brace on a line of its own gets the statement on a new line above it, indented one level deeper than
+    /// the brace; otherwise the statement goes right before the brace.
+    ///
+    /// # Parameters
+    /// * `sources` - The files, to find the indentation of the brace.
+    /// * `brace` - The span of the closing `}`.
+    /// * `value` - The returned value, such as `0`.
+    ///
+    /// # Returns
+    /// Returns a new `Fix`.
+    ///
+    /// # Examples
+    /// ```
+    /// use common::{fix::{apply_edits, Fix}, source::{SourceManager, Span}};
+    ///
+    /// let mut sources = SourceManager::new();
+    /// let text = "int main() {\n    int x = 1;\n}\n";
+    /// let file = sources.add_file("main.c", text.to_string());
+    ///
+    /// let fix = Fix::insert_return(&sources, Span::new(file, 28, 29), "0");
+    /// assert_eq!(apply_edits(text, fix.get_edits()).unwrap(), "int main() {\n    int x = 1;\n    return 0;\n}\n");
+    /// ```
+    pub fn insert_return(sources: &SourceManager, brace: Span, value: &str) -> Self {
+        let file = brace.get_file();
+        let statement = format!("return {};", value);
+        let line_start = sources.get_file(file)
+            .and_then(|source| {
+                let location = source.get_location(brace.get_start())?;
+                let start = source.get_line_start(location.get_line())?;
+                Some((start, source.get_text().get(start..brace.get_start())?))
+            })
+            .filter(|(_, indent)| indent.chars().all(|c| c == ' ' || c == '\t'));
+
+        let edit = match line_start {
+            Some((start, indent)) => Edit::insert(file, start, &format!("{}    {}\n", indent, statement)),
+            None => Edit::insert(file, brace.get_start(), &format!("{} ", statement)),
+        };
+        Fix::new(&format!("add `{}`", statement), vec![edit], Applicability::MachineApplicable)
+    }
+
+    /// Creates a fix replacing a misspelled name with the name that was probably meant.
+    ///
+    /// # Parameters
+    /// * `span` - The span of the misspelled name.
+    /// * `name` - The name that was probably meant.
+    ///
+    /// # Returns
+    /// Returns a new `Fix` that needs review, since a close match is not necessarily the name that was meant.
+    pub fn rename(span: Span, name: &str) -> Self {
+        Fix::new(&format!("replace with `{}`", name), vec![Edit::new(span, name)], Applicability::MaybeIncorrect)
+    }
+
+    /// Retrieves the description of the fix.
+    ///
+    /// # Returns
+    /// Returns the message.
+    pub fn get_message(&self) -> &str {
+        &self.message
+    }
+
+    /// Retrieves the edits making up the fix.
+    ///
+    /// # Returns
+    /// Returns the edits in the order they were given.
+    pub fn get_edits(&self) -> &[Edit] {
+        &self.edits
+    }
+
+    /// Retrieves how confident the compiler is in the fix.
+    ///
+    /// # Returns
+    /// Returns the applicability.
+    pub fn get_applicability(&self) -> Applicability {
+        self.applicability
+    }
+
+    /// Checks whether the fix can be applied without review.
+    ///
+    /// # Returns
+    /// Returns `true` for `Applicability::MachineApplicable`, `false` otherwise.
+    pub fn is_machine_applicable(&self) -> bool {
+        self.applicability == Applicability::MachineApplicable
+    }
+}
+
+/// Applies edits to the text of a single file. Edits are applied in order of their spans, and insertions at the
+/// same offset in the order they were given.
+///
+/// # Parameters
+/// * `text` - The text of the file.
+/// * `edits` - The edits, all in that file.
+///
+/// # Returns
+/// Returns the edited text.
+///
+/// # Errors
+/// - Returns an `ErrorType::DevError` if two edits overlap or an edit does not fall on character boundaries of
+///   the text.
+pub fn apply_edits(text: &str, edits: &[Edit]) -> Result<String, ErrorType> {
+    let mut edits: Vec<&Edit> = edits.iter().collect();
+    edits.sort_by_key(|edit| (edit.span.get_start(), edit.span.get_end()));
+
+    let mut output = String::with_capacity(text.len());
+    let mut position = 0;
+    for edit in edits {
+        let (start, end) = (edit.span.get_start(), edit.span.get_end());
+        if start < position {
+            return Err(ErrorType::DevError { message: format!("overlapping edits at offset {}", start) });
+        }
+        if text.get(start..end).is_none() {
+            return Err(ErrorType::DevError { message: format!("edit {}..{} is outside the text", start, end) });
+        }
+        output += &text[position..start];
+        output += &edit.replacement;
+        position = end;
+    }
+    output += &text[position..];
+    Ok(output)
+}
+
+/// Collects the edits of every machine-applicable fix, grouped by file. A fix is skipped as a whole if one of its
+/// edits overlaps an edit of an earlier fix, does not fit its file, or falls in a macro expansion, whose text does
+/// not exist in any file on disk.
+///
+/// # Parameters
+/// * `diagnostics` - The diagnostics carrying the fixes.
+/// * `sources` - The files the fixes edit.
+///
+/// # Returns
+/// Returns the accepted edits of each file, ordered by file.
+pub fn collect_edits(diagnostics: &[Diagnostic], sources: &SourceManager) -> BTreeMap<FileId, Vec<Edit>> {
+    accept_fixes(diagnostics, sources).0
+}
+
+/// Applies every machine-applicable fix, as collected by `collect_edits`.
+///
+/// # Parameters
+/// * `diagnostics` - The diagnostics carrying the fixes.
+/// * `sources` - The files the fixes edit.
+///
+/// # Returns
+/// Returns the new text of every file that changed, ordered by file, and the ids of the fixes that were applied,
+/// in the order they were reported. A diagnostic none of whose fixes is among them still stands.
+///
+/// # Errors
+/// - Returns an `ErrorType::DevError` if the collected edits cannot be applied, which is a bug.
+pub fn apply_fixes(diagnostics: &[Diagnostic], sources: &SourceManager) -> Result<(FixedFiles, Vec<FixId>), ErrorType> {
+    let (edits, applied) = accept_fixes(diagnostics, sources);
+    let mut changed = Vec::new();
+    for (file, edits) in edits {
+        let text = sources.get_file(file).map(|source| source.get_text()).unwrap_or_default();
+        let fixed = apply_edits(text, &edits)?;
+        if fixed != text {
+            changed.push((file, fixed));
+        }
+    }
+    Ok((changed, applied))
+}
+
+/// Builds a unified diff between two versions of a file, with three lines of context around each change.
+///
+/// # Parameters
+/// * `name` - The name of the file, shown as `a/name` and `b/name` in the header.
+/// * `old` - The text before the change.
+/// * `new` - The text after the change.
+///
+/// # Returns
+/// Returns the diff, or an empty string if the texts are equal.
+///
+/// # Examples
+/// ```
+/// use common::fix::unified_diff;
+///
+/// let diff = unified_diff("main.c", "int x = 1\nint y;\n", "int x = 1;\nint y;\n");
+/// assert_eq!(diff, "--- a/main.c\n+++ b/main.c\n@@ -1,2 +1,2 @@\n-int x = 1\n+int x = 1;\n int y;\n");
+/// ```
+pub fn unified_diff(name: &str, old: &str, new: &str) -> String {
+    if old == new {
+        return String::new();
+    }
+    let old_lines: Vec<&str> = old.split_inclusive('\n').collect();
+    let new_lines: Vec<&str> = new.split_inclusive('\n').collect();
+    let lines = diff_lines(&old_lines, &new_lines);
+
+    let mut output = format!("--- a/{}\n+++ b/{}\n", name, name);
+    let changes: Vec<usize> = lines.iter().enumerate()
+        .filter(|(_, line)| !matches!(line, Line::Same(_)))
+        .map(|(index, _)| index)
+        .collect();
+
+    let mut index = 0;
+    while index < changes.len() {
+        let first = changes[index];
+        let mut last = first;
+        while index + 1 < changes.len() && changes[index + 1] - last <= 2 * DIFF_CONTEXT + 1 {
+            index += 1;
+            last = changes[index];
+        }
+        index += 1;
+
+        let start = first.saturating_sub(DIFF_CONTEXT);
+        let end = (last + DIFF_CONTEXT + 1).min(lines.len());
+        let before = &lines[..start];
+        let old_start = before.iter().filter(|line| !matches!(line, Line::Added(_))).count();
+        let new_start = before.iter().filter(|line| !matches!(line, Line::Removed(_))).count();
+        let hunk = &lines[start..end];
+        let old_count = hunk.iter().filter(|line| !matches!(line, Line::Added(_))).count();
+        let new_count = hunk.iter().filter(|line| !matches!(line, Line::Removed(_))).count();
+
+        output += &format!("@@ -{} +{} @@\n", hunk_range(old_start, old_count), hunk_range(new_start, new_count));
+        for line in hunk {
+            let (marker, text) = match line {
+                Line::Same(text) => (' ', text),
+                Line::Removed(text) => ('-', text),
+                Line::Added(text) => ('+', text),
+            };
+            output.push(marker);
+            output += text;
+            if !text.ends_with('\n') {
+                output += "\n\\ No newline at end of file\n";
+            }
+        }
+    }
+    output
+}
+
+/// Accepts the machine-applicable fixes in the order they were reported, skipping those described by
+/// `collect_edits`, and returns the accepted edits of each file with the ids of the accepted fixes.
+fn accept_fixes(diagnostics: &[Diagnostic], sources: &SourceManager) -> (BTreeMap<FileId, Vec<Edit>>, Vec<FixId>) {
+    let mut accepted: BTreeMap<FileId, Vec<Edit>> = BTreeMap::new();
+    let mut applied = Vec::new();
+    let fixes = diagnostics.iter().enumerate()
+        .flat_map(|(diagnostic, item)| item.get_fixes().iter().enumerate().map(move |(fix, item)| (FixId::new(diagnostic, fix), item)))
+        .filter(|(_, fix)| fix.is_machine_applicable());
+
+    for (id, fix) in fixes {
+        let applicable = fix.edits.iter().enumerate().all(|(index, edit)| {
+            let span = edit.span;
+            let fits = sources.get_file(span.get_file()).is_some_and(|source| {
+                !matches!(source.get_provenance(), Provenance::MacroExpansion { .. })
+                    && source.get_text().get(span.get_start()..span.get_end()).is_some()
+            });
+            let earlier = accepted.get(&span.get_file()).into_iter().flatten().chain(&fix.edits[..index]);
+            fits && earlier.clone().all(|other| !overlaps(span, other.span))
+        });
+        if applicable {
+            for edit in &fix.edits {
+                accepted.entry(edit.span.get_file()).or_default().push(edit.clone());
+            }
+            applied.push(id);
+        }
+    }
+    (accepted, applied)
+}
+
+/// Checks whether two edits in the same file conflict. Insertions at the same offset do not conflict with each
+/// other, but an insertion strictly inside a replaced range does.
+fn overlaps(a: Span, b: Span) -> bool {
+    if a.get_file() != b.get_file() {
+        return false;
+    }
+    if a.is_empty() || b.is_empty() {
+        let (point, range) = if a.is_empty() { (a, b) } else { (b, a) };
+        return range.get_start() < point.get_start() && point.get_start() < range.get_end();
+    }
+    a.get_start() < b.get_end() && b.get_start() < a.get_end()
+}
+
+/// Formats the line range of a hunk header, where an empty range names the line before it and the count of a
+/// single line is left out.
+fn hunk_range(start: usize, count: usize) -> String {
+    match count {
+        0 => format!("{},0", start),
+        1 => format!("{}", start + 1),
+        count => format!("{},{}", start + 1, count),
+    }
+}
+
+/// Computes a line diff from the longest common subsequence of the lines.
+fn diff_lines<'a>(old: &[&'a str], new: &[&'a str]) -> Vec<Line<'a>> {
+    let mut common = vec![vec![0; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            common[i][j] = if old[i] == new[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut lines = Vec::new();
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            lines.push(Line::Same(old[i]));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || common[i + 1][j] >= common[i][j + 1]) {
+            lines.push(Line::Removed(old[i]));
+            i += 1;
+        } else {
+            lines.push(Line::Added(new[j]));
+            j += 1;
+        }
+    }
+    lines
+}
@@ -0,0 +1,350 @@
+//! This file computes the memory layout of data types, which the IR generator uses to size and align
+//! aggregate types such as structs and unions, and to pack bit-fields.
+
+use crate::{
+    ast::data_type::DataType,
+    error::ErrorType,
+};
+
+/// The placement of a single field inside an aggregate type.
+///
+/// # Fields
+/// * `name` - The name of the field.
+/// * `data_type` - The data type of the field.
+/// * `offset` - The offset of the field from the start of the aggregate, in bytes. For a bit-field this is
+///   the offset of the storage unit holding it.
+/// * `bit_offset` - The offset of a bit-field from the least significant bit of its storage unit.
+/// * `bit_width` - The width of a bit-field in bits, or `None` for an ordinary field.
+/// * `flexible` - Whether the field is a trailing flexible array member, which does not count towards the size.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldLayout {
+    name: String,
+    data_type: DataType,
+    offset: usize,
+    bit_offset: u32,
+    bit_width: Option<u32>,
+    flexible: bool,
+}
+
+/// The memory layout of an aggregate type.
+///
+/// # Fields
+/// * `size` - The total size of the aggregate in bytes, including trailing padding but excluding any
+///   flexible array member.
+/// * `align` - The alignment of the aggregate in bytes.
+/// * `fields` - The placement of every field, in declaration order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AggregateLayout {
+    size: usize,
+    align: usize,
+    fields: Vec<FieldLayout>,
+}
+
+impl FieldLayout {
+    /// Retrieves the name of this field.
+    ///
+    /// # Returns
+    ///
+    /// Returns the field's name as a `&str`.
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Retrieves the data type of this field.
+    ///
+    /// # Returns
+    ///
+    /// Returns the field's `DataType`.
+    pub fn get_data_type(&self) -> DataType {
+        self.data_type
+    }
+
+    /// Retrieves the offset of this field.
+    ///
+    /// # Returns
+    ///
+    /// Returns the field's offset from the start of the aggregate, in bytes.
+    pub fn get_offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Retrieves the bit offset of this field within its storage unit.
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of bits a bit-field is shifted by in its storage unit, or 0 for an ordinary field.
+    pub fn get_bit_offset(&self) -> u32 {
+        self.bit_offset
+    }
+
+    /// Retrieves the bit width of this field.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(u32)` with the width of a bit-field, or `None` for an ordinary field.
+    pub fn get_bit_width(&self) -> Option<u32> {
+        self.bit_width
+    }
+
+    /// Computes the unshifted mask selecting this bit-field's bits. Reads shift the storage unit right by
+    /// the bit offset and apply this mask; writes clear `mask << bit_offset` before or-ing in the new value.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(u64)` with the low `bit_width` bits set, or `None` for an ordinary field.
+    pub fn get_bit_mask(&self) -> Option<u64> {
+        self.bit_width.map(|width| if width >= 64 { u64::MAX } else { (1u64 << width) - 1 })
+    }
+
+    /// Checks if this field is a flexible array member.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the field is a trailing flexible array member, `false` otherwise.
+    pub fn is_flexible_array(&self) -> bool {
+        self.flexible
+    }
+}
+
+impl AggregateLayout {
+    /// Retrieves the size of this aggregate.
+    ///
+    /// # Returns
+    ///
+    /// Returns the aggregate's size in bytes, including trailing padding.
+    pub fn get_size(&self) -> usize {
+        self.size
+    }
+
+    /// Retrieves the alignment of this aggregate.
+    ///
+    /// # Returns
+    ///
+    /// Returns the aggregate's alignment in bytes.
+    pub fn get_align(&self) -> usize {
+        self.align
+    }
+
+    /// Retrieves the placement of this aggregate's fields.
+    ///
+    /// # Returns
+    ///
+    /// Returns a slice of `FieldLayout`s in declaration order.
+    pub fn get_fields(&self) -> &[FieldLayout] {
+        &self.fields
+    }
+
+    /// Retrieves the field that determines this aggregate's alignment, that is the first of its most
+    /// strictly aligned fields. The IR generator uses it as the leading member of a lowered union.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(&FieldLayout)` for the most aligned field, or `None` if the aggregate has no fields.
+    pub fn get_most_aligned_field(&self) -> Option<&FieldLayout> {
+        let mut most_aligned: Option<&FieldLayout> = None;
+        for field in &self.fields {
+            let align = align_of(&field.data_type).unwrap_or(1);
+            match most_aligned {
+                Some(current) if align_of(&current.data_type).unwrap_or(1) >= align => {},
+                _ => most_aligned = Some(field),
+            }
+        }
+        most_aligned
+    }
+}
+
+/// Computes the size of a scalar data type, as C lays it out on common 64-bit targets: 4 bytes for `int`,
+/// `unsigned` and enums, 8 for `long`, `double` and pointers.
+///
+/// # Parameters
+/// * `data_type` - The data type to size.
+///
+/// # Returns
+/// Returns `Some(usize)` with the size in bytes, or `None` if the type has no size on its own, such as
+/// `Void`, `Function`, or aggregates whose fields are only known from the symbol table.
+pub fn size_of(data_type: &DataType) -> Option<usize> {
+    match data_type {
+        DataType::Integer | DataType::Unsign | DataType::Sign | DataType::Enum | DataType::Float => Some(4),
+        DataType::Long | DataType::Double | DataType::String => Some(8),
+        DataType::Char | DataType::Boolean => Some(1),
+        DataType::Struct | DataType::Union | DataType::Function | DataType::None | DataType::Void => None,
+    }
+}
+
+/// Computes the alignment of a scalar data type. Scalars are aligned to their own size.
+///
+/// # Parameters
+/// * `data_type` - The data type to align.
+///
+/// # Returns
+/// Returns `Some(usize)` with the alignment in bytes, or `None` if the type has no size on its own.
+pub fn align_of(data_type: &DataType) -> Option<usize> {
+    size_of(data_type)
+}
+
+/// Rounds an offset up to the next multiple of an alignment.
+///
+/// # Parameters
+/// * `offset` - The offset to round.
+/// * `align` - The alignment to round to, which must be non-zero.
+///
+/// # Returns
+/// Returns the smallest multiple of `align` that is at least `offset`.
+pub fn align_to(offset: usize, align: usize) -> usize {
+    offset.div_ceil(align) * align
+}
+
+/// Computes the size and alignment of a field, failing if its type has no size on its own.
+///
+/// # Parameters
+/// * `name` - The name of the field, used in the error message.
+/// * `data_type` - The data type of the field.
+///
+/// # Returns
+/// Returns the field's `(size, align)` in bytes, or an `ErrorType` if the type has no size.
+fn field_size_and_align(name: &str, data_type: &DataType) -> Result<(usize, usize), ErrorType> {
+    match (size_of(data_type), align_of(data_type)) {
+        (Some(size), Some(align)) => Ok((size, align)),
+        _ => Err(ErrorType::DevError {
+            message: format!("Cannot compute the layout of field '{}' of type {}", name, data_type),
+        }),
+    }
+}
+
+/// Computes the layout of a struct, placing fields in declaration order at their natural alignment.
+///
+/// Bit-fields are packed into storage units of their declared type: a bit-field continues in the unit
+/// holding the previous bits if it fits there, and otherwise starts a new, aligned unit. A bit-field of
+/// width zero closes the current unit. A flexible array member is placed after every other field at the
+/// alignment of its element type, and does not count towards the size of the struct.
+///
+/// # Parameters
+/// * `fields` - The struct's fields as `(name, type)` pairs, as recorded in `SymbolValue::StructValue`.
+/// * `bit_widths` - The widths of the fields declared as bit-fields, keyed by field name.
+/// * `flexible_array` - The trailing flexible array member and its element type, if any.
+///
+/// # Returns
+/// Returns the struct's `AggregateLayout`, or an `ErrorType` if a field cannot be laid out.
+///
+/// # Errors
+/// * Returns an error if a field's type has no size, if a bit-field is not of an integer type, or if a
+///   bit-field is wider than its type.
+pub fn struct_layout(fields: &[(String, DataType)], bit_widths: &[(String, u32)], flexible_array: Option<&(String, DataType)>) -> Result<AggregateLayout, ErrorType> {
+    let mut bit_position: usize = 0;
+    let mut align: usize = 1;
+    let mut field_layouts: Vec<FieldLayout> = Vec::new();
+
+    for (name, data_type) in fields {
+        let (field_size, field_align) = field_size_and_align(name, data_type)?;
+        align = align.max(field_align);
+
+        let bit_width = bit_widths.iter().find(|(field_name, _)| field_name == name).map(|(_, width)| *width);
+        match bit_width {
+            Some(width) => {
+                if !matches!(data_type, DataType::Integer | DataType::Long | DataType::Unsign | DataType::Sign | DataType::Char | DataType::Boolean) {
+                    return Err(ErrorType::DevError {
+                        message: format!("Bit-field '{}' must have an integer type, found {}", name, data_type),
+                    });
+                }
+
+                let unit_bits: usize = field_size * 8;
+                if width as usize > unit_bits {
+                    return Err(ErrorType::DevError {
+                        message: format!("Width of bit-field '{}' ({} bits) exceeds its type {} ({} bits)", name, width, data_type, unit_bits),
+                    });
+                }
+
+                let align_bits: usize = field_align * 8;
+                if width == 0 {
+                    bit_position = align_to(bit_position, align_bits);
+                    continue;
+                }
+
+                let mut unit_start: usize = bit_position / align_bits * align_bits;
+                if bit_position + width as usize > unit_start + unit_bits {
+                    unit_start = align_to(bit_position, align_bits);
+                    bit_position = unit_start;
+                }
+
+                field_layouts.push(FieldLayout {
+                    name: name.clone(),
+                    data_type: *data_type,
+                    offset: unit_start / 8,
+                    bit_offset: (bit_position - unit_start) as u32,
+                    bit_width: Some(width),
+                    flexible: false,
+                });
+                bit_position += width as usize;
+            },
+            None => {
+                bit_position = align_to(bit_position, field_align * 8);
+                field_layouts.push(FieldLayout {
+                    name: name.clone(),
+                    data_type: *data_type,
+                    offset: bit_position / 8,
+                    bit_offset: 0,
+                    bit_width: None,
+                    flexible: false,
+                });
+                bit_position += field_size * 8;
+            },
+        }
+    }
+
+    if let Some((name, element_type)) = flexible_array {
+        let (_, element_align) = field_size_and_align(name, element_type)?;
+        align = align.max(element_align);
+        bit_position = align_to(bit_position, element_align * 8);
+        field_layouts.push(FieldLayout {
+            name: name.clone(),
+            data_type: *element_type,
+            offset: bit_position / 8,
+            bit_offset: 0,
+            bit_width: None,
+            flexible: true,
+        });
+    }
+
+    Ok(AggregateLayout {
+        size: align_to(bit_position.div_ceil(8), align),
+        align,
+        fields: field_layouts,
+    })
+}
+
+/// Computes the layout of a union. Every field is placed at offset zero, and the union is sized and aligned
+/// to hold its largest and most strictly aligned member.
+///
+/// # Parameters
+/// * `fields` - The union's fields as `(name, type)` pairs, as recorded in `SymbolValue::UnionValue`.
+///
+/// # Returns
+/// Returns the union's `AggregateLayout`, or an `ErrorType` if a field's type has no size.
+///
+/// # Errors
+/// * Returns an error if a field is `Void`, a function, or a nested aggregate.
+pub fn union_layout(fields: &[(String, DataType)]) -> Result<AggregateLayout, ErrorType> {
+    let mut size: usize = 0;
+    let mut align: usize = 1;
+    let mut field_layouts: Vec<FieldLayout> = Vec::new();
+
+    for (name, data_type) in fields {
+        let (field_size, field_align) = field_size_and_align(name, data_type)?;
+        size = size.max(field_size);
+        align = align.max(field_align);
+        field_layouts.push(FieldLayout {
+            name: name.clone(),
+            data_type: *data_type,
+            offset: 0,
+            bit_offset: 0,
+            bit_width: None,
+            flexible: false,
+        });
+    }
+
+    Ok(AggregateLayout {
+        size: align_to(size, align),
+        align,
+        fields: field_layouts,
+    })
+}
@@ -8,6 +8,37 @@ pub mod ast;
 /// Defines constants.
 pub mod constants;
 
+/// Defines diagnostics with locations, codes, severities, notes and help.
+pub mod diagnostic;
+
+/// Emits diagnostics as JSON lines and SARIF documents.
+pub mod emit;
+
 /// Defines error types.
 pub mod error;
 
+/// Explains error codes at length, with failing examples and their fixes.
+pub mod explain;
+
+/// Defines fix-its and applies them to source files.
+pub mod fix;
+
+/// Computes memory layouts of data types.
+pub mod layout;
+
+/// Defines macros for building ASTs concisely.
+mod macros;
+
+
+/// Renders diagnostics for the terminal with source snippets and colour.
+pub mod render;
+
+/// Serializes and deserializes values to and from JSON and a compact binary format.
+#[cfg(feature = "serde")]
+pub mod serialize;
+
+/// Owns the source files of a compilation and resolves locations in them.
+pub mod source;
+
+/// Finds close matches for misspelled names.
+pub mod suggest;
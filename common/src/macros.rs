@@ -0,0 +1,106 @@
+//! This file defines the `ast!` and `ast_node!` macros, which build trees from a nested, S-expression-like
+//! syntax instead of long chains of `ASTNode::new` and `add_child` calls.
+//!
+//! Node kinds and data types are resolved as `NodeType::Kind` and `DataType::Variant`, so a misspelled name is a
+//! compile-time error rather than a malformed tree.
+
+/// Builds an `ASTNode` from a nested, S-expression-like syntax. Each node is written as its `NodeType` variant
+/// name, followed by its payload in parentheses if the variant carries one, and by its children in square
+/// brackets if it has any:
+///
+/// * `Break` - a node without payload or children.
+/// * `Identifier("x")`, `Literal(5)`, `Operator("+")` - a node whose payload is converted with `to_string`.
+/// * `Type(Integer)` - a `Type` node, naming a `DataType` variant.
+/// * `BlockExpression[Break, Continue]` - a node with children, separated by commas.
+/// * `{ expr }` - an existing `ASTNode` spliced in as a child.
+///
+/// # Examples
+/// ```
+/// use common::{ast_node, ast::{core::ASTNode, node_type::NodeType}};
+///
+/// let node = ast_node!(BinaryExpression[Identifier("a"), Operator("+"), Literal(5)]);
+///
+/// let mut expected = ASTNode::new(NodeType::BinaryExpression);
+/// expected.add_children(vec![
+///     ASTNode::new(NodeType::Identifier("a".to_string())),
+///     ASTNode::new(NodeType::Operator("+".to_string())),
+///     ASTNode::new(NodeType::Literal("5".to_string())),
+/// ]);
+/// assert_eq!(node, expected);
+/// ```
+#[macro_export]
+macro_rules! ast_node {
+    (@node_type Type ($data_type:ident)) => {
+        $crate::ast::node_type::NodeType::Type($crate::ast::data_type::DataType::$data_type)
+    };
+    (@node_type $kind:ident ($payload:expr)) => {
+        $crate::ast::node_type::NodeType::$kind(::std::string::ToString::to_string(&$payload))
+    };
+
+    (@children [$($done:expr),*]) => {
+        ::std::vec![$($done),*]
+    };
+    (@children [$($done:expr),*] { $node:expr } $(, $($rest:tt)*)?) => {
+        $crate::ast_node!(@children [$($done,)* $node] $($($rest)*)?)
+    };
+    (@children [$($done:expr),*] $kind:ident ($($payload:tt)+) [$($children:tt)*] $(, $($rest:tt)*)?) => {
+        $crate::ast_node!(@children [$($done,)* $crate::ast_node!($kind ($($payload)+) [$($children)*])] $($($rest)*)?)
+    };
+    (@children [$($done:expr),*] $kind:ident ($($payload:tt)+) $(, $($rest:tt)*)?) => {
+        $crate::ast_node!(@children [$($done,)* $crate::ast_node!($kind ($($payload)+))] $($($rest)*)?)
+    };
+    (@children [$($done:expr),*] $kind:ident [$($children:tt)*] $(, $($rest:tt)*)?) => {
+        $crate::ast_node!(@children [$($done,)* $crate::ast_node!($kind [$($children)*])] $($($rest)*)?)
+    };
+    (@children [$($done:expr),*] $kind:ident $(, $($rest:tt)*)?) => {
+        $crate::ast_node!(@children [$($done,)* $crate::ast_node!($kind)] $($($rest)*)?)
+    };
+
+    ({ $node:expr }) => {
+        $node
+    };
+    ($kind:ident ($($payload:tt)+) [$($children:tt)*]) => {{
+        let mut node = $crate::ast::core::ASTNode::new($crate::ast_node!(@node_type $kind ($($payload)+)));
+        node.set_children($crate::ast_node!(@children [] $($children)*));
+        node
+    }};
+    ($kind:ident ($($payload:tt)+)) => {
+        $crate::ast::core::ASTNode::new($crate::ast_node!(@node_type $kind ($($payload)+)))
+    };
+    ($kind:ident [$($children:tt)*]) => {{
+        let mut node = $crate::ast::core::ASTNode::new($crate::ast::node_type::NodeType::$kind);
+        node.set_children($crate::ast_node!(@children [] $($children)*));
+        node
+    }};
+    ($kind:ident) => {
+        $crate::ast::core::ASTNode::new($crate::ast::node_type::NodeType::$kind)
+    };
+}
+
+/// Builds an `AST` rooted at a node written in the `ast_node!` syntax.
+///
+/// # Examples
+/// ```
+/// use common::ast;
+///
+/// let tree = ast!(FunctionDeclaration[
+///     Identifier("f"),
+///     Type(Integer),
+///     BlockExpression[
+///         Return[AssignedValue[Literal(0)]],
+///     ],
+/// ]);
+/// assert_eq!(tree.get_root_ref().get_children_ref().len(), 3);
+/// ```
+///
+/// A misspelled node kind does not compile:
+///
+/// ```compile_fail
+/// let tree = common::ast!(FunctionDecl[Identifier("f")]);
+/// ```
+#[macro_export]
+macro_rules! ast {
+    ($($node:tt)+) => {
+        $crate::ast::core::AST::new($crate::ast_node!($($node)+))
+    };
+}
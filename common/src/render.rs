@@ -0,0 +1,345 @@
+//! This file renders diagnostics for the terminal in the style of rustc and clang: a header with the severity,
+//! code and message, the location, and the offending source lines in a numbered gutter with the primary span
+//! underlined by `^` and secondary spans by `-`, followed by notes and help.
+//!
+//! ```text
+//! error[E0002]: cannot find variable `y` in this scope
+//!  --> main.c:2:9
+//!   |
+//! 1 | int x;
+//!   |     - similarly named variable `x` declared here
+//! 2 | int z = y;
+//!   |         ^ not found in this scope
+//!   |
+//!   = help: declare `y` before using it
+//! ```
+//!
+//! Colour is chosen with `ColorChoice`, which is what the driver's `--color=auto|always|never` switch parses
+//! into.
+
+use std::{
+    fmt,
+    io::{self, IsTerminal},
+    str::FromStr,
+};
+
+use crate::{
+    diagnostic::{Diagnostic, Severity},
+    error::ErrorType,
+    source::{FileId, Provenance, SourceManager, Span},
+};
+
+const RESET: &str = "\x1b[0m";
+const BOLD: &str = "\x1b[1m";
+const RED: &str = "\x1b[1;31m";
+const YELLOW: &str = "\x1b[1;33m";
+const GREEN: &str = "\x1b[1;32m";
+const CYAN: &str = "\x1b[1;36m";
+const BLUE: &str = "\x1b[1;34m";
+
+/// The number of columns a tab is expanded to in rendered source lines.
+const TAB_WIDTH: usize = 4;
+
+/// When to colour rendered diagnostics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorChoice {
+    /// Colour when writing to a terminal and `NO_COLOR` is not set.
+    #[default]
+    Auto,
+    /// Always colour.
+    Always,
+    /// Never colour.
+    Never,
+}
+
+/// Renders diagnostics against the files of a `SourceManager`.
+///
+/// # Fields
+/// * `sources` - The files the diagnostics' spans refer to.
+/// * `color` - Whether to emit ANSI colour codes.
+pub struct Renderer<'a> {
+    sources: &'a SourceManager,
+    color: bool,
+}
+
+/// A span to underline, with its label.
+struct Annotation<'a> {
+    span: Span,
+    label: Option<&'a str>,
+    primary: bool,
+}
+
+impl ColorChoice {
+    /// Decides whether to colour output written to a stream.
+    ///
+    /// # Parameters
+    /// * `is_terminal` - Whether the stream is a terminal.
+    ///
+    /// # Returns
+    /// Returns `true` if output should be coloured, `false` otherwise.
+    pub fn should_color(&self, is_terminal: bool) -> bool {
+        match self {
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+            ColorChoice::Auto => is_terminal && std::env::var_os("NO_COLOR").is_none(),
+        }
+    }
+
+    /// Decides whether to colour output written to standard error, where diagnostics are printed.
+    ///
+    /// # Returns
+    /// Returns `true` if output should be coloured, `false` otherwise.
+    pub fn should_color_stderr(&self) -> bool {
+        self.should_color(io::stderr().is_terminal())
+    }
+}
+
+impl FromStr for ColorChoice {
+    type Err = ErrorType;
+
+    /// Parses the value of a `--color` switch.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "auto" => Ok(ColorChoice::Auto),
+            "always" => Ok(ColorChoice::Always),
+            "never" => Ok(ColorChoice::Never),
+            _ => Err(ErrorType::from(Diagnostic::error(&format!("invalid color choice `{}`, expected one of auto, always, never", value)))),
+        }
+    }
+}
+
+impl fmt::Display for ColorChoice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ColorChoice::Auto => write!(f, "auto"),
+            ColorChoice::Always => write!(f, "always"),
+            ColorChoice::Never => write!(f, "never"),
+        }
+    }
+}
+
+impl<'a> Renderer<'a> {
+    /// Creates a renderer.
+    ///
+    /// # Parameters
+    /// * `sources` - The files the diagnostics' spans refer to.
+    /// * `color` - Whether to emit ANSI colour codes, usually from `ColorChoice::should_color_stderr`.
+    ///
+    /// # Returns
+    /// Returns a new `Renderer`.
+    pub fn new(sources: &'a SourceManager, color: bool) -> Self {
+        Renderer { sources, color }
+    }
+
+    /// Renders a diagnostic. Spans that do not resolve in the `SourceManager` are left out of the snippet.
+    ///
+    /// # Parameters
+    /// * `diagnostic` - The diagnostic to render.
+    ///
+    /// # Returns
+    /// Returns the rendered text, ending in a newline.
+    ///
+    /// # Examples
+    /// ```
+    /// use common::{diagnostic::Diagnostic, render::Renderer, source::{SourceManager, Span}};
+    ///
+    /// let mut sources = SourceManager::new();
+    /// let file = sources.add_file("main.c", "int x = y;\n".to_string());
+    /// let diagnostic = Diagnostic::error("cannot find variable `y` in this scope")
+    ///     .with_code("E0002")
+    ///     .with_span(Span::new(file, 8, 9), "not found in this scope");
+    ///
+    /// assert_eq!(Renderer::new(&sources, false).render(&diagnostic), [
+    ///     "error[E0002]: cannot find variable `y` in this scope",
+    ///     " --> main.c:1:9",
+    ///     "  |",
+    ///     "1 | int x = y;",
+    ///     "  |         ^ not found in this scope",
+    ///     "",
+    /// ].join("\n"));
+    /// ```
+    pub fn render(&self, diagnostic: &Diagnostic) -> String {
+        let mut annotations: Vec<Annotation> = Vec::new();
+        if let Some(span) = diagnostic.get_span() {
+            annotations.push(Annotation { span, label: diagnostic.get_span_label(), primary: true });
+        }
+        for label in diagnostic.get_labels() {
+            annotations.push(Annotation { span: label.get_span(), label: Some(label.get_message()), primary: false });
+        }
+        annotations.retain(|annotation| self.sources.get_span_location(&annotation.span).is_some());
+
+        let width = annotations.iter()
+            .filter_map(|annotation| self.sources.get_span_location(&annotation.span))
+            .map(|location| location.get_line().to_string().len())
+            .max()
+            .unwrap_or(1);
+        let pad = " ".repeat(width);
+        let severity_style = style_of(diagnostic.get_severity());
+
+        let mut output = self.paint(severity_style, diagnostic.get_severity().get_name());
+        if let Some(code) = diagnostic.get_code() {
+            output += &self.paint(severity_style, &format!("[{}]", code));
+        }
+        output += &self.paint(BOLD, &format!(": {}", diagnostic.get_message()));
+        output.push('\n');
+
+        let mut files: Vec<FileId> = Vec::new();
+        for annotation in &annotations {
+            if !files.contains(&annotation.span.get_file()) {
+                files.push(annotation.span.get_file());
+            }
+        }
+        for (index, file) in files.iter().enumerate() {
+            let in_file: Vec<&Annotation> = annotations.iter().filter(|annotation| annotation.span.get_file() == *file).collect();
+            let arrow = if index == 0 { "-->" } else { ":::" };
+            if let Some(position) = self.sources.format_span(&in_file[0].span) {
+                output += &format!("{}{} {}\n", pad, self.paint(BLUE, arrow), position);
+            }
+            output += &self.render_snippet(*file, &in_file, diagnostic.get_severity(), &pad);
+        }
+
+        let mut notes: Vec<String> = diagnostic.get_notes().to_vec();
+        if let Some(span) = diagnostic.get_span() {
+            notes.extend(self.expansion_notes(span.get_file()));
+        }
+        let mut help: Vec<&str> = diagnostic.get_help().iter().map(String::as_str).collect();
+        help.extend(diagnostic.get_fixes().iter().map(|fix| fix.get_message()));
+        if !annotations.is_empty() && (!notes.is_empty() || !help.is_empty()) {
+            output += &format!("{}{}\n", pad, self.paint(BLUE, " |"));
+        }
+        for note in &notes {
+            output += &format!("{} {} {}: {}\n", pad, self.paint(BLUE, "="), self.paint(BOLD, "note"), note);
+        }
+        for help in help {
+            output += &format!("{} {} {}: {}\n", pad, self.paint(BLUE, "="), self.paint(CYAN, "help"), help);
+        }
+        output
+    }
+
+    /// Renders several diagnostics separated by blank lines, followed by a summary of the error and warning
+    /// counts if there are any.
+    ///
+    /// # Parameters
+    /// * `diagnostics` - The diagnostics to render.
+    ///
+    /// # Returns
+    /// Returns the rendered text.
+    pub fn render_all(&self, diagnostics: &[Diagnostic]) -> String {
+        let mut rendered: Vec<String> = diagnostics.iter().map(|diagnostic| self.render(diagnostic)).collect();
+        if let Some(summary) = self.render_summary(diagnostics) {
+            rendered.push(summary);
+        }
+        rendered.join("\n")
+    }
+
+    /// Renders a summary line such as `error: aborting due to 2 previous errors; 1 warning emitted`.
+    ///
+    /// # Parameters
+    /// * `diagnostics` - The diagnostics to summarize.
+    ///
+    /// # Returns
+    /// Returns `Some(String)` with the summary, or `None` if there are no errors or warnings.
+    pub fn render_summary(&self, diagnostics: &[Diagnostic]) -> Option<String> {
+        let errors = diagnostics.iter().filter(|diagnostic| diagnostic.get_severity() == Severity::Error).count();
+        let warnings = diagnostics.iter().filter(|diagnostic| diagnostic.get_severity() == Severity::Warning).count();
+        let plural = |count: usize, noun: &str| format!("{} {}{}", count, noun, if count == 1 { "" } else { "s" });
+        let message = match (errors, warnings) {
+            (0, 0) => return None,
+            (0, warnings) => return Some(format!("{}: {} emitted\n", self.paint(YELLOW, "warning"), self.paint(BOLD, &plural(warnings, "warning")))),
+            (errors, 0) => format!("aborting due to {}", plural(errors, "previous error")),
+            (errors, warnings) => format!("aborting due to {}; {} emitted", plural(errors, "previous error"), plural(warnings, "warning")),
+        };
+        Some(format!("{}{}\n", self.paint(RED, "error"), self.paint(BOLD, &format!(": {}", message))))
+    }
+
+    /// Renders the numbered source lines of one file with their underlines.
+    fn render_snippet(&self, file: FileId, annotations: &[&Annotation], severity: Severity, pad: &str) -> String {
+        let mut output = format!("{}{}\n", pad, self.paint(BLUE, " |"));
+        let mut lines: Vec<usize> = annotations.iter()
+            .filter_map(|annotation| self.sources.get_span_location(&annotation.span))
+            .map(|location| location.get_line())
+            .collect();
+        lines.sort_unstable();
+        lines.dedup();
+
+        let mut previous: Option<usize> = None;
+        for line in lines {
+            if previous.is_some_and(|previous| line > previous + 1) {
+                output += &format!("{}\n", self.paint(BLUE, "..."));
+            }
+            previous = Some(line);
+            let text = self.sources.get_line_text(file, line).unwrap_or("");
+            let shown = expand_tabs(text);
+            let separator = if shown.trim_end().is_empty() { "" } else { " " };
+            output += &format!("{}{}{}\n", self.paint(BLUE, &format!("{:>width$} |", line, width = pad.len())), separator, shown.trim_end());
+
+            let mut on_line: Vec<(usize, usize, &Annotation)> = annotations.iter()
+                .filter_map(|annotation| {
+                    let location = self.sources.get_span_location(&annotation.span)?;
+                    if location.get_line() != line {
+                        return None;
+                    }
+                    let line_start = self.sources.get_file(file)?.get_line_start(line)?;
+                    let start = annotation.span.get_start() - line_start;
+                    let end = (annotation.span.get_end() - line_start).min(text.len()).max(start);
+                    let column = display_width(text.get(..start).unwrap_or(text));
+                    let length = display_width(text.get(start..end).unwrap_or("")).max(1);
+                    Some((column, length, *annotation))
+                })
+                .collect();
+            on_line.sort_by_key(|(column, _, annotation)| (*column, !annotation.primary));
+            for (column, length, annotation) in on_line {
+                let (marker, style) = if annotation.primary { ('^', style_of(severity)) } else { ('-', BLUE) };
+                let mut underline = marker.to_string().repeat(length);
+                if let Some(label) = annotation.label {
+                    underline = format!("{} {}", underline, label);
+                }
+                output += &format!("{}{} {}{}\n", pad, self.paint(BLUE, " |"), " ".repeat(column), self.paint(style, &underline));
+            }
+        }
+        output
+    }
+
+    /// Describes the include directives and macro invocations that led to a file, innermost first.
+    fn expansion_notes(&self, file: FileId) -> Vec<String> {
+        let mut notes = Vec::new();
+        let mut current = file;
+        for origin in self.sources.get_expansion_chain(file) {
+            let position = self.sources.format_span(&origin).unwrap_or_else(|| origin.to_string());
+            match self.sources.get_file(current).map(|source| source.get_provenance()) {
+                Some(Provenance::MacroExpansion { name, .. }) => notes.push(format!("in this expansion of macro `{}` at {}", name, position)),
+                _ => notes.push(format!("in the file included from {}", position)),
+            }
+            current = origin.get_file();
+        }
+        notes
+    }
+
+    /// Wraps text in an ANSI style if colour is enabled.
+    fn paint(&self, style: &str, text: &str) -> String {
+        if self.color {
+            format!("{}{}{}", style, text, RESET)
+        } else {
+            text.to_string()
+        }
+    }
+}
+
+/// Retrieves the colour used for a severity.
+fn style_of(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => RED,
+        Severity::Warning => YELLOW,
+        Severity::Note => GREEN,
+    }
+}
+
+/// Computes the number of columns text occupies once tabs are expanded.
+fn display_width(text: &str) -> usize {
+    text.chars().map(|c| if c == '\t' { TAB_WIDTH } else { 1 }).sum()
+}
+
+/// Expands the tabs of a line to spaces, so underlines line up with the text above them.
+fn expand_tabs(text: &str) -> String {
+    text.replace('\t', &" ".repeat(TAB_WIDTH))
+}
@@ -0,0 +1,97 @@
+//! This file converts values such as an `AST` or a `SymbolTableStack` to and from JSON and a compact binary
+//! format, so that external tools and test fixtures can exchange them without rebuilding them node by node.
+//!
+//! Only available with the `serde` feature enabled.
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::error::ErrorType;
+
+/// Serializes a value to a JSON string.
+///
+/// # Parameters
+/// * `value` - The value to serialize.
+///
+/// # Returns
+/// Returns the value as a JSON `String`, or an `ErrorType` if it cannot be serialized.
+///
+/// # Errors
+/// * Returns an error if the value fails to serialize.
+///
+/// # Examples
+/// ```
+/// use common::{ast::{core::{ASTNode, AST}, node_type::NodeType}, serialize::{from_json, to_json}};
+///
+/// let ast = AST::new(ASTNode::new(NodeType::TopLevelExpression));
+/// let json = to_json(&ast).unwrap();
+/// assert_eq!(from_json::<AST>(&json).unwrap(), ast);
+/// ```
+pub fn to_json<T: Serialize>(value: &T) -> Result<String, ErrorType> {
+    serde_json::to_string(value).map_err(|err| ErrorType::DevError {
+        message: format!("Failed to serialize to JSON: {}", err),
+    })
+}
+
+/// Serializes a value to an indented, human-readable JSON string, as used for checked-in test fixtures.
+///
+/// # Parameters
+/// * `value` - The value to serialize.
+///
+/// # Returns
+/// Returns the value as a JSON `String`, or an `ErrorType` if it cannot be serialized.
+///
+/// # Errors
+/// * Returns an error if the value fails to serialize.
+pub fn to_json_pretty<T: Serialize>(value: &T) -> Result<String, ErrorType> {
+    serde_json::to_string_pretty(value).map_err(|err| ErrorType::DevError {
+        message: format!("Failed to serialize to JSON: {}", err),
+    })
+}
+
+/// Deserializes a value from a JSON string.
+///
+/// # Parameters
+/// * `json` - The JSON to deserialize.
+///
+/// # Returns
+/// Returns the deserialized value, or an `ErrorType` if the JSON does not describe a value of type `T`.
+///
+/// # Errors
+/// * Returns an error if the input is not valid JSON or does not match the shape of `T`.
+pub fn from_json<T: DeserializeOwned>(json: &str) -> Result<T, ErrorType> {
+    serde_json::from_str(json).map_err(|err| ErrorType::DevError {
+        message: format!("Failed to deserialize from JSON: {}", err),
+    })
+}
+
+/// Serializes a value to the compact binary format.
+///
+/// # Parameters
+/// * `value` - The value to serialize.
+///
+/// # Returns
+/// Returns the encoded bytes, or an `ErrorType` if the value cannot be serialized.
+///
+/// # Errors
+/// * Returns an error if the value fails to serialize.
+pub fn to_binary<T: Serialize>(value: &T) -> Result<Vec<u8>, ErrorType> {
+    bincode::serialize(value).map_err(|err| ErrorType::DevError {
+        message: format!("Failed to serialize to binary: {}", err),
+    })
+}
+
+/// Deserializes a value from the compact binary format.
+///
+/// # Parameters
+/// * `bytes` - The encoded bytes, as produced by `to_binary`.
+///
+/// # Returns
+/// Returns the deserialized value, or an `ErrorType` if the bytes do not encode a value of type `T`.
+///
+/// # Errors
+/// * Returns an error if the bytes are truncated or do not match the shape of `T`.
+pub fn from_binary<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, ErrorType> {
+    bincode::deserialize(bytes).map_err(|err| ErrorType::DevError {
+        message: format!("Failed to deserialize from binary: {}", err),
+    })
+}
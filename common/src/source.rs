@@ -0,0 +1,503 @@
+//! This file defines the `SourceManager`, which owns the text of every file in a compilation and hands out
+//! `FileId`s, so each phase can report a location as a `Span` of byte offsets and resolve it to a file name,
+//! line, column and line text only when printing.
+//!
+//! Files are registered as loaded from disk or from memory, as included by another file, or as the text
+//! produced by expanding a macro. Included files and expansions remember the `Span` they came from, so a
+//! location inside them can be traced back to the file the user wrote.
+
+use std::{fmt, fs, path::Path};
+
+use crate::{diagnostic::Diagnostic, error::ErrorType};
+
+/// A handle to a file registered with a `SourceManager`. Ids are stable for the lifetime of the manager,
+/// since files are never removed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FileId(usize);
+
+/// A range of bytes in a file, from `start` up to but not including `end`.
+///
+/// # Fields
+/// * `file` - The file the range is in.
+/// * `start` - The byte offset of the first byte of the range.
+/// * `end` - The byte offset just past the last byte of the range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Span {
+    file: FileId,
+    start: usize,
+    end: usize,
+}
+
+/// A resolved position in a file.
+///
+/// # Fields
+/// * `file` - The file the position is in.
+/// * `line` - The line number, starting at 1.
+/// * `column` - The column number in characters, starting at 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Location {
+    file: FileId,
+    line: usize,
+    column: usize,
+}
+
+/// Where the text of a file came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Provenance {
+    /// A file given to the compiler directly, from disk or from memory.
+    Root,
+    /// A file included by another file.
+    Include {
+        /// The span of the include directive.
+        directive: Span,
+    },
+    /// The text produced by expanding a macro.
+    MacroExpansion {
+        /// The name of the expanded macro.
+        name: String,
+        /// The span of the macro invocation.
+        call_site: Span,
+    },
+}
+
+/// A file registered with a `SourceManager`.
+///
+/// # Fields
+/// * `id` - The id of the file.
+/// * `name` - The path or display name of the file.
+/// * `text` - The contents of the file.
+/// * `line_starts` - The byte offset at which each line starts.
+/// * `provenance` - Where the text of the file came from.
+#[derive(Debug, Clone)]
+pub struct SourceFile {
+    id: FileId,
+    name: String,
+    text: String,
+    line_starts: Vec<usize>,
+    provenance: Provenance,
+}
+
+/// Owns the files of a compilation and resolves spans in them.
+///
+/// # Fields
+/// * `files` - The registered files, indexed by `FileId`.
+#[derive(Debug, Clone, Default)]
+pub struct SourceManager {
+    files: Vec<SourceFile>,
+}
+
+impl FileId {
+    /// Retrieves the position of this file in its `SourceManager`.
+    ///
+    /// # Returns
+    /// Returns the index of the file.
+    pub fn get_index(&self) -> usize {
+        self.0
+    }
+}
+
+impl fmt::Display for FileId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "file#{}", self.0)
+    }
+}
+
+impl Span {
+    /// Creates a span.
+    ///
+    /// # Parameters
+    /// * `file` - The file the range is in.
+    /// * `start` - The byte offset of the first byte of the range.
+    /// * `end` - The byte offset just past the last byte of the range.
+    ///
+    /// # Returns
+    /// Returns a new `Span`, with `start` and `end` swapped if they are out of order.
+    pub fn new(file: FileId, start: usize, end: usize) -> Self {
+        Span { file, start: start.min(end), end: start.max(end) }
+    }
+
+    /// Retrieves the file this span is in.
+    ///
+    /// # Returns
+    /// Returns the span's `FileId`.
+    pub fn get_file(&self) -> FileId {
+        self.file
+    }
+
+    /// Retrieves the offset at which this span starts.
+    ///
+    /// # Returns
+    /// Returns the byte offset of the first byte.
+    pub fn get_start(&self) -> usize {
+        self.start
+    }
+
+    /// Retrieves the offset at which this span ends.
+    ///
+    /// # Returns
+    /// Returns the byte offset just past the last byte.
+    pub fn get_end(&self) -> usize {
+        self.end
+    }
+
+    /// Retrieves the length of this span.
+    ///
+    /// # Returns
+    /// Returns the number of bytes covered.
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// Checks whether this span covers no bytes, as for a position rather than a range.
+    ///
+    /// # Returns
+    /// Returns `true` if the span is empty, `false` otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Creates the smallest span covering this span and another in the same file.
+    ///
+    /// # Parameters
+    /// * `other` - The other span.
+    ///
+    /// # Returns
+    /// Returns the covering `Span`, or `None` if the spans are in different files.
+    pub fn merge(&self, other: &Span) -> Option<Span> {
+        if self.file != other.file {
+            return None;
+        }
+        Some(Span::new(self.file, self.start.min(other.start), self.end.max(other.end)))
+    }
+}
+
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}[{}..{}]", self.file, self.start, self.end)
+    }
+}
+
+impl Location {
+    /// Retrieves the file this location is in.
+    ///
+    /// # Returns
+    /// Returns the location's `FileId`.
+    pub fn get_file(&self) -> FileId {
+        self.file
+    }
+
+    /// Retrieves the line number of this location.
+    ///
+    /// # Returns
+    /// Returns the line number, starting at 1.
+    pub fn get_line(&self) -> usize {
+        self.line
+    }
+
+    /// Retrieves the column number of this location.
+    ///
+    /// # Returns
+    /// Returns the column number in characters, starting at 1.
+    pub fn get_column(&self) -> usize {
+        self.column
+    }
+}
+
+impl SourceFile {
+    /// Creates a file and computes its line table.
+    fn new(id: FileId, name: String, text: String, provenance: Provenance) -> Self {
+        let mut line_starts = vec![0];
+        line_starts.extend(text.match_indices('\n').map(|(index, _)| index + 1));
+        SourceFile { id, name, text, line_starts, provenance }
+    }
+
+    /// Retrieves the id of this file.
+    ///
+    /// # Returns
+    /// Returns the file's `FileId`.
+    pub fn get_id(&self) -> FileId {
+        self.id
+    }
+
+    /// Retrieves the name of this file.
+    ///
+    /// # Returns
+    /// Returns the path or display name the file was registered with.
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Retrieves the contents of this file.
+    ///
+    /// # Returns
+    /// Returns the text of the file.
+    pub fn get_text(&self) -> &str {
+        &self.text
+    }
+
+    /// Retrieves where the text of this file came from.
+    ///
+    /// # Returns
+    /// Returns the file's `Provenance`.
+    pub fn get_provenance(&self) -> &Provenance {
+        &self.provenance
+    }
+
+    /// Retrieves the number of lines in this file. A trailing newline starts a final, empty line.
+    ///
+    /// # Returns
+    /// Returns the number of lines, which is at least 1.
+    pub fn get_line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    /// Resolves a byte offset to a line and column.
+    ///
+    /// # Parameters
+    /// * `offset` - The byte offset, which may equal the length of the text to point past its end.
+    ///
+    /// # Returns
+    /// Returns `Some(Location)` if the offset is in the file and on a character boundary, `None` otherwise.
+    pub fn get_location(&self, offset: usize) -> Option<Location> {
+        if !self.text.is_char_boundary(offset) {
+            return None;
+        }
+        let line_index = self.line_starts.partition_point(|start| *start <= offset) - 1;
+        let column = self.text[self.line_starts[line_index]..offset].chars().count() + 1;
+        Some(Location { file: self.id, line: line_index + 1, column })
+    }
+
+    /// Retrieves the byte offset at which a line starts.
+    ///
+    /// # Parameters
+    /// * `line` - The line number, starting at 1.
+    ///
+    /// # Returns
+    /// Returns `Some(usize)` with the offset, or `None` if the line does not exist.
+    pub fn get_line_start(&self, line: usize) -> Option<usize> {
+        self.line_starts.get(line.checked_sub(1)?).copied()
+    }
+
+    /// Retrieves the text of a line, without its line terminator.
+    ///
+    /// # Parameters
+    /// * `line` - The line number, starting at 1.
+    ///
+    /// # Returns
+    /// Returns `Some(&str)` with the line, or `None` if the line does not exist.
+    pub fn get_line_text(&self, line: usize) -> Option<&str> {
+        let start = self.get_line_start(line)?;
+        let end = self.line_starts.get(line).map_or(self.text.len(), |next| next - 1);
+        Some(self.text[start..end].trim_end_matches('\r'))
+    }
+
+    /// Retrieves the text covered by a span in this file.
+    ///
+    /// # Parameters
+    /// * `span` - The span to retrieve.
+    ///
+    /// # Returns
+    /// Returns `Some(&str)` with the text, or `None` if the span is in another file or out of bounds.
+    pub fn get_snippet(&self, span: &Span) -> Option<&str> {
+        if span.file != self.id {
+            return None;
+        }
+        self.text.get(span.start..span.end)
+    }
+}
+
+impl SourceManager {
+    /// Creates a manager with no files.
+    ///
+    /// # Returns
+    /// Returns a new `SourceManager`.
+    pub fn new() -> Self {
+        SourceManager::default()
+    }
+
+    /// Reads a file from disk and registers it as a root file.
+    ///
+    /// # Parameters
+    /// * `path` - The path of the file.
+    ///
+    /// # Returns
+    /// Returns the `FileId` of the file, or an `ErrorType` if it cannot be read.
+    ///
+    /// # Errors
+    /// * Returns an error diagnostic if the file does not exist or is not valid UTF-8.
+    pub fn load(&mut self, path: &Path) -> Result<FileId, ErrorType> {
+        let text = fs::read_to_string(path).map_err(|error| {
+            ErrorType::from(Diagnostic::error(&format!("couldn't read `{}`: {}", path.display(), error)))
+        })?;
+        Ok(self.add_file(&path.display().to_string(), text))
+    }
+
+    /// Registers text held in memory as a root file.
+    ///
+    /// # Parameters
+    /// * `name` - The name to show for the file, such as its path or `<stdin>`.
+    /// * `text` - The contents of the file.
+    ///
+    /// # Returns
+    /// Returns the `FileId` of the file.
+    ///
+    /// # Examples
+    /// ```
+    /// use common::source::SourceManager;
+    ///
+    /// let mut sources = SourceManager::new();
+    /// let file = sources.add_file("main.c", "int x;\nint y = z;\n".to_string());
+    /// let location = sources.get_location(file, 15).unwrap();
+    /// assert_eq!((location.get_line(), location.get_column()), (2, 9));
+    /// assert_eq!(sources.get_line_text(file, 2), Some("int y = z;"));
+    /// ```
+    pub fn add_file(&mut self, name: &str, text: String) -> FileId {
+        self.register(name.to_string(), text, Provenance::Root)
+    }
+
+    /// Registers the text of a file included by another file.
+    ///
+    /// # Parameters
+    /// * `name` - The path of the included file.
+    /// * `text` - The contents of the included file.
+    /// * `directive` - The span of the include directive in the including file.
+    ///
+    /// # Returns
+    /// Returns the `FileId` of the included file.
+    pub fn add_include(&mut self, name: &str, text: String, directive: Span) -> FileId {
+        self.register(name.to_string(), text, Provenance::Include { directive })
+    }
+
+    /// Registers the text produced by expanding a macro.
+    ///
+    /// # Parameters
+    /// * `macro_name` - The name of the expanded macro.
+    /// * `text` - The expansion.
+    /// * `call_site` - The span of the macro invocation.
+    ///
+    /// # Returns
+    /// Returns the `FileId` of the expansion, named `<macro NAME>`.
+    pub fn add_macro_expansion(&mut self, macro_name: &str, text: String, call_site: Span) -> FileId {
+        self.register(format!("<macro {}>", macro_name), text, Provenance::MacroExpansion { name: macro_name.to_string(), call_site })
+    }
+
+    /// Retrieves a registered file.
+    ///
+    /// # Parameters
+    /// * `file` - The id of the file.
+    ///
+    /// # Returns
+    /// Returns `Some(&SourceFile)` if the id was issued by this manager, `None` otherwise.
+    pub fn get_file(&self, file: FileId) -> Option<&SourceFile> {
+        self.files.get(file.0)
+    }
+
+    /// Retrieves every registered file, in the order they were registered.
+    ///
+    /// # Returns
+    /// Returns the files as a slice.
+    pub fn get_files(&self) -> &[SourceFile] {
+        &self.files
+    }
+
+    /// Retrieves the name of a file.
+    ///
+    /// # Parameters
+    /// * `file` - The id of the file.
+    ///
+    /// # Returns
+    /// Returns `Some(&str)` with the name, or `None` if the id is unknown.
+    pub fn get_name(&self, file: FileId) -> Option<&str> {
+        self.get_file(file).map(SourceFile::get_name)
+    }
+
+    /// Resolves a byte offset in a file to a line and column.
+    ///
+    /// # Parameters
+    /// * `file` - The id of the file.
+    /// * `offset` - The byte offset.
+    ///
+    /// # Returns
+    /// Returns `Some(Location)`, or `None` if the file is unknown or the offset is not in it.
+    pub fn get_location(&self, file: FileId, offset: usize) -> Option<Location> {
+        self.get_file(file)?.get_location(offset)
+    }
+
+    /// Resolves the start of a span to a line and column.
+    ///
+    /// # Parameters
+    /// * `span` - The span.
+    ///
+    /// # Returns
+    /// Returns `Some(Location)`, or `None` if the span does not resolve.
+    pub fn get_span_location(&self, span: &Span) -> Option<Location> {
+        self.get_location(span.file, span.start)
+    }
+
+    /// Retrieves the text of a line in a file, without its line terminator.
+    ///
+    /// # Parameters
+    /// * `file` - The id of the file.
+    /// * `line` - The line number, starting at 1.
+    ///
+    /// # Returns
+    /// Returns `Some(&str)` with the line, or `None` if the file or line does not exist.
+    pub fn get_line_text(&self, file: FileId, line: usize) -> Option<&str> {
+        self.get_file(file)?.get_line_text(line)
+    }
+
+    /// Retrieves the text covered by a span.
+    ///
+    /// # Parameters
+    /// * `span` - The span.
+    ///
+    /// # Returns
+    /// Returns `Some(&str)` with the text, or `None` if the span does not resolve.
+    pub fn get_snippet(&self, span: &Span) -> Option<&str> {
+        self.get_file(span.file)?.get_snippet(span)
+    }
+
+    /// Retrieves the chain of include directives and macro invocations that led to a file.
+    ///
+    /// # Parameters
+    /// * `file` - The id of the file.
+    ///
+    /// # Returns
+    /// Returns the spans of the directives and invocations, innermost first, ending in a root file. The vector
+    /// is empty for a root file.
+    pub fn get_expansion_chain(&self, file: FileId) -> Vec<Span> {
+        let mut chain = Vec::new();
+        let mut current = file;
+        while let Some(source) = self.get_file(current) {
+            let origin = match source.get_provenance() {
+                Provenance::Root => break,
+                Provenance::Include { directive } => *directive,
+                Provenance::MacroExpansion { call_site, .. } => *call_site,
+            };
+            chain.push(origin);
+            current = origin.file;
+        }
+        chain
+    }
+
+    /// Formats a span as `name:line:column`, the form used at the head of diagnostics.
+    ///
+    /// # Parameters
+    /// * `span` - The span.
+    ///
+    /// # Returns
+    /// Returns `Some(String)`, or `None` if the span does not resolve.
+    pub fn format_span(&self, span: &Span) -> Option<String> {
+        let location = self.get_span_location(span)?;
+        Some(format!("{}:{}:{}", self.get_name(span.file)?, location.line, location.column))
+    }
+
+    /// Adds a file to the manager.
+    fn register(&mut self, name: String, text: String, provenance: Provenance) -> FileId {
+        let id = FileId(self.files.len());
+        self.files.push(SourceFile::new(id, name, text, provenance));
+        id
+    }
+}
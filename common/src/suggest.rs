@@ -0,0 +1,107 @@
+//! This file finds close matches for misspelled names, so that errors about undefined variables, functions,
+//! fields and variants can suggest what was probably meant.
+//!
+//! Names are compared with the optimal string alignment distance: the number of single-character insertions,
+//! deletions, substitutions and transpositions of adjacent characters needed to turn one name into the other.
+//! A candidate that differs only in case always matches, however long the name.
+
+/// Computes the optimal string alignment distance between two names, counting characters rather than bytes.
+///
+/// # Parameters
+/// * `a` - The first name.
+/// * `b` - The second name.
+///
+/// # Returns
+/// Returns the number of insertions, deletions, substitutions and adjacent transpositions separating the names.
+///
+/// # Examples
+/// ```
+/// use common::suggest::edit_distance;
+///
+/// assert_eq!(edit_distance("count", "count"), 0);
+/// assert_eq!(edit_distance("cuont", "count"), 1);
+/// assert_eq!(edit_distance("cnt", "count"), 2);
+/// ```
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut rows = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in rows[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            let mut distance = (rows[i - 1][j] + 1)
+                .min(rows[i][j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = distance;
+        }
+    }
+    rows[a.len()][b.len()]
+}
+
+/// Retrieves the largest distance at which a candidate is still suggested for a name: a third of its length,
+/// but at least one.
+///
+/// # Parameters
+/// * `name` - The name that was not found.
+///
+/// # Returns
+/// Returns the largest accepted distance.
+pub fn max_distance(name: &str) -> usize {
+    (name.chars().count() / 3).max(1)
+}
+
+/// Finds the candidate closest to a name that was not found.
+///
+/// A candidate equal to the name up to case is preferred over any other. Otherwise the candidate with the
+/// smallest distance within `max_distance` is chosen, and ties go to the candidate listed first, so callers
+/// should list candidates from the innermost scope outwards.
+///
+/// # Parameters
+/// * `name` - The name that was not found.
+/// * `candidates` - The names that are visible where it was used.
+///
+/// # Returns
+/// Returns the best candidate, or `None` if none is close enough. The name itself is never suggested.
+///
+/// # Examples
+/// ```
+/// use common::suggest::best_match;
+///
+/// let candidates = ["total", "count", "MAX_SIZE"];
+/// assert_eq!(best_match("cuont", candidates), Some("count"));
+/// assert_eq!(best_match("max_size", candidates), Some("MAX_SIZE"));
+/// assert_eq!(best_match("index", candidates), None);
+/// ```
+pub fn best_match<'a, I>(name: &str, candidates: I) -> Option<&'a str>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let limit = max_distance(name);
+    let lowercase = name.to_lowercase();
+    let mut best: Option<(usize, &'a str)> = None;
+
+    for candidate in candidates {
+        if candidate == name {
+            continue;
+        }
+        if candidate.to_lowercase() == lowercase {
+            return Some(candidate);
+        }
+        let distance = edit_distance(name, candidate);
+        if distance <= limit && !matches!(best, Some((best_distance, _)) if best_distance <= distance) {
+            best = Some((distance, candidate));
+        }
+    }
+    best.map(|(_, candidate)| candidate)
+}
@@ -0,0 +1,73 @@
+//! This file contains tests for the arena-backed `ArenaAST` and its side tables.
+
+use common::{
+    ast, ast_node,
+    ast::{
+        arena::{ArenaAST, TypeTable},
+        core::AST,
+        data_type::DataType,
+        node_type::NodeType,
+    },
+};
+
+/// Tests that converting to an arena and back is lossless.
+#[test]
+fn test_round_trip() {
+    let ast = ast!(TopLevelExpression[Assignment[Identifier("x"), BinaryExpression[Identifier("a"), Operator("+"), Literal(5)]]]);
+    let arena = ArenaAST::from_ast(&ast);
+    assert_eq!(arena.size(), 7);
+    assert_eq!(arena.to_ast(), ast);
+    assert_eq!(AST::from(&ArenaAST::from(&ast)), ast);
+}
+
+/// Tests that nodes are numbered in pre-order and linked to their parents.
+#[test]
+fn test_children_and_parents() {
+    let arena = ArenaAST::from_ast(&ast!(TopLevelExpression[Assignment[Identifier("x"), BinaryExpression[Identifier("a"), Operator("+"), Literal(5)]]]));
+    let root = arena.get_root();
+    assert_eq!(arena.get_parent(root), None);
+
+    let assignment = arena.get_children(root)[0];
+    assert_eq!(arena.get_node_type(assignment), &NodeType::Assignment);
+    assert_eq!(arena.get_parent(assignment), Some(root));
+
+    let sum = arena.get_children(assignment)[1];
+    let literal = arena.get_children(sum)[2];
+    assert_eq!(literal.get_index(), 6);
+    assert_eq!(arena.get_node_type(literal), &NodeType::Literal("5".to_string()));
+    assert_eq!(arena.get_ancestors(literal), vec![sum, assignment, root]);
+
+    let order: Vec<usize> = arena.get_descendants(root).iter().map(|id| id.get_index()).collect();
+    assert_eq!(order, vec![0, 1, 2, 3, 4, 5, 6]);
+}
+
+/// Tests building an arena directly and grafting an `ASTNode` subtree into it.
+#[test]
+fn test_build_arena() {
+    let mut arena = ArenaAST::new(NodeType::BlockExpression);
+    let root = arena.get_root();
+    let ret = arena.add_child(root, NodeType::Return);
+    let grafted = arena.insert_subtree(ret, &ast_node!(Literal(0)));
+
+    assert_eq!(arena.get_parent(grafted), Some(ret));
+    assert_eq!(arena.to_node(ret), ast_node!(Return[Literal(0)]));
+}
+
+/// Tests recording, replacing and removing facts in a side table.
+#[test]
+fn test_side_table() {
+    let arena = ArenaAST::from_ast(&ast!(TopLevelExpression[Assignment[Identifier("x"), BinaryExpression[Identifier("a"), Operator("+"), Literal(5)]]]));
+    let assignment = arena.get_children(arena.get_root())[0];
+    let sum = arena.get_children(assignment)[1];
+
+    let mut types = TypeTable::new();
+    assert!(types.is_empty());
+    assert_eq!(types.insert(sum, DataType::Integer), None);
+    assert_eq!(types.insert(sum, DataType::Long), Some(DataType::Integer));
+    assert_eq!(types.get(sum), Some(&DataType::Long));
+    assert_eq!(types.get(assignment), None);
+    assert_eq!(types.entries(), vec![(sum, &DataType::Long)]);
+    assert_eq!(types.remove(sum), Some(DataType::Long));
+    assert!(types.is_empty());
+    assert_eq!(format!("{}", sum), "#3");
+}
@@ -0,0 +1,45 @@
+//! This file contains tests for the operations on `DataType`.
+
+use common::ast::data_type::DataType;
+
+/// Tests that the default argument promotions widen floats, characters and booleans.
+#[test]
+fn test_default_argument_promotion() {
+    assert_eq!(DataType::Float.default_argument_promotion(), DataType::Double);
+    assert_eq!(DataType::Char.default_argument_promotion(), DataType::Integer);
+    assert_eq!(DataType::Boolean.default_argument_promotion(), DataType::Integer);
+}
+
+/// Tests that types already at least as wide as an `int` or a `double` are left unchanged.
+#[test]
+fn test_default_argument_promotion_unchanged() {
+    for data_type in [DataType::Integer, DataType::Long, DataType::Double, DataType::String, DataType::Unsign] {
+        assert_eq!(data_type.default_argument_promotion(), data_type);
+    }
+}
+
+/// Tests the classification of integer and arithmetic types and the integer promotions.
+#[test]
+fn test_integer_promotion() {
+    assert!(DataType::Enum.is_integer());
+    assert!(!DataType::Float.is_integer());
+    assert!(DataType::Float.is_arithmetic());
+    assert!(!DataType::String.is_arithmetic());
+    for data_type in [DataType::Char, DataType::Boolean, DataType::Enum, DataType::Sign] {
+        assert_eq!(data_type.integer_promotion(), DataType::Integer);
+    }
+    assert_eq!(DataType::Float.integer_promotion(), DataType::Float);
+}
+
+/// Tests the common type chosen by the usual arithmetic conversions.
+#[test]
+fn test_usual_arithmetic_conversion() {
+    let conversion = |left: DataType, right: DataType| left.usual_arithmetic_conversion(&right);
+    assert_eq!(conversion(DataType::Boolean, DataType::Char), Some(DataType::Integer));
+    assert_eq!(conversion(DataType::Integer, DataType::Unsign), Some(DataType::Unsign));
+    assert_eq!(conversion(DataType::Long, DataType::Unsign), Some(DataType::Long));
+    assert_eq!(conversion(DataType::Long, DataType::Float), Some(DataType::Float));
+    assert_eq!(conversion(DataType::Double, DataType::Float), Some(DataType::Double));
+    assert_eq!(conversion(DataType::String, DataType::Integer), None);
+    assert_eq!(conversion(DataType::Void, DataType::Void), None);
+}
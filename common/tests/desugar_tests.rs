@@ -0,0 +1,152 @@
+//! This file contains tests for the desugaring pass that lowers convenience constructs to a core language.
+
+use common::{
+    ast,
+    ast::{
+        core::ASTNode,
+        desugar::{desugar, Desugarer, Lowering},
+        diff::assert_ast_eq,
+        query::Query,
+        validate::validate,
+        visit::Fold,
+    },
+    ast_node,
+};
+
+/// Builds the lowered form of `i++`.
+fn lowered_increment() -> ASTNode {
+    ast_node!(Assignment[Identifier("i"), BinaryExpression[Identifier("i"), Operator("+"), Literal(1)]])
+}
+
+/// Tests that a for loop becomes a while loop in a block with its initializer, with `continue` running the
+/// increment first.
+#[test]
+fn test_for_loop() {
+    let tree = ast!(BlockExpression[ForLoop[
+        LoopInitializer[Initialization[Variable[Identifier("i"), Type(Integer)], AssignedValue[Literal(0)]]],
+        Condition[BinaryExpression[Identifier("i"), Operator("<"), Literal(10)]],
+        LoopIncrement[UnaryExpression[Identifier("i"), Operator("++")]],
+        BlockExpression[
+            IfStatement[Condition[Identifier("skip")], BlockExpression[Continue]],
+            WhileLoop[Condition[Literal(1)], BlockExpression[Continue]],
+        ],
+    ]]);
+    let increment = lowered_increment();
+
+    let expected = ast!(BlockExpression[BlockExpression[
+        Initialization[Variable[Identifier("i"), Type(Integer)], AssignedValue[Literal(0)]],
+        WhileLoop[
+            Condition[BinaryExpression[Identifier("i"), Operator("<"), Literal(10)]],
+            BlockExpression[
+                BlockExpression[
+                    IfStatement[Condition[Identifier("skip")], BlockExpression[BlockExpression[{ increment.clone() }, Continue]]],
+                    WhileLoop[Condition[Literal(1)], BlockExpression[Continue]],
+                ],
+                { increment },
+            ],
+        ],
+    ]]);
+    let lowered = desugar(tree);
+    assert_ast_eq(&expected, &lowered);
+    assert!(validate(&lowered).is_empty());
+}
+
+/// Tests that a for loop without clauses becomes `while (1)` without a surrounding block.
+#[test]
+fn test_empty_for_loop() {
+    let tree = ast!(ForLoop[LoopInitializer, Condition, LoopIncrement, BlockExpression[Break]]);
+    let expected = ast!(WhileLoop[Condition[Literal(1)], BlockExpression[BlockExpression[Break]]]);
+    assert_ast_eq(&expected, &desugar(tree));
+}
+
+/// Tests that a do while loop checks its negated condition at the end of the body and before each `continue`.
+#[test]
+fn test_do_while_loop() {
+    let tree = ast!(DoWhileLoop[BlockExpression[Continue], Condition[Identifier("more")]]);
+    let check = ast_node!(IfStatement[
+        Condition[UnaryExpression[Operator("!"), Identifier("more")]],
+        BlockExpression[Break],
+    ]);
+    let expected = ast!(WhileLoop[
+        Condition[Literal(1)],
+        BlockExpression[BlockExpression[BlockExpression[{ check.clone() }, Continue]], { check }],
+    ]);
+    let lowered = desugar(tree);
+    assert_ast_eq(&expected, &lowered);
+    assert!(validate(&lowered).is_empty());
+}
+
+/// Tests compound assignments, including values wrapped in `AssignedValue`.
+#[test]
+fn test_compound_assignment() {
+    let tree = ast!(BlockExpression[
+        Assignment[Identifier("x"), Operator("<<="), Literal(2)],
+        Assignment[MemberAccess[Identifier("p"), Identifier("y")], Operator("-="), AssignedValue[Identifier("z")]],
+    ]);
+    let expected = ast!(BlockExpression[
+        Assignment[Identifier("x"), BinaryExpression[Identifier("x"), Operator("<<"), Literal(2)]],
+        Assignment[
+            MemberAccess[Identifier("p"), Identifier("y")],
+            AssignedValue[BinaryExpression[MemberAccess[Identifier("p"), Identifier("y")], Operator("-"), Identifier("z")]],
+        ],
+    ]);
+    assert_ast_eq(&expected, &desugar(tree));
+}
+
+/// Tests that prefix increments are lowered anywhere, but postfix ones only where their value is unused.
+#[test]
+fn test_increments() {
+    let tree = ast!(BlockExpression[
+        UnaryExpression[Operator("--"), Identifier("a")],
+        Assignment[Identifier("b"), UnaryExpression[Identifier("c"), Operator("++")]],
+        Assignment[Identifier("d"), UnaryExpression[Operator("++"), Identifier("e")]],
+    ]);
+    let expected = ast!(BlockExpression[
+        Assignment[Identifier("a"), BinaryExpression[Identifier("a"), Operator("-"), Literal(1)]],
+        Assignment[Identifier("b"), UnaryExpression[Identifier("c"), Operator("++")]],
+        Assignment[Identifier("d"), Assignment[Identifier("e"), BinaryExpression[Identifier("e"), Operator("+"), Literal(1)]]],
+    ]);
+    assert_ast_eq(&expected, &desugar(tree));
+}
+
+/// Tests that `else if` chains become nested blocks.
+#[test]
+fn test_else_if() {
+    let tree = ast!(IfStatement[
+        Condition[Identifier("a")],
+        BlockExpression[Break],
+        ElseStatement[IfStatement[Condition[Identifier("b")], BlockExpression[Continue], ElseStatement[BlockExpression]]],
+    ]);
+    let expected = ast!(IfStatement[
+        Condition[Identifier("a")],
+        BlockExpression[Break],
+        ElseStatement[BlockExpression[IfStatement[Condition[Identifier("b")], BlockExpression[Continue], ElseStatement[BlockExpression]]]],
+    ]);
+    assert_ast_eq(&expected, &desugar(tree));
+}
+
+/// Tests that a pass restricted to some lowerings leaves the other constructs alone, and that a fully lowered
+/// tree contains none of the removed constructs.
+#[test]
+fn test_selected_lowerings() {
+    let tree = ast!(TopLevelExpression[FunctionDeclaration[
+        Identifier("f"),
+        Type(Void),
+        BlockExpression[
+            DoWhileLoop[BlockExpression[Assignment[Identifier("x"), Operator("*="), Literal(2)]], Condition[Identifier("x")]],
+            ForLoop[LoopInitializer, Condition[Identifier("x")], LoopIncrement[UnaryExpression[Identifier("x"), Operator("--")]], BlockExpression],
+        ],
+    ]]);
+
+    let partial = Desugarer::only(&[Lowering::DoWhileLoop]).fold_ast(tree.clone());
+    assert!(Query::parse("DoWhileLoop").unwrap().find_all(partial.get_root_ref()).is_empty());
+    assert_eq!(Query::parse("ForLoop").unwrap().find_all(partial.get_root_ref()).len(), 1);
+    assert_eq!(Query::parse("Assignment[has=Operator]").unwrap().find_all(partial.get_root_ref()).len(), 1);
+
+    let full = desugar(tree);
+    for kind in ["ForLoop", "DoWhileLoop", "Assignment[has=Operator]"] {
+        assert!(Query::parse(kind).unwrap().find_all(full.get_root_ref()).is_empty(), "{} remains", kind);
+    }
+    assert!(Query::parse("UnaryExpression Operator[value=--]").unwrap().find_all(full.get_root_ref()).is_empty());
+    assert!(validate(&full).is_empty());
+}
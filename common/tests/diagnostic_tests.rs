@@ -0,0 +1,90 @@
+//! This file contains tests for `Diagnostic` and the mapping of `ErrorType` onto it.
+
+use common::{
+    diagnostic::{Diagnostic, Label, Severity},
+    error::ErrorType,
+    source::{SourceManager, Span},
+};
+
+/// Tests that a diagnostic keeps its labels, notes and help in order, and formats them without source text.
+#[test]
+fn test_builder_and_display() {
+    let mut sources = SourceManager::new();
+    let file = sources.add_file("main.c", "int x;\nint x;\n".to_string());
+
+    let diagnostic = Diagnostic::error("redeclaration of `x`")
+        .with_code("E0010")
+        .with_span(Span::new(file, 11, 12), "redeclared here")
+        .with_label(Span::new(file, 4, 5), "first declared here")
+        .with_note("both declarations are in the same scope")
+        .with_help("rename one of the variables");
+
+    assert_eq!(diagnostic.get_severity(), Severity::Error);
+    assert!(diagnostic.is_error());
+    assert_eq!(diagnostic.get_code(), Some("E0010"));
+    assert_eq!(diagnostic.get_span_label(), Some("redeclared here"));
+    assert_eq!(diagnostic.get_labels(), &[Label::new(Span::new(file, 4, 5), "first declared here")]);
+    assert_eq!(diagnostic.to_string(), [
+        "error[E0010]: redeclaration of `x`",
+        "  --> file#0[11..12]: redeclared here",
+        "  ... file#0[4..5]: first declared here",
+        "  = note: both declarations are in the same scope",
+        "  = help: rename one of the variables",
+    ].join("\n"));
+}
+
+/// Tests warnings and notes without codes or locations.
+#[test]
+fn test_severities() {
+    assert_eq!(Diagnostic::warning("unused variable `y`").to_string(), "warning: unused variable `y`");
+    assert!(!Diagnostic::note("declared here").is_error());
+    assert!(Severity::Error < Severity::Warning && Severity::Warning < Severity::Note);
+}
+
+/// Tests that every `ErrorType` variant has a distinct code, a readable message and maps onto a diagnostic.
+#[test]
+fn test_error_type_mapping() {
+    let errors = vec![
+        ErrorType::TypeMismatch { left_type: "int".to_string(), right_type: "float".to_string() },
+        ErrorType::UndefinedVariable { variable_name: "y".to_string() },
+        ErrorType::UnsupportedOperator { operator: "%".to_string(), operand_type: "float".to_string() },
+        ErrorType::SyntaxError { message: "expected `;`".to_string() },
+        ErrorType::DivisionByZero { operation: "x / 0".to_string() },
+        ErrorType::InvalidAssignment { target: "5".to_string() },
+        ErrorType::UnrecognizedToken { token: "@".to_string() },
+        ErrorType::InvalidAST { path: "root/Break[0]".to_string(), message: "bad".to_string() },
+        ErrorType::Redeclaration { name: "x".to_string(), previous: "a variable of type `int`".to_string(), path: None },
+        ErrorType::DevError { message: "todo".to_string() },
+    ];
+
+    let mut codes: Vec<&str> = errors.iter().map(ErrorType::get_code).collect();
+    codes.sort();
+    codes.dedup();
+    assert_eq!(codes.len(), errors.len());
+
+    assert_eq!(errors[1].to_string(), "cannot find variable `y` in this scope");
+    assert_eq!(Diagnostic::from(errors[8].clone()).get_notes(), ["`x` was first declared in this scope as a variable of type `int`"]);
+    for error in errors {
+        let code = error.get_code().to_string();
+        let diagnostic = Diagnostic::from(error);
+        assert!(diagnostic.is_error());
+        assert_eq!(diagnostic.get_code(), Some(code.as_str()));
+    }
+}
+
+/// Tests that a located diagnostic survives a round trip through `ErrorType` and works as a `std::error::Error`.
+#[test]
+fn test_wrapped_diagnostic() {
+    let mut sources = SourceManager::new();
+    let file = sources.add_file("main.c", "x = 1;".to_string());
+    let diagnostic = Diagnostic::from(ErrorType::UndefinedVariable { variable_name: "x".to_string() })
+        .with_span(Span::new(file, 0, 1), "");
+
+    let error = ErrorType::from(diagnostic.clone());
+    assert_eq!(error.get_code(), "E0002");
+    assert_eq!(error.to_string(), "cannot find variable `x` in this scope");
+    assert_eq!(Diagnostic::from(error.clone()), diagnostic);
+
+    let boxed: Box<dyn std::error::Error> = Box::new(error);
+    assert_eq!(boxed.to_string(), "cannot find variable `x` in this scope");
+}
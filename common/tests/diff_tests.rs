@@ -0,0 +1,70 @@
+//! This file contains tests for the structural diff between `AST`s.
+
+use common::{
+    ast,
+    ast::{
+        diff::{assert_ast_eq, diff, AstEdit},
+        node_type::NodeType,
+    },
+};
+
+/// Tests that equal trees have no edits and pass the assertion helper.
+#[test]
+fn test_equal_trees() {
+    let tree = ast!(TopLevelExpression[Assignment[Identifier("x"), Literal(1)]]);
+    assert!(diff(&tree, &tree.clone()).is_empty());
+    assert_ast_eq(&tree, &tree.clone());
+}
+
+/// Tests that a changed payload is reported as a single change at its path.
+#[test]
+fn test_changed_payload() {
+    let old = ast!(TopLevelExpression[Assignment[Identifier("x"), Literal(1)]]);
+    let new = ast!(TopLevelExpression[Assignment[Identifier("x"), Literal(2)]]);
+
+    let edits = diff(&old, &new);
+    assert_eq!(edits.len(), 1);
+    assert!(matches!(&edits[0], AstEdit::Changed { old, new, .. }
+        if old == &NodeType::Literal("1".to_string()) && new == &NodeType::Literal("2".to_string())));
+    assert_eq!(edits[0].to_string(), "~ root/TopLevelExpression[0]/Assignment[0]/Literal[1]: Literal(1) -> Literal(2)");
+}
+
+/// Tests that an inserted statement in a function body is reported alone, without disturbing its siblings.
+#[test]
+fn test_inserted_and_removed() {
+    let old = ast!(TopLevelExpression[FunctionDeclaration[
+        Identifier("f"),
+        Type(Void),
+        BlockExpression[Break, Return],
+    ]]);
+    let new = ast!(TopLevelExpression[FunctionDeclaration[
+        Identifier("f"),
+        Type(Void),
+        BlockExpression[Break, Continue, Return],
+    ]]);
+
+    let edits = diff(&old, &new);
+    let lines: Vec<String> = edits.iter().map(|edit| edit.to_string()).collect();
+    assert_eq!(lines, vec!["+ root/TopLevelExpression[0]/FunctionDeclaration[0]/BlockExpression[2]/Continue[1]: Continue"]);
+
+    let reverse: Vec<String> = diff(&new, &old).iter().map(|edit| edit.to_string()).collect();
+    assert_eq!(reverse, vec!["- root/TopLevelExpression[0]/FunctionDeclaration[0]/BlockExpression[2]/Continue[1]: Continue"]);
+}
+
+/// Tests that a node replaced by one of another kind is a single change, without edits for its children.
+#[test]
+fn test_replaced_kind() {
+    let old = ast!(BlockExpression[WhileLoop[Condition[Identifier("a")]]]);
+    let new = ast!(BlockExpression[DoWhileLoop[BlockExpression[], Condition[Identifier("a")]]]);
+
+    let edits = diff(&old, &new);
+    assert_eq!(edits.len(), 1);
+    assert_eq!(edits[0].to_string(), "~ root/BlockExpression[0]/DoWhileLoop[0]: WhileLoop -> DoWhileLoop");
+}
+
+/// Tests that the assertion helper panics with the diff.
+#[test]
+#[should_panic(expected = "~ root/TopLevelExpression[0]/Identifier[0]: Identifier(a) -> Identifier(b)")]
+fn test_assert_ast_eq_panics_with_diff() {
+    assert_ast_eq(&ast!(TopLevelExpression[Identifier("a")]), &ast!(TopLevelExpression[Identifier("b")]));
+}
@@ -0,0 +1,56 @@
+//! This file contains tests for rendering an `AST` as a Graphviz DOT graph.
+
+use common::{
+    ast, ast_node,
+    ast::{
+        dot::{to_dot, DotOptions, NodeCategory},
+        node_type::NodeType,
+    },
+};
+
+/// Tests that nodes are numbered in pre-order, labelled with their payloads and linked to their children.
+#[test]
+fn test_ast_to_dot() {
+    let ast = ast!(TopLevelExpression[Return[AssignedValue[BinaryExpression[Identifier("a"), Operator("+"), Literal("\"b\"")]]]]);
+    let expected = "digraph ast {\n    node [shape=box, fontname=\"monospace\"];\n    n0 [label=\"TopLevelExpression\"];\n    n1 [label=\"Return\"];\n    n2 [label=\"AssignedValue\"];\n    n3 [label=\"BinaryExpression\"];\n    n4 [label=\"Identifier(a)\"];\n    n3 -> n4;\n    n5 [label=\"Operator(+)\"];\n    n3 -> n5;\n    n6 [label=\"Literal(\\\"b\\\")\"];\n    n3 -> n6;\n    n2 -> n3;\n    n1 -> n2;\n    n0 -> n1;\n}\n";
+    assert_eq!(ast.to_dot(&DotOptions::default()), expected);
+}
+
+/// Tests that coloured output fills nodes according to their category.
+#[test]
+fn test_colored_dot() {
+    let ast = ast!(TopLevelExpression[Return[AssignedValue[BinaryExpression[Identifier("a"), Operator("+"), Literal("\"b\"")]]]]);
+    let dot = ast.to_dot(&DotOptions::new("example graph", true));
+    assert!(dot.starts_with("digraph \"example graph\" {\n"));
+    assert!(dot.contains("n1 [label=\"Return\", style=filled, fillcolor=lightsalmon];"));
+    assert!(dot.contains("n3 [label=\"BinaryExpression\", style=filled, fillcolor=palegreen];"));
+    assert!(dot.contains("n4 [label=\"Identifier(a)\", style=filled, fillcolor=lightyellow];"));
+}
+
+/// Tests that graph names are quoted unless they are plain identifiers, and that keywords are always quoted.
+#[test]
+fn test_graph_name_quoting() {
+    let node = ast_node!(Break);
+    for (name, header) in [("ast", "digraph ast {"), ("2d", "digraph \"2d\" {"), ("Graph", "digraph \"Graph\" {"), ("STRICT", "digraph \"STRICT\" {"), ("nodes", "digraph nodes {")] {
+        assert!(to_dot(&node, &DotOptions::new(name, false)).starts_with(header), "{}", name);
+    }
+}
+
+/// Tests rendering a subtree on its own.
+#[test]
+fn test_subtree_to_dot() {
+    let node = ast_node!(Type(Integer));
+    let dot = to_dot(&node, &DotOptions::default());
+    assert!(dot.contains("n0 [label=\"Type(Integer)\"];"));
+    assert!(!dot.contains("->"));
+}
+
+/// Tests classifying node types into categories.
+#[test]
+fn test_node_categories() {
+    assert_eq!(NodeCategory::of(&NodeType::FunctionDeclaration), NodeCategory::Declaration);
+    assert_eq!(NodeCategory::of(&NodeType::WhileLoop), NodeCategory::ControlFlow);
+    assert_eq!(NodeCategory::of(&NodeType::FunctionCall), NodeCategory::Expression);
+    assert_eq!(NodeCategory::of(&NodeType::Constant("1".to_string())), NodeCategory::Leaf);
+    assert_eq!(NodeCategory::of(&NodeType::Condition), NodeCategory::Structural);
+}
@@ -0,0 +1,140 @@
+//! This file contains tests for emitting diagnostics as JSON lines and SARIF documents.
+
+use common::{
+    diagnostic::Diagnostic,
+    emit::{to_json_line, to_json_lines, to_sarif, ErrorFormat},
+    fix::Fix,
+    source::{SourceManager, Span},
+};
+use serde_json::{json, Value};
+
+/// Builds a source manager and diagnostics covering locations, labels, notes, help and a missing location.
+fn fixture() -> (SourceManager, Vec<Diagnostic>) {
+    let mut sources = SourceManager::new();
+    let file = sources.add_file("src/main.c", "int x;\nint x = \"é\\n\";\n".to_string());
+    let diagnostics = vec![
+        Diagnostic::error("redeclaration of `x`")
+            .with_code("E0010")
+            .with_span(Span::new(file, 11, 12), "redeclared here")
+            .with_label(Span::new(file, 4, 5), "first declared here")
+            .with_note("a \"quoted\" note")
+            .with_help("rename it"),
+        Diagnostic::warning("unused variable").with_span(Span::new(file, 15, 21), ""),
+        Diagnostic::error("couldn't read `b.c`"),
+    ];
+    (sources, diagnostics)
+}
+
+/// Tests the fields of JSON lines, including escaping, code point columns and diagnostics without a location.
+#[test]
+fn test_json_lines() {
+    let (sources, diagnostics) = fixture();
+    let output = to_json_lines(&diagnostics, &sources);
+    let lines: Vec<Value> = output.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+    assert_eq!(lines.len(), 3);
+
+    assert_eq!(lines[0], json!({
+        "severity": "error",
+        "code": "E0010",
+        "message": "redeclaration of `x`",
+        "file": "src/main.c",
+        "range": {"start": {"line": 2, "column": 5, "offset": 11}, "end": {"line": 2, "column": 6, "offset": 12}},
+        "label": "redeclared here",
+        "labels": [{
+            "message": "first declared here",
+            "file": "src/main.c",
+            "range": {"start": {"line": 1, "column": 5, "offset": 4}, "end": {"line": 1, "column": 6, "offset": 5}},
+        }],
+        "notes": ["a \"quoted\" note"],
+        "help": ["rename it"],
+        "fixes": [],
+    }));
+    assert_eq!(lines[1]["range"]["end"]["column"], json!(14));
+    assert_eq!(lines[1]["code"], Value::Null);
+    assert_eq!(lines[2]["file"], Value::Null);
+    assert_eq!(lines[2]["range"], Value::Null);
+    assert!(!to_json_line(&diagnostics[0], &sources).contains('\n'));
+}
+
+/// Tests the structure of a SARIF document.
+#[test]
+fn test_sarif() {
+    let (sources, diagnostics) = fixture();
+    let sarif: Value = serde_json::from_str(&to_sarif(&diagnostics, &sources, "0.1.0")).unwrap();
+
+    assert_eq!(sarif["version"], json!("2.1.0"));
+    let run = &sarif["runs"][0];
+    assert_eq!(run["tool"]["driver"]["name"], json!("sicc"));
+    assert_eq!(run["tool"]["driver"]["rules"], json!([{"id": "E0010"}]));
+    assert_eq!(run["artifacts"][0]["location"]["uri"], json!("src/main.c"));
+
+    let results = run["results"].as_array().unwrap();
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0]["ruleId"], json!("E0010"));
+    assert_eq!(results[0]["level"], json!("error"));
+    assert_eq!(results[0]["message"]["text"], json!("redeclaration of `x`"));
+    assert_eq!(results[0]["locations"][0]["physicalLocation"]["region"],
+        json!({"startLine": 2, "startColumn": 5, "endLine": 2, "endColumn": 6}));
+    assert_eq!(results[0]["relatedLocations"][0]["message"]["text"], json!("first declared here"));
+    assert_eq!(results[0]["properties"]["help"], json!(["rename it"]));
+
+    assert_eq!(results[1]["level"], json!("warning"));
+    assert!(results[1].get("ruleId").is_none());
+    assert_eq!(results[2]["locations"], json!([]));
+}
+
+/// Tests that file names are percent-encoded in SARIF URIs, but kept as they are in JSON lines.
+#[test]
+fn test_sarif_uris() {
+    let mut sources = SourceManager::new();
+    let file = sources.add_file("my project/a#1 é.c", "int x;\n".to_string());
+    let diagnostic = Diagnostic::error("unused").with_span(Span::new(file, 4, 5), "").with_fix(Fix::insert_semicolon(Span::new(file, 4, 5)));
+    let sarif: Value = serde_json::from_str(&to_sarif(std::slice::from_ref(&diagnostic), &sources, "0.1.0")).unwrap();
+
+    let uri = json!("my%20project/a%231%20%C3%A9.c");
+    let run = &sarif["runs"][0];
+    assert_eq!(run["artifacts"][0]["location"]["uri"], uri);
+    assert_eq!(run["results"][0]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"], uri);
+    assert_eq!(run["results"][0]["fixes"][0]["artifactChanges"][0]["artifactLocation"]["uri"], uri);
+
+    let line: Value = serde_json::from_str(&to_json_line(&diagnostic, &sources)).unwrap();
+    assert_eq!(line["file"], json!("my project/a#1 é.c"));
+}
+
+/// Tests that fix-its are emitted with their edits in JSON lines and as fixes in SARIF.
+#[test]
+fn test_fixes() {
+    let mut sources = SourceManager::new();
+    let file = sources.add_file("main.c", "int x = 1
+".to_string());
+    let diagnostic = Diagnostic::error("expected `;`").with_fix(Fix::insert_semicolon(Span::new(file, 8, 9)));
+
+    let line: Value = serde_json::from_str(&to_json_line(&diagnostic, &sources)).unwrap();
+    assert_eq!(line["fixes"], json!([{
+        "message": "insert the missing `;`",
+        "applicability": "machine-applicable",
+        "edits": [{
+            "file": "main.c",
+            "range": {"start": {"line": 1, "column": 10, "offset": 9}, "end": {"line": 1, "column": 10, "offset": 9}},
+            "replacement": ";",
+        }],
+    }]));
+
+    let sarif: Value = serde_json::from_str(&to_sarif(&[diagnostic], &sources, "0.1.0")).unwrap();
+    let fix = &sarif["runs"][0]["results"][0]["fixes"][0];
+    assert_eq!(fix["description"]["text"], json!("insert the missing `;`"));
+    assert_eq!(fix["artifactChanges"][0]["artifactLocation"]["uri"], json!("main.c"));
+    assert_eq!(fix["artifactChanges"][0]["replacements"][0], json!({
+        "deletedRegion": {"startLine": 1, "startColumn": 10, "endLine": 1, "endColumn": 10},
+        "insertedContent": {"text": ";"},
+    }));
+}
+
+/// Tests parsing of the `--error-format` values.
+#[test]
+fn test_error_format() {
+    assert_eq!("json".parse::<ErrorFormat>().unwrap(), ErrorFormat::Json);
+    assert_eq!("sarif".parse::<ErrorFormat>().unwrap(), ErrorFormat::Sarif);
+    assert_eq!("human".parse::<ErrorFormat>().unwrap(), ErrorFormat::default());
+    assert!("xml".parse::<ErrorFormat>().is_err());
+}
@@ -0,0 +1,61 @@
+//! This file contains tests for the long-form explanations of error codes.
+
+use common::{
+    diagnostic::Diagnostic,
+    error::ErrorType,
+    explain::{explain, get_codes, normalize_code},
+};
+
+/// Tests that every kind of error has a code with an explanation.
+#[test]
+fn test_every_error_is_explained() {
+    let errors = vec![
+        ErrorType::DevError { message: String::new() },
+        ErrorType::TypeMismatch { left_type: String::new(), right_type: String::new() },
+        ErrorType::UndefinedVariable { variable_name: String::new() },
+        ErrorType::UnsupportedOperator { operator: String::new(), operand_type: String::new() },
+        ErrorType::SyntaxError { message: String::new() },
+        ErrorType::DivisionByZero { operation: String::new() },
+        ErrorType::InvalidAssignment { target: String::new() },
+        ErrorType::UnrecognizedToken { token: String::new() },
+        ErrorType::InvalidAST { path: String::new(), message: String::new() },
+        ErrorType::Redeclaration { name: String::new(), previous: String::new(), path: None },
+        ErrorType::from(Diagnostic::error("")),
+    ];
+    let mut codes: Vec<&str> = errors.iter().map(ErrorType::get_code).collect();
+    codes.sort_unstable();
+    codes.dedup();
+
+    assert_eq!(codes, get_codes());
+    for code in codes {
+        assert!(explain(code).is_some(), "no explanation for {}", code);
+    }
+}
+
+/// Tests that every explanation shows a failing example and its fix. E0000 is the exception: it reports a bug in
+/// the compiler rather than a mistake in the program, so there is no erroneous program to show.
+#[test]
+fn test_explanations_have_examples() {
+    for code in get_codes() {
+        let explanation = explain(code).unwrap();
+        assert!(!explanation.trim().is_empty());
+        let examples = explanation.matches("```").count() / 2;
+        if code == "E0000" {
+            assert_eq!(examples, 0, "E0000 shows an example, but no program causes it");
+        } else {
+            assert!(examples >= 2, "{} does not show an erroneous example and its fix", code);
+        }
+    }
+    assert!(explain("E0001").unwrap().contains("Erroneous code example:"));
+}
+
+/// Tests the forms in which a code is accepted.
+#[test]
+fn test_code_forms() {
+    assert_eq!(normalize_code(" 0001 "), Some("E0001".to_string()));
+    assert_eq!(normalize_code("e06"), Some("E0006".to_string()));
+    assert_eq!(normalize_code("E"), None);
+    assert_eq!(normalize_code("E00x1"), None);
+    assert_eq!(explain("6"), explain("E0006"));
+    assert_eq!(explain("E0100"), None);
+}
@@ -0,0 +1,111 @@
+//! This file contains tests for fix-its, applying them to source files and printing them as unified diffs.
+
+use common::{
+    diagnostic::Diagnostic,
+    fix::{apply_edits, apply_fixes, collect_edits, unified_diff, Applicability, Edit, Fix, FixId},
+    source::{SourceManager, Span},
+};
+
+/// Tests applying edits in span order, keeping the order of insertions at one offset, and rejecting overlaps.
+#[test]
+fn test_apply_edits() {
+    let mut sources = SourceManager::new();
+    let file = sources.add_file("main.c", "int cuont = 1\n".to_string());
+    let text = sources.get_file(file).unwrap().get_text();
+
+    let edits = vec![
+        Edit::insert(file, 13, ";"),
+        Edit::new(Span::new(file, 4, 9), "count"),
+        Edit::insert(file, 13, " "),
+    ];
+    assert_eq!(apply_edits(text, &edits).unwrap(), "int count = 1; \n");
+    assert_eq!(apply_edits(text, &[Edit::new(Span::new(file, 0, 4), "")]).unwrap(), "cuont = 1\n");
+
+    let overlapping = vec![Edit::new(Span::new(file, 4, 9), "count"), Edit::new(Span::new(file, 6, 11), "")];
+    assert!(apply_edits(text, &overlapping).is_err());
+    assert!(apply_edits(text, &[Edit::insert(file, 40, ";")]).is_err());
+}
+
+/// Tests the constructors for a missing `;`, a missing `return` and a misspelled name.
+#[test]
+fn test_fix_constructors() {
+    let mut sources = SourceManager::new();
+    let text = "int main() { int x = 1 }\n";
+    let file = sources.add_file("main.c", text.to_string());
+
+    let semicolon = Fix::insert_semicolon(Span::new(file, 21, 22));
+    let inline_return = Fix::insert_return(&sources, Span::new(file, 23, 24), "x");
+    let rename = Fix::rename(Span::new(file, 17, 18), "y");
+    assert_eq!(semicolon.get_message(), "insert the missing `;`");
+    assert_eq!(inline_return.get_message(), "add `return x;`");
+    assert!(!rename.is_machine_applicable());
+
+    let diagnostics = vec![
+        Diagnostic::error("expected `;`").with_fix(semicolon),
+        Diagnostic::warning("missing return").with_fix(inline_return),
+        Diagnostic::error("misspelled").with_fix(rename),
+    ];
+    assert!(diagnostics[0].to_string().ends_with("\n  = help: insert the missing `;`"));
+
+    let (fixed, applied) = apply_fixes(&diagnostics, &sources).unwrap();
+    assert_eq!(fixed, vec![(file, "int main() { int x = 1; return x; }\n".to_string())]);
+    assert_eq!(applied, vec![FixId::new(0, 0), FixId::new(1, 0)]);
+}
+
+/// Tests that fixes needing review, conflicting with earlier fixes, or editing macro expansions are skipped, and
+/// are left out of the applied fixes.
+#[test]
+fn test_collect_edits_skips_fixes() {
+    let mut sources = SourceManager::new();
+    let main = sources.add_file("main.c", "int x = SQUARE(y);\n".to_string());
+    let expansion = sources.add_macro_expansion("SQUARE", "((y) * (y))".to_string(), Span::new(main, 8, 17));
+
+    let diagnostics = vec![
+        Diagnostic::error("first").with_fix(Fix::new("rename", vec![Edit::new(Span::new(main, 4, 5), "z")], Applicability::MachineApplicable)),
+        Diagnostic::error("conflicting").with_fix(Fix::new("both", vec![
+            Edit::insert(main, 0, "static "),
+            Edit::new(Span::new(main, 4, 5), "w"),
+        ], Applicability::MachineApplicable)),
+        Diagnostic::error("review").with_fix(Fix::new("maybe", vec![Edit::insert(main, 0, "const ")], Applicability::MaybeIncorrect)),
+        Diagnostic::error("in a macro").with_fix(Fix::new("rename", vec![Edit::new(Span::new(expansion, 2, 3), "v")], Applicability::MachineApplicable)),
+    ];
+
+    let edits = collect_edits(&diagnostics, &sources);
+    assert_eq!(edits.len(), 1);
+    assert_eq!(edits[&main], vec![Edit::new(Span::new(main, 4, 5), "z")]);
+    assert_eq!(apply_fixes(&diagnostics, &sources).unwrap().1, vec![FixId::new(0, 0)]);
+}
+
+/// Tests hunks, merged and separate, added and removed lines, and a missing final newline.
+#[test]
+fn test_unified_diff() {
+    let old: String = (1..=20).map(|line| format!("line {}\n", line)).collect();
+    let new = old.replace("line 2\n", "line two\n").replace("line 5\n", "").replace("line 18\n", "line 18\nline 18b\n");
+    assert_eq!(unified_diff("a.c", &old, &new), [
+        "--- a/a.c",
+        "+++ b/a.c",
+        "@@ -1,8 +1,7 @@",
+        " line 1",
+        "-line 2",
+        "+line two",
+        " line 3",
+        " line 4",
+        "-line 5",
+        " line 6",
+        " line 7",
+        " line 8",
+        "@@ -16,5 +15,6 @@",
+        " line 16",
+        " line 17",
+        " line 18",
+        "+line 18b",
+        " line 19",
+        " line 20",
+        "",
+    ].join("\n"));
+
+    assert_eq!(unified_diff("b.c", "int x", "int x;"),
+        "--- a/b.c\n+++ b/b.c\n@@ -1 +1 @@\n-int x\n\\ No newline at end of file\n+int x;\n\\ No newline at end of file\n");
+    assert_eq!(unified_diff("c.c", "", "int x;\n"), "--- a/c.c\n+++ b/c.c\n@@ -0,0 +1 @@\n+int x;\n");
+    assert_eq!(unified_diff("d.c", "same\n", "same\n"), "");
+}
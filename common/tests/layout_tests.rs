@@ -0,0 +1,164 @@
+//! This file contains tests for computing the memory layouts of data types.
+
+use common::{
+    ast::data_type::DataType,
+    layout::{align_to, size_of, struct_layout, union_layout},
+};
+
+/// Tests that scalar sizes match C on common 64-bit targets.
+#[test]
+fn test_scalar_sizes() {
+    assert_eq!(size_of(&DataType::Integer), Some(4));
+    assert_eq!(size_of(&DataType::Unsign), Some(4));
+    assert_eq!(size_of(&DataType::Enum), Some(4));
+    assert_eq!(size_of(&DataType::Long), Some(8));
+    assert_eq!(size_of(&DataType::Double), Some(8));
+    assert_eq!(size_of(&DataType::Float), Some(4));
+    assert_eq!(size_of(&DataType::Char), Some(1));
+    assert_eq!(size_of(&DataType::Void), None);
+    assert_eq!(size_of(&DataType::Union), None);
+}
+
+/// Tests rounding offsets up to an alignment.
+#[test]
+fn test_align_to() {
+    assert_eq!(align_to(0, 8), 0);
+    assert_eq!(align_to(1, 8), 8);
+    assert_eq!(align_to(9, 4), 12);
+    assert_eq!(align_to(16, 8), 16);
+}
+
+/// Tests that a union is sized and aligned to its largest member, with every member at offset zero.
+#[test]
+fn test_union_layout() {
+    let fields = vec![
+        ("c".to_string(), DataType::Char),
+        ("f".to_string(), DataType::Float),
+        ("d".to_string(), DataType::Double),
+    ];
+
+    let layout = union_layout(&fields).expect("Failed to compute union layout");
+
+    assert_eq!(layout.get_size(), 8);
+    assert_eq!(layout.get_align(), 8);
+    assert!(layout.get_fields().iter().all(|field| field.get_offset() == 0));
+    assert_eq!(layout.get_most_aligned_field().map(|field| field.get_name()), Some("d"));
+
+    let registers = union_layout(&[("word".to_string(), DataType::Unsign), ("byte".to_string(), DataType::Char)]).unwrap();
+    assert_eq!((registers.get_size(), registers.get_align()), (4, 4));
+}
+
+/// Tests that an empty union has no size and byte alignment.
+#[test]
+fn test_empty_union_layout() {
+    let layout = union_layout(&[]).expect("Failed to compute union layout");
+
+    assert_eq!(layout.get_size(), 0);
+    assert_eq!(layout.get_align(), 1);
+    assert!(layout.get_most_aligned_field().is_none());
+}
+
+/// Tests that a union containing a type without a size is rejected.
+#[test]
+fn test_union_layout_with_void_field() {
+    let fields = vec![("v".to_string(), DataType::Void)];
+
+    assert!(union_layout(&fields).is_err());
+}
+
+/// Tests that ordinary struct fields are placed at their natural alignment.
+#[test]
+fn test_struct_layout() {
+    let fields = vec![
+        ("c".to_string(), DataType::Char),
+        ("i".to_string(), DataType::Integer),
+        ("f".to_string(), DataType::Float),
+    ];
+
+    let layout = struct_layout(&fields, &[], None).expect("Failed to compute struct layout");
+    let offsets: Vec<usize> = layout.get_fields().iter().map(|field| field.get_offset()).collect();
+
+    assert_eq!(offsets, vec![0, 4, 8]);
+    assert_eq!(layout.get_size(), 12);
+    assert_eq!(layout.get_align(), 4);
+}
+
+/// Tests that adjacent bit-fields share a storage unit and that the following field starts after them.
+#[test]
+fn test_struct_layout_with_bit_fields() {
+    let fields = vec![
+        ("flags".to_string(), DataType::Unsign),
+        ("mode".to_string(), DataType::Unsign),
+        ("c".to_string(), DataType::Char),
+    ];
+    let bit_widths = vec![("flags".to_string(), 3), ("mode".to_string(), 2)];
+
+    let layout = struct_layout(&fields, &bit_widths, None).expect("Failed to compute struct layout");
+    let flags = &layout.get_fields()[0];
+    let mode = &layout.get_fields()[1];
+    let c = &layout.get_fields()[2];
+
+    assert_eq!((flags.get_offset(), flags.get_bit_offset(), flags.get_bit_mask()), (0, 0, Some(0b111)));
+    assert_eq!((mode.get_offset(), mode.get_bit_offset(), mode.get_bit_mask()), (0, 3, Some(0b11)));
+    assert_eq!((c.get_offset(), c.get_bit_width()), (1, None));
+    assert_eq!((layout.get_size(), layout.get_align()), (4, 4));
+}
+
+/// Tests that a bit-field which does not fit in the rest of its storage unit starts a new one.
+#[test]
+fn test_struct_layout_bit_field_straddling_unit() {
+    let fields = vec![
+        ("a".to_string(), DataType::Char),
+        ("b".to_string(), DataType::Char),
+    ];
+    let bit_widths = vec![("a".to_string(), 5), ("b".to_string(), 5)];
+
+    let layout = struct_layout(&fields, &bit_widths, None).expect("Failed to compute struct layout");
+    let b = &layout.get_fields()[1];
+
+    assert_eq!((b.get_offset(), b.get_bit_offset()), (1, 0));
+    assert_eq!(layout.get_size(), 2);
+}
+
+/// Tests that a zero width bit-field closes the current storage unit.
+#[test]
+fn test_struct_layout_zero_width_bit_field() {
+    let fields = vec![
+        ("a".to_string(), DataType::Char),
+        ("pad".to_string(), DataType::Char),
+        ("b".to_string(), DataType::Char),
+    ];
+    let bit_widths = vec![("a".to_string(), 2), ("pad".to_string(), 0), ("b".to_string(), 2)];
+
+    let layout = struct_layout(&fields, &bit_widths, None).expect("Failed to compute struct layout");
+
+    assert_eq!(layout.get_fields().len(), 2);
+    assert_eq!(layout.get_fields()[1].get_offset(), 1);
+}
+
+/// Tests that a flexible array member is placed at the end without counting towards the size.
+#[test]
+fn test_struct_layout_with_flexible_array() {
+    let fields = vec![
+        ("length".to_string(), DataType::Integer),
+        ("tag".to_string(), DataType::Char),
+    ];
+    let flexible_array = ("data".to_string(), DataType::Char);
+
+    let layout = struct_layout(&fields, &[], Some(&flexible_array)).expect("Failed to compute struct layout");
+    let data = layout.get_fields().last().expect("Missing flexible array member");
+
+    assert!(data.is_flexible_array());
+    assert_eq!(data.get_offset(), 5);
+    assert_eq!(layout.get_size(), 8);
+}
+
+/// Tests that bit-fields wider than their type or of a non-integer type are rejected.
+#[test]
+fn test_struct_layout_invalid_bit_fields() {
+    let too_wide = vec![("c".to_string(), DataType::Char)];
+    assert!(struct_layout(&too_wide, &[("c".to_string(), 9)], None).is_err());
+
+    let not_integer = vec![("d".to_string(), DataType::Double)];
+    assert!(struct_layout(&not_integer, &[("d".to_string(), 3)], None).is_err());
+}
@@ -0,0 +1,67 @@
+//! This file contains tests for the `ast!` and `ast_node!` builder macros.
+
+use common::{
+    ast,
+    ast::{
+        core::{ASTNode, AST},
+        data_type::DataType,
+        node_type::NodeType,
+    },
+    ast_node,
+};
+
+/// Tests that the macro builds the same tree as the equivalent `ASTNode::new` chain.
+#[test]
+fn test_function_declaration() {
+    let built = ast!(FunctionDeclaration[
+        Identifier("add"),
+        Parameter[Identifier("a"), Type(Integer)],
+        Parameter[Identifier("b"), Type(Integer)],
+        Type(Integer),
+        BlockExpression[
+            Return[AssignedValue[BinaryExpression[Identifier("a"), Operator("+"), Identifier("b")]]],
+        ],
+    ]);
+
+    let mut parameter_a = ASTNode::new(NodeType::Parameter);
+    parameter_a.add_children(vec![ASTNode::new(NodeType::Identifier("a".to_string())), ASTNode::new(NodeType::Type(DataType::Integer))]);
+    let mut parameter_b = ASTNode::new(NodeType::Parameter);
+    parameter_b.add_children(vec![ASTNode::new(NodeType::Identifier("b".to_string())), ASTNode::new(NodeType::Type(DataType::Integer))]);
+
+    let mut sum = ASTNode::new(NodeType::BinaryExpression);
+    sum.add_children(vec![
+        ASTNode::new(NodeType::Identifier("a".to_string())),
+        ASTNode::new(NodeType::Operator("+".to_string())),
+        ASTNode::new(NodeType::Identifier("b".to_string())),
+    ]);
+    let mut value = ASTNode::new(NodeType::AssignedValue);
+    value.add_child(sum);
+    let mut ret = ASTNode::new(NodeType::Return);
+    ret.add_child(value);
+    let mut block = ASTNode::new(NodeType::BlockExpression);
+    block.add_child(ret);
+
+    let mut function = ASTNode::new(NodeType::FunctionDeclaration);
+    function.add_children(vec![
+        ASTNode::new(NodeType::Identifier("add".to_string())),
+        parameter_a,
+        parameter_b,
+        ASTNode::new(NodeType::Type(DataType::Integer)),
+        block,
+    ]);
+
+    assert_eq!(built, AST::new(function));
+}
+
+/// Tests leaves, numeric payloads, empty child lists and splicing existing nodes.
+#[test]
+fn test_leaves_and_splicing() {
+    assert_eq!(ast_node!(Break), ASTNode::new(NodeType::Break));
+    assert_eq!(ast_node!(Literal(42)), ASTNode::new(NodeType::Literal("42".to_string())));
+    assert_eq!(ast_node!(BlockExpression[]), ASTNode::new(NodeType::BlockExpression));
+
+    let condition = ast_node!(Condition[Identifier("done")]);
+    let spliced = ast_node!(WhileLoop[{ condition.clone() }, BlockExpression[Break]]);
+    assert_eq!(spliced.get_children_ref()[0], condition);
+    assert_eq!(spliced.get_children_ref()[1].get_children_ref()[0], ASTNode::new(NodeType::Break));
+}
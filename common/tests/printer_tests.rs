@@ -0,0 +1,115 @@
+//! This file contains tests for printing an AST back into C source code.
+
+use common::{
+    ast, ast_node,
+    ast::printer::{print_expression, print_node},
+};
+
+/// Tests that parentheses are only emitted where precedence requires them.
+#[test]
+fn test_minimal_parentheses() {
+    let grouped = ast_node!(BinaryExpression[
+        BinaryExpression[Identifier("a"), Operator("+"), Identifier("b")], Operator("*"), Identifier("c"),
+    ]);
+    assert_eq!(print_expression(&grouped).unwrap(), "(a + b) * c");
+
+    let ungrouped = ast_node!(BinaryExpression[
+        Identifier("a"), Operator("+"), BinaryExpression[Identifier("b"), Operator("*"), Identifier("c")],
+    ]);
+    assert_eq!(print_expression(&ungrouped).unwrap(), "a + b * c");
+
+    let left_assoc = ast_node!(BinaryExpression[
+        BinaryExpression[Identifier("a"), Operator("-"), Identifier("b")], Operator("-"), Identifier("c"),
+    ]);
+    assert_eq!(print_expression(&left_assoc).unwrap(), "a - b - c");
+
+    let right_grouped = ast_node!(BinaryExpression[
+        Identifier("a"), Operator("-"), BinaryExpression[Identifier("b"), Operator("-"), Identifier("c")],
+    ]);
+    assert_eq!(print_expression(&right_grouped).unwrap(), "a - (b - c)");
+}
+
+/// Tests prefix and postfix unary expressions, and separating nested negations.
+#[test]
+fn test_unary_expressions() {
+    let negated = ast_node!(UnaryExpression[Operator("-"), BinaryExpression[Identifier("a"), Operator("+"), Literal(1)]]);
+    assert_eq!(print_expression(&negated).unwrap(), "-(a + 1)");
+
+    let double_negated = ast_node!(UnaryExpression[Operator("-"), UnaryExpression[Operator("-"), Identifier("x")]]);
+    assert_eq!(print_expression(&double_negated).unwrap(), "- -x");
+
+    let postfix = ast_node!(UnaryExpression[Identifier("i"), Operator("++")]);
+    assert_eq!(print_expression(&postfix).unwrap(), "i++");
+}
+
+/// Tests printing a function with an initialization, an if-else chain and a return.
+#[test]
+fn test_function_declaration() {
+    let ast = ast!(TopLevelExpression[
+        FunctionDeclaration[
+            Identifier("max"),
+            Parameter[Identifier("a"), Type(Integer)],
+            Parameter[Identifier("b"), Type(Integer)],
+            Type(Integer),
+            BlockExpression[
+                IfStatement[
+                    Condition[BinaryExpression[Identifier("a"), Operator(">"), Identifier("b")]],
+                    BlockExpression[Return[AssignedValue[Identifier("a")]]],
+                    ElseStatement[
+                        IfStatement[
+                            Condition[BinaryExpression[Identifier("a"), Operator("=="), Identifier("b")]],
+                            BlockExpression[Return[AssignedValue[Literal(0)]]],
+                        ],
+                    ],
+                ],
+                Return[AssignedValue[Identifier("b")]],
+            ],
+        ],
+    ]);
+
+    let expected = "int max(int a, int b) {\n    if (a > b) {\n        return a;\n    } else if (a == b) {\n        return 0;\n    }\n    return b;\n}\n";
+    assert_eq!(ast.to_c_source().unwrap(), expected);
+}
+
+/// Tests printing loops, initializations and compound assignments.
+#[test]
+fn test_loops() {
+    let for_loop = ast_node!(ForLoop[
+        LoopInitializer[Initialization[Variable[Identifier("i"), Type(Integer)], AssignedValue[Literal(0)]]],
+        Condition[BinaryExpression[Identifier("i"), Operator("<"), Literal(10)]],
+        LoopIncrement[UnaryExpression[Identifier("i"), Operator("++")]],
+        BlockExpression[Assignment[Identifier("sum"), Operator("+="), Identifier("i")]],
+    ]);
+    let expected = "for (int i = 0; i < 10; i++) {\n    sum += i;\n}\n";
+    assert_eq!(print_node(&for_loop).unwrap(), expected);
+
+    let do_while = ast_node!(DoWhileLoop[
+        BlockExpression[Assignment[Identifier("x"), BinaryExpression[Identifier("x"), Operator("-"), Literal(1)]]],
+        Condition[BinaryExpression[Identifier("x"), Operator(">"), Literal(0)]],
+    ]);
+    assert_eq!(print_node(&do_while).unwrap(), "do {\n    x = x - 1;\n} while (x > 0);\n");
+}
+
+/// Tests printing aggregate declarations with bit-fields and flexible array members, and a variable of struct type.
+#[test]
+fn test_aggregate_declarations() {
+    let declaration = ast_node!(StructDeclaration[
+        Identifier("Packet"),
+        Field[Literal("flags"), Type(Integer), BitWidth[Literal(3)]],
+        Field[Literal("data"), Type(Char), FlexibleArray],
+    ]);
+    assert_eq!(print_node(&declaration).unwrap(), "struct Packet [\n    flags: int : 3,\n    data: char[]\n];\n");
+
+    let variable = ast_node!(Initialization[Variable[Identifier("packet"), Type(Struct), Identifier("Packet")]]);
+    assert_eq!(print_node(&variable).unwrap(), "struct Packet packet;\n");
+
+    let enumeration = ast_node!(EnumDeclaration[Identifier("Color"), Variant[Identifier("RED")], Variant[Identifier("GREEN")]]);
+    assert_eq!(print_node(&enumeration).unwrap(), "enum Color [\n    RED,\n    GREEN\n];\n");
+}
+
+/// Tests that printing a node that breaks the child-ordering conventions fails.
+#[test]
+fn test_malformed_node() {
+    let broken = ast_node!(BinaryExpression[Identifier("a")]);
+    assert!(print_expression(&broken).is_err());
+}
@@ -0,0 +1,106 @@
+//! This file contains tests for selecting AST nodes with `Query`.
+
+use common::{
+    ast,
+    ast::{
+        core::{ASTNode, AST},
+        node_type::NodeType,
+        query::Query,
+    },
+};
+
+/// Builds a program with an assignment to `i` outside a loop and assignments to `i` and `j` inside one.
+fn sample() -> AST {
+    ast!(TopLevelExpression[
+        FunctionDeclaration[
+            Identifier("main"),
+            Parameter[Identifier("argc"), Type(Integer)],
+            Type(Integer),
+            BlockExpression[
+                Assignment[Identifier("i"), Literal(0)],
+                WhileLoop[
+                    Condition[BinaryExpression[Identifier("i"), Operator("<"), Literal(10)]],
+                    BlockExpression[
+                        Assignment[Identifier("i"), Operator("+="), Literal(1)],
+                        Assignment[Variable[Identifier("j"), Type(Integer)], AssignedValue[Identifier("i")]],
+                    ],
+                ],
+                Return[AssignedValue[Identifier("i")]],
+            ],
+        ],
+    ])
+}
+
+/// Tests the motivating example: assignments to `i` under a while loop, ignoring the one outside it.
+#[test]
+fn test_assignments_to_identifier_in_loop() {
+    let tree = sample();
+    let query = Query::parse("WhileLoop Assignment[identifier=i]").unwrap();
+
+    let paths: Vec<String> = query.find_paths(&tree).iter().map(|path| path.to_string()).collect();
+    assert_eq!(paths, vec![
+        "root/TopLevelExpression[0]/FunctionDeclaration[0]/BlockExpression[3]/WhileLoop[1]/BlockExpression[1]/Assignment[0]",
+    ]);
+
+    let built = Query::new().descendants("WhileLoop").descendants("Assignment").with_identifier("i");
+    assert_eq!(built.find_all(tree.get_root_ref()), query.find_all(tree.get_root_ref()));
+}
+
+/// Tests that identifier predicates also see through `Variable` targets.
+#[test]
+fn test_identifier_through_variable() {
+    let tree = sample();
+    let found = Query::parse("Assignment[identifier=j]").unwrap().find_all(tree.get_root_ref());
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].get_children_ref()[0].get_node_type_ref(), &NodeType::Variable);
+}
+
+/// Tests that the child axis only selects direct children.
+#[test]
+fn test_child_axis() {
+    let tree = sample();
+    assert_eq!(Query::parse("FunctionDeclaration > Identifier").unwrap().find_all(tree.get_root_ref()).len(), 1);
+    assert_eq!(Query::parse("FunctionDeclaration Identifier").unwrap().find_all(tree.get_root_ref()).len(), 8);
+    assert_eq!(Query::parse("> FunctionDeclaration").unwrap().find_all(tree.get_root_ref()).len(), 1);
+    assert!(Query::parse("> Assignment").unwrap().find_all(tree.get_root_ref()).is_empty());
+}
+
+/// Tests value, has and wildcard predicates, and results in pre-order without duplicates.
+#[test]
+fn test_predicates_and_order() {
+    let tree = sample();
+
+    let operators = Query::parse("*[value=+=]").unwrap().find_all(tree.get_root_ref());
+    assert_eq!(operators, vec![&ASTNode::new(NodeType::Operator("+=".to_string()))]);
+
+    let with_values = Query::parse("BlockExpression Assignment[has=AssignedValue]").unwrap().find_all(tree.get_root_ref());
+    assert_eq!(with_values.len(), 1);
+
+    let identifiers = Query::parse("BlockExpression Identifier[identifier=i]").unwrap().find_paths(&tree);
+    assert_eq!(identifiers.len(), 5);
+    let mut sorted = identifiers.clone();
+    sorted.sort_by_key(|path| path.get_indices());
+    assert_eq!(identifiers, sorted);
+}
+
+/// Tests that function filters restrict a step.
+#[test]
+fn test_filter() {
+    let tree = sample();
+    let compound = Query::new()
+        .descendants("Assignment")
+        .filter(|node| node.get_children_ref().iter().any(|child| matches!(child.get_node_type_ref(), NodeType::Operator(_))));
+    assert_eq!(compound.find_all(tree.get_root_ref()).len(), 1);
+    assert!(compound.find_first(tree.get_root_ref()).is_some());
+}
+
+/// Tests that malformed selectors are rejected.
+#[test]
+fn test_parse_errors() {
+    assert!(Query::parse("").is_err());
+    assert!(Query::parse("WhileLoops").is_err());
+    assert!(Query::parse("Assignment[identifier=i").is_err());
+    assert!(Query::parse("Assignment[name=i]").is_err());
+    assert!(Query::parse("WhileLoop >").is_err());
+    assert!(Query::parse("WhileLoop > > Assignment").is_err());
+}
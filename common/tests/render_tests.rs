@@ -0,0 +1,97 @@
+//! This file contains tests for rendering diagnostics for the terminal.
+
+use common::{
+    diagnostic::Diagnostic,
+    render::{ColorChoice, Renderer},
+    source::{SourceManager, Span},
+};
+
+/// Builds a source manager holding a small program.
+fn sources() -> SourceManager {
+    let mut sources = SourceManager::new();
+    sources.add_file("main.c", "int count;\nint x;\n\nint main() {\n\treturn cuont;\n}\n".to_string());
+    sources
+}
+
+/// Tests the gutter, a secondary label on an earlier line, the elision marker and tab expansion.
+#[test]
+fn test_primary_and_secondary_labels() {
+    let sources = sources();
+    let file = sources.get_files()[0].get_id();
+    let text = sources.get_file(file).unwrap().get_text();
+    let use_start = text.find("cuont").unwrap();
+
+    let diagnostic = Diagnostic::error("cannot find variable `cuont` in this scope")
+        .with_code("E0002")
+        .with_span(Span::new(file, use_start, use_start + 5), "not found in this scope")
+        .with_label(Span::new(file, 4, 9), "a variable with a similar name exists")
+        .with_help("a variable with a similar name exists: `count`");
+
+    assert_eq!(Renderer::new(&sources, false).render(&diagnostic), [
+        "error[E0002]: cannot find variable `cuont` in this scope",
+        " --> main.c:5:9",
+        "  |",
+        "1 | int count;",
+        "  |     ----- a variable with a similar name exists",
+        "...",
+        "5 |     return cuont;",
+        "  |            ^^^^^ not found in this scope",
+        "  |",
+        "  = help: a variable with a similar name exists: `count`",
+        "",
+    ].join("\n"));
+}
+
+/// Tests that labels in another file get their own header, and that included files note their origin.
+#[test]
+fn test_multiple_files_and_includes() {
+    let mut sources = SourceManager::new();
+    let main = sources.add_file("main.c", "#include \"a.h\"\nint x;\n".to_string());
+    let header = sources.add_include("a.h", "int x;\n".to_string(), Span::new(main, 0, 14));
+
+    let diagnostic = Diagnostic::error("redefinition of `x`")
+        .with_span(Span::new(main, 19, 20), "redefined here")
+        .with_label(Span::new(header, 4, 5), "previous definition is here");
+    let rendered = Renderer::new(&sources, false).render(&diagnostic);
+    assert!(rendered.contains(" --> main.c:2:5\n"));
+    assert!(rendered.contains(" ::: a.h:1:5\n"));
+    assert!(rendered.contains("  |     - previous definition is here\n"));
+
+    let in_header = Diagnostic::warning("unused variable `x`").with_span(Span::new(header, 4, 5), "");
+    let rendered = Renderer::new(&sources, false).render(&in_header);
+    assert!(rendered.ends_with("  = note: in the file included from main.c:1:1\n"), "{}", rendered);
+}
+
+/// Tests diagnostics without a location and the summary line.
+#[test]
+fn test_unlocated_and_summary() {
+    let sources = sources();
+    let renderer = Renderer::new(&sources, false);
+    let diagnostics = vec![
+        Diagnostic::error("first").with_note("detail"),
+        Diagnostic::error("second"),
+        Diagnostic::warning("third"),
+    ];
+
+    assert_eq!(renderer.render(&diagnostics[0]), "error: first\n  = note: detail\n");
+    assert!(renderer.render_all(&diagnostics).ends_with("error: aborting due to 2 previous errors; 1 warning emitted\n"));
+    assert_eq!(renderer.render_summary(&diagnostics[2..]), Some("warning: 1 warning emitted\n".to_string()));
+    assert_eq!(renderer.render_summary(&[Diagnostic::note("only a note")]), None);
+}
+
+/// Tests that colour codes are only emitted when enabled, and parsing of the `--color` values.
+#[test]
+fn test_color() {
+    let sources = sources();
+    let diagnostic = Diagnostic::error("boom");
+    assert!(Renderer::new(&sources, true).render(&diagnostic).contains("\x1b[1;31merror\x1b[0m"));
+    assert!(!Renderer::new(&sources, false).render(&diagnostic).contains('\x1b'));
+
+    assert_eq!("always".parse::<ColorChoice>().unwrap(), ColorChoice::Always);
+    assert_eq!("never".parse::<ColorChoice>().unwrap(), ColorChoice::Never);
+    assert_eq!("auto".parse::<ColorChoice>().unwrap(), ColorChoice::default());
+    assert!("sometimes".parse::<ColorChoice>().is_err());
+    assert!(ColorChoice::Always.should_color(false));
+    assert!(!ColorChoice::Never.should_color(true));
+    assert!(!ColorChoice::Auto.should_color(false));
+}
@@ -0,0 +1,61 @@
+//! This file contains tests for serializing an `AST` to JSON and the compact binary format.
+//!
+//! Run with `cargo test -p common --features serde`.
+
+#![cfg(feature = "serde")]
+
+use common::{
+    ast,
+    ast::{
+        core::{ASTNode, AST},
+        data_type::DataType,
+        node_type::NodeType,
+    },
+    serialize::{from_binary, from_json, to_binary, to_json, to_json_pretty},
+};
+
+/// Tests that an `AST` round-trips through JSON.
+#[test]
+fn test_ast_json_round_trip() {
+    let ast = ast!(TopLevelExpression[
+        Initialization[
+            Variable[Identifier("x"), Type(Integer)],
+            AssignedValue[BinaryExpression[Identifier("a"), Operator("+"), Literal(5)]],
+        ],
+    ]);
+    let json = to_json(&ast).unwrap();
+    assert_eq!(from_json::<AST>(&json).unwrap(), ast);
+
+    let pretty = to_json_pretty(&ast).unwrap();
+    assert_eq!(from_json::<AST>(&pretty).unwrap(), ast);
+}
+
+/// Tests that an `AST` round-trips through the binary format, which is more compact than JSON.
+#[test]
+fn test_ast_binary_round_trip() {
+    let ast = ast!(TopLevelExpression[
+        Initialization[
+            Variable[Identifier("x"), Type(Integer)],
+            AssignedValue[BinaryExpression[Identifier("a"), Operator("+"), Literal(5)]],
+        ],
+    ]);
+    let bytes = to_binary(&ast).unwrap();
+    assert_eq!(from_binary::<AST>(&bytes).unwrap(), ast);
+    assert!(bytes.len() < to_json(&ast).unwrap().len());
+}
+
+/// Tests that node types and data types deserialize from hand-written JSON fixtures.
+#[test]
+fn test_node_type_from_json_fixture() {
+    assert_eq!(from_json::<NodeType>(r#"{"Identifier":"x"}"#).unwrap(), NodeType::Identifier("x".to_string()));
+    assert_eq!(from_json::<NodeType>(r#""BlockExpression""#).unwrap(), NodeType::BlockExpression);
+    assert_eq!(from_json::<NodeType>(r#"{"Type":"Double"}"#).unwrap(), NodeType::Type(DataType::Double));
+    assert_eq!(from_json::<ASTNode>(r#"{"node_type":"Break","children":[]}"#).unwrap(), ASTNode::new(NodeType::Break));
+}
+
+/// Tests that malformed input is reported as an error.
+#[test]
+fn test_invalid_input() {
+    assert!(from_json::<AST>(r#"{"root":{"node_type":"NotANode","children":[]}}"#).is_err());
+    assert!(from_binary::<AST>(&[1, 2, 3]).is_err());
+}
@@ -0,0 +1,89 @@
+//! This file contains tests for the `SourceManager` and its line tables.
+
+use common::source::{Provenance, SourceManager, Span};
+
+/// Tests that offsets resolve to lines and columns, including the end of the text and multi-byte characters.
+#[test]
+fn test_locations() {
+    let mut sources = SourceManager::new();
+    let file = sources.add_file("main.c", "int a;\r\n/* é */ int b;\n".to_string());
+
+    let start = sources.get_location(file, 0).unwrap();
+    assert_eq!((start.get_line(), start.get_column()), (1, 1));
+
+    let after_accent = sources.get_location(file, "int a;\r\n/* é */ ".len()).unwrap();
+    assert_eq!((after_accent.get_line(), after_accent.get_column()), (2, 9));
+
+    let end = sources.get_location(file, sources.get_file(file).unwrap().get_text().len()).unwrap();
+    assert_eq!((end.get_line(), end.get_column()), (3, 1));
+
+    assert!(sources.get_location(file, "int a;\r\n/* ".len() + 1).is_none());
+    assert!(sources.get_location(file, 1000).is_none());
+}
+
+/// Tests line text, line counts and snippets.
+#[test]
+fn test_line_text_and_snippets() {
+    let mut sources = SourceManager::new();
+    let file = sources.add_file("main.c", "int a;\r\nint b;".to_string());
+    let source = sources.get_file(file).unwrap();
+
+    assert_eq!(source.get_line_count(), 2);
+    assert_eq!(source.get_line_text(1), Some("int a;"));
+    assert_eq!(source.get_line_text(2), Some("int b;"));
+    assert_eq!(source.get_line_text(0), None);
+    assert_eq!(source.get_line_text(3), None);
+    assert_eq!(source.get_line_start(2), Some(8));
+
+    let span = Span::new(file, 12, 8);
+    assert_eq!((span.get_start(), span.get_end(), span.len()), (8, 12, 4));
+    assert_eq!(sources.get_snippet(&span), Some("int "));
+    assert_eq!(sources.format_span(&span), Some("main.c:2:1".to_string()));
+}
+
+/// Tests that files keep distinct ids and that includes and macro expansions trace back to their origin.
+#[test]
+fn test_provenance() {
+    let mut sources = SourceManager::new();
+    let main = sources.add_file("main.c", "#include \"util.h\"\nint x = MAX;\n".to_string());
+    let directive = Span::new(main, 0, 17);
+    let header = sources.add_include("util.h", "#define MAX LIMIT\nint y = MAX;\n".to_string(), directive);
+    let call_site = Span::new(header, 26, 29);
+    let expansion = sources.add_macro_expansion("MAX", "LIMIT".to_string(), call_site);
+
+    assert_ne!(main, header);
+    assert_eq!(sources.get_files().len(), 3);
+    assert_eq!(sources.get_name(expansion), Some("<macro MAX>"));
+    assert_eq!(sources.get_file(expansion).unwrap().get_provenance(),
+        &Provenance::MacroExpansion { name: "MAX".to_string(), call_site });
+    assert_eq!(sources.get_snippet(&call_site), Some("MAX"));
+
+    assert_eq!(sources.get_expansion_chain(expansion), vec![call_site, directive]);
+    assert!(sources.get_expansion_chain(main).is_empty());
+}
+
+/// Tests that merging spans covers both and refuses spans from different files.
+#[test]
+fn test_merge() {
+    let mut sources = SourceManager::new();
+    let first = sources.add_file("a.c", "abcdef".to_string());
+    let second = sources.add_file("b.c", "abcdef".to_string());
+
+    assert_eq!(Span::new(first, 1, 2).merge(&Span::new(first, 4, 5)), Some(Span::new(first, 1, 5)));
+    assert_eq!(Span::new(first, 1, 2).merge(&Span::new(second, 4, 5)), None);
+    assert!(Span::new(first, 3, 3).is_empty());
+}
+
+/// Tests loading a file from disk and the error for a missing one.
+#[test]
+fn test_load() {
+    let path = std::env::temp_dir().join("sicc_source_tests_load.c");
+    std::fs::write(&path, "int main() {}\n").unwrap();
+
+    let mut sources = SourceManager::new();
+    let file = sources.load(&path).unwrap();
+    assert_eq!(sources.get_line_text(file, 1), Some("int main() {}"));
+    assert!(sources.load(&path.with_extension("missing")).is_err());
+
+    std::fs::remove_file(path).unwrap();
+}
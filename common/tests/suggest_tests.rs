@@ -0,0 +1,29 @@
+//! This file contains tests for finding close matches for misspelled names.
+
+use common::suggest::{best_match, edit_distance, max_distance};
+
+/// Tests the distance for insertions, deletions, substitutions, transpositions and multi-byte characters.
+#[test]
+fn test_edit_distance() {
+    assert_eq!(edit_distance("", "abc"), 3);
+    assert_eq!(edit_distance("total", "totals"), 1);
+    assert_eq!(edit_distance("total", "tota"), 1);
+    assert_eq!(edit_distance("total", "tetal"), 1);
+    assert_eq!(edit_distance("total", "toatl"), 1);
+    assert_eq!(edit_distance("ca", "abc"), 3);
+    assert_eq!(edit_distance("naïve", "naive"), 1);
+}
+
+/// Tests the choice between candidates, the distance limit and the preference for case differences.
+#[test]
+fn test_best_match() {
+    assert_eq!(max_distance("i"), 1);
+    assert_eq!(max_distance("counter"), 2);
+
+    assert_eq!(best_match("i", ["j", "k"]), Some("j"));
+    assert_eq!(best_match("countr", ["counter", "count"]), Some("counter"));
+    assert_eq!(best_match("buffer_length", ["BUFFER_LENGTH", "buffer_lengths"]), Some("BUFFER_LENGTH"));
+    assert_eq!(best_match("x", ["x"]), None);
+    assert_eq!(best_match("index", Vec::<&str>::new()), None);
+    assert_eq!(best_match("value", ["values", "valve"]), Some("values"));
+}
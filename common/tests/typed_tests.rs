@@ -0,0 +1,135 @@
+//! This file contains tests for converting between `ASTNode`s and the typed `Expr`, `Stmt` and `Decl` enums.
+
+use common::{
+    ast, ast_node,
+    ast::{
+        core::ASTNode,
+        data_type::DataType,
+        node_type::NodeType,
+        typed::{Decl, Expr, Field, Program, Stmt, SwitchArm},
+    },
+};
+
+/// Builds a function that exercises most statement kinds, in the shape the parser produces.
+fn sample_function() -> ASTNode {
+    ast_node!(FunctionDeclaration[
+        Identifier("sum"),
+        Parameter[Identifier("n"), Type(Integer)],
+        VariadicParameter,
+        Type(Integer),
+        BlockExpression[
+            Initialization[Variable[Identifier("total"), Type(Integer)], AssignedValue[Literal(0)]],
+            ForLoop[
+                LoopInitializer[Initialization[Variable[Identifier("i"), Type(Integer)], AssignedValue[Literal(0)]]],
+                Condition[BinaryExpression[Identifier("i"), Operator("<"), Identifier("n")]],
+                LoopIncrement[UnaryExpression[Identifier("i"), Operator("++")]],
+                BlockExpression[Assignment[Identifier("total"), Operator("+="), Identifier("i")]],
+            ],
+            SwitchStatement[
+                Identifier("total"),
+                BlockExpression[
+                    Case[Literal(0), BlockExpression[Break]],
+                    Default[BlockExpression[FunctionCall[Identifier("log"), Identifier("total")]]],
+                ],
+            ],
+            IfStatement[
+                Condition[BinaryExpression[Identifier("total"), Operator(">"), Literal(10)]],
+                BlockExpression[Return[AssignedValue[Literal(10)]]],
+                ElseStatement[BlockExpression[WhileLoop[Condition[Identifier("total")]]]],
+            ],
+            Return[AssignedValue[Identifier("total")]],
+        ],
+    ])
+}
+
+/// Tests that a conventional tree converts to the typed form and back unchanged.
+#[test]
+fn test_lossless_round_trip() {
+    let ast = ast!(TopLevelExpression[
+        StructDeclaration[
+            Identifier("Packet"),
+            Field[Literal("flags"), Type(Integer), BitWidth[Literal(3)]],
+            Field[Literal("data"), Type(Char), FlexibleArray],
+        ],
+        EnumDeclaration[Identifier("Color"), Variant[Identifier("RED")]],
+        Initialization[Variable[Identifier("packet"), Type(Struct), Identifier("Packet")]],
+        { sample_function() },
+    ]);
+
+    let program = Program::from_ast(&ast).unwrap();
+    assert_eq!(program.get_items().len(), 4);
+    assert!(matches!(&program.get_items()[2], Stmt::Initialization { record: Some(record), .. } if record == "Packet"));
+    assert_eq!(program.to_ast(), ast);
+}
+
+/// Tests that the typed form exposes the structure by name.
+#[test]
+fn test_pattern_matching() {
+    let decl = Decl::from_node(&sample_function()).unwrap();
+    let (parameters, variadic, body) = match decl {
+        Decl::Function { name, parameters, variadic, return_type, body } => {
+            assert_eq!(name, "sum");
+            assert_eq!(return_type, DataType::Integer);
+            (parameters, variadic, body.unwrap())
+        },
+        other => panic!("Expected a function, found {:?}", other),
+    };
+    assert_eq!(parameters, vec![("n".to_string(), DataType::Integer)]);
+    assert!(variadic);
+
+    match &body[1] {
+        Stmt::For { initializer, increment, body, .. } => {
+            assert!(matches!(initializer.as_deref(), Some(Stmt::Initialization { name, .. }) if name == "i"));
+            assert_eq!(increment, &Some(Expr::Unary { operator: "++".to_string(), operand: Box::new(Expr::Identifier("i".to_string())), postfix: true }));
+            assert_eq!(body[0], Stmt::Expr(Expr::Assignment {
+                target: Box::new(Expr::Identifier("total".to_string())),
+                operator: Some("+=".to_string()),
+                value: Box::new(Expr::Identifier("i".to_string())),
+            }));
+        },
+        other => panic!("Expected a for loop, found {:?}", other),
+    }
+
+    match &body[2] {
+        Stmt::Switch { arms, .. } => assert!(matches!(arms.as_slice(), [SwitchArm::Case { .. }, SwitchArm::Default { .. }])),
+        other => panic!("Expected a switch, found {:?}", other),
+    }
+
+    match &body[3] {
+        Stmt::If { else_branch: Some(else_branch), .. } => assert!(matches!(else_branch.as_ref(), Stmt::Block(block) if matches!(block[0], Stmt::While { body: None, .. }))),
+        other => panic!("Expected an if-else, found {:?}", other),
+    }
+}
+
+/// Tests building typed nodes directly and lowering them to the generic form.
+#[test]
+fn test_typed_to_node() {
+    let decl = Decl::Union {
+        name: "Value".to_string(),
+        fields: vec![Field::new("i", DataType::Integer, None, false), Field::new("f", DataType::Float, None, false)],
+    };
+    let expected = ast_node!(UnionDeclaration[
+        Identifier("Value"),
+        Field[Literal("i"), Type(Integer)],
+        Field[Literal("f"), Type(Float)],
+    ]);
+    assert_eq!(ASTNode::from(&decl), expected);
+
+    let assignment = Expr::Assignment {
+        target: Box::new(Expr::Variable { name: "x".to_string(), data_type: Some(DataType::Integer), record: None }),
+        operator: None,
+        value: Box::new(Expr::Member { base: Box::new(Expr::Identifier("p".to_string())), member: "y".to_string() }),
+    };
+    let lowered = assignment.to_node();
+    assert_eq!(lowered.get_children_ref()[1].get_node_type(), NodeType::AssignedValue);
+    assert_eq!(Expr::try_from(&lowered).unwrap(), assignment);
+}
+
+/// Tests that nodes breaking the conventions are rejected.
+#[test]
+fn test_malformed_nodes() {
+    assert!(Expr::from_node(&ast_node!(BinaryExpression[Identifier("a"), Identifier("b")])).is_err());
+    assert!(Stmt::from_node(&ast_node!(DoWhileLoop[Condition[Identifier("a")]])).is_err());
+    assert!(Decl::from_node(&ast_node!(FunctionDeclaration[Identifier("f")])).is_err());
+    assert!(Program::from_ast(&ast!(Break)).is_err());
+}
@@ -0,0 +1,119 @@
+//! This file contains tests for validating an `AST` against the child-ordering conventions.
+
+use common::{
+    ast, ast_node,
+    ast::{
+        core::ASTNode,
+        data_type::DataType,
+        node_type::NodeType,
+        path::NodePath,
+        validate::{check_ast, validate, validate_node},
+    },
+    error::ErrorType,
+};
+
+/// Builds `int f(int a) { int x = a; if (x) { return x; } }` in the shape the parser produces.
+fn valid_function() -> ASTNode {
+    ast_node!(FunctionDeclaration[
+        Identifier("f"),
+        Parameter[Identifier("a"), Type(Integer)],
+        Type(Integer),
+        BlockExpression[
+            Initialization[Variable[Identifier("x"), Type(Integer)], AssignedValue[Identifier("a")]],
+            IfStatement[Condition[Identifier("x")], BlockExpression[Return[AssignedValue[Identifier("x")]]]],
+        ],
+    ])
+}
+
+/// Tests that a conventional tree has no violations.
+#[test]
+fn test_valid_tree() {
+    let ast = ast!(TopLevelExpression[{ valid_function() }]);
+    assert_eq!(validate(&ast), Vec::new());
+    assert_eq!(check_ast(&ast), Ok(()));
+}
+
+/// Tests that a function whose return type follows its body is reported with its path.
+#[test]
+fn test_misplaced_child() {
+    let mut function = valid_function();
+    let mut children = function.get_children();
+    children.swap(2, 3);
+    function.set_children(children);
+    let ast = ast!(TopLevelExpression[{ function }]);
+
+    let violations = validate(&ast);
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].get_path().to_string(), "root/TopLevelExpression[0]/FunctionDeclaration[0]");
+    assert_eq!(
+        violations[0].get_message(),
+        "FunctionDeclaration expects children [Identifier, Parameter*, VariadicParameter?, Type, BlockExpression?] but found [Identifier, Parameter, BlockExpression, Type]",
+    );
+}
+
+/// Tests that every violation in a tree is reported, in pre-order, and that paths resolve to the offending nodes.
+#[test]
+fn test_multiple_violations() {
+    let bad_leaf = ast_node!(Break[Literal(1)]);
+    let ast = ast!(TopLevelExpression[
+        WhileLoop[Condition[BinaryExpression[Literal(1), Literal(2)]], BlockExpression[{ bad_leaf.clone() }]],
+    ]);
+
+    let violations = validate(&ast);
+    let paths: Vec<String> = violations.iter().map(|violation| violation.get_path().to_string()).collect();
+    assert_eq!(paths, vec![
+        "root/TopLevelExpression[0]/WhileLoop[0]/Condition[0]/BinaryExpression[0]",
+        "root/TopLevelExpression[0]/WhileLoop[0]/BlockExpression[1]/Break[0]",
+    ]);
+    assert_eq!(violations[1].get_path().resolve(&ast), Some(&bad_leaf));
+    assert!(matches!(&check_ast(&ast).unwrap_err()[0], ErrorType::InvalidAST { path, .. } if path == &paths[0]));
+}
+
+/// Tests that both prefix and postfix unary expressions are accepted, and that paths are relative to the validated node.
+#[test]
+fn test_alternative_schemas() {
+    let prefix = ast_node!(UnaryExpression[Operator("-"), Identifier("x")]);
+    let postfix = ast_node!(UnaryExpression[Identifier("x"), Operator("++")]);
+    assert!(validate_node(&prefix).is_empty());
+    assert!(validate_node(&postfix).is_empty());
+
+    let bad = ast_node!(UnaryExpression[Identifier("x")]);
+    assert_eq!(validate_node(&bad)[0].get_path(), &NodePath::root(&bad));
+}
+
+/// Tests that the list of kind names agrees with the name of every `NodeType` variant, in declaration order.
+#[test]
+fn test_kind_names_agree() {
+    let kinds = vec![
+        NodeType::NoExpression, NodeType::Literal(String::new()), NodeType::Identifier(String::new()),
+        NodeType::Operator(String::new()), NodeType::Type(DataType::Integer), NodeType::Constant(String::new()),
+        NodeType::IfStatement, NodeType::ElseStatement, NodeType::ForLoop, NodeType::WhileLoop, NodeType::DoWhileLoop,
+        NodeType::Break, NodeType::Continue, NodeType::Return, NodeType::SwitchStatement, NodeType::Case,
+        NodeType::Default, NodeType::Assignment, NodeType::Initialization, NodeType::FunctionDeclaration,
+        NodeType::StructDeclaration, NodeType::EnumDeclaration, NodeType::UnionDeclaration, NodeType::ModuleExpression,
+        NodeType::TopLevelExpression, NodeType::BlockExpression, NodeType::Condition, NodeType::Action, NodeType::Variant,
+        NodeType::AssignedValue, NodeType::Field, NodeType::BitWidth, NodeType::FlexibleArray, NodeType::MemberAccess,
+        NodeType::Parameter, NodeType::VariadicParameter, NodeType::Variable, NodeType::BinaryExpression,
+        NodeType::UnaryExpression, NodeType::FunctionCall, NodeType::Operand, NodeType::LoopInitializer,
+        NodeType::LoopIncrement,
+    ];
+    for kind in &kinds {
+        // Exhaustive, so adding a variant fails to compile until it is also added to `kinds` above.
+        match kind {
+            NodeType::NoExpression | NodeType::Literal(_) | NodeType::Identifier(_) | NodeType::Operator(_)
+            | NodeType::Type(_) | NodeType::Constant(_) | NodeType::IfStatement | NodeType::ElseStatement
+            | NodeType::ForLoop | NodeType::WhileLoop | NodeType::DoWhileLoop | NodeType::Break | NodeType::Continue
+            | NodeType::Return | NodeType::SwitchStatement | NodeType::Case | NodeType::Default | NodeType::Assignment
+            | NodeType::Initialization | NodeType::FunctionDeclaration | NodeType::StructDeclaration
+            | NodeType::EnumDeclaration | NodeType::UnionDeclaration | NodeType::ModuleExpression
+            | NodeType::TopLevelExpression | NodeType::BlockExpression | NodeType::Condition | NodeType::Action
+            | NodeType::Variant | NodeType::AssignedValue | NodeType::Field | NodeType::BitWidth
+            | NodeType::FlexibleArray | NodeType::MemberAccess | NodeType::Parameter | NodeType::VariadicParameter
+            | NodeType::Variable | NodeType::BinaryExpression | NodeType::UnaryExpression | NodeType::FunctionCall
+            | NodeType::Operand | NodeType::LoopInitializer | NodeType::LoopIncrement => {},
+        }
+        assert!(format!("{:?}", kind).starts_with(kind.get_kind_name()));
+    }
+    let names: Vec<&str> = kinds.iter().map(NodeType::get_kind_name).collect();
+    assert_eq!(NodeType::get_kind_names(), names.as_slice());
+}
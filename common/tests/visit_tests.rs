@@ -0,0 +1,170 @@
+//! This file contains tests for the `Visitor`, `VisitorMut` and `Fold` traits.
+
+use common::{
+    ast,
+    ast::{
+        core::ASTNode,
+        node_type::NodeType,
+        visit::{fold_children, walk_children, Fold, Visitor, VisitorMut},
+    },
+};
+
+/// Records the order in which hooks and kind methods run.
+struct Tracer {
+    events: Vec<String>,
+    depth: usize,
+    max_depth: usize,
+}
+
+impl Visitor for Tracer {
+    fn pre_visit(&mut self, _node: &ASTNode) -> bool {
+        self.depth += 1;
+        self.max_depth = self.max_depth.max(self.depth);
+        true
+    }
+
+    fn post_visit(&mut self, _node: &ASTNode) {
+        self.depth -= 1;
+    }
+
+    fn visit_identifier(&mut self, node: &ASTNode) {
+        self.events.push(node.get_node_type().to_string());
+    }
+
+    fn visit_return(&mut self, node: &ASTNode) {
+        self.events.push("Return".to_string());
+        walk_children(self, node);
+    }
+}
+
+/// Tests that a visitor reaches every node in pre-order, with hooks around each node.
+#[test]
+fn test_visitor_order_and_hooks() {
+    let mut tracer = Tracer { events: Vec::new(), depth: 0, max_depth: 0 };
+    let ast = ast!(TopLevelExpression[
+        FunctionDeclaration[
+            Identifier("f"),
+            Parameter[Identifier("x"), Type(Integer)],
+            Type(Integer),
+            BlockExpression[Assignment[Identifier("x"), Operator("+="), Literal(1)], Return[AssignedValue[Identifier("x")]]],
+        ],
+    ]);
+    tracer.visit_ast(&ast);
+    assert_eq!(tracer.events, vec!["Identifier(f)", "Identifier(x)", "Identifier(x)", "Return", "Identifier(x)"]);
+    assert_eq!(tracer.depth, 0);
+    assert_eq!(tracer.max_depth, 6);
+}
+
+/// Skips function bodies by returning `false` from the pre hook.
+struct SkipBlocks {
+    identifiers: usize,
+}
+
+impl Visitor for SkipBlocks {
+    fn pre_visit(&mut self, node: &ASTNode) -> bool {
+        node.get_node_type() != NodeType::BlockExpression
+    }
+
+    fn visit_identifier(&mut self, _node: &ASTNode) {
+        self.identifiers += 1;
+    }
+}
+
+/// Tests that returning `false` from `pre_visit` skips a subtree.
+#[test]
+fn test_pre_visit_skips_subtree() {
+    let mut skipper = SkipBlocks { identifiers: 0 };
+    let ast = ast!(TopLevelExpression[
+        FunctionDeclaration[
+            Identifier("f"),
+            Parameter[Identifier("x"), Type(Integer)],
+            Type(Integer),
+            BlockExpression[Assignment[Identifier("x"), Operator("+="), Literal(1)], Return[AssignedValue[Identifier("x")]]],
+        ],
+    ]);
+    skipper.visit_ast(&ast);
+    assert_eq!(skipper.identifiers, 2);
+}
+
+/// Renames every identifier in place.
+struct Renamer;
+
+impl VisitorMut for Renamer {
+    fn visit_identifier_mut(&mut self, node: &mut ASTNode) {
+        if node.get_node_type() == NodeType::Identifier("x".to_string()) {
+            *node = ASTNode::new(NodeType::Identifier("y".to_string()));
+        }
+    }
+}
+
+/// Tests that a mutable visitor edits the tree in place.
+#[test]
+fn test_visitor_mut_renames() {
+    let mut ast = ast!(TopLevelExpression[
+        FunctionDeclaration[
+            Identifier("f"),
+            Parameter[Identifier("x"), Type(Integer)],
+            Type(Integer),
+            BlockExpression[Assignment[Identifier("x"), Operator("+="), Literal(1)], Return[AssignedValue[Identifier("x")]]],
+        ],
+    ]);
+    Renamer.visit_ast_mut(&mut ast);
+
+    let mut tracer = Tracer { events: Vec::new(), depth: 0, max_depth: 0 };
+    tracer.visit_ast(&ast);
+    assert_eq!(tracer.events, vec!["Identifier(f)", "Identifier(y)", "Identifier(y)", "Return", "Identifier(y)"]);
+}
+
+/// Rewrites `x += 1` into `x = x + 1`, replacing the node's children with a different shape.
+struct ExpandCompound;
+
+impl Fold for ExpandCompound {
+    fn fold_assignment(&mut self, node: ASTNode) -> ASTNode {
+        let node = fold_children(self, node);
+        let children = node.get_children();
+        if let [target, operator, value] = children.as_slice() {
+            if let NodeType::Operator(op) = operator.get_node_type() {
+                let mut sum = ASTNode::new(NodeType::BinaryExpression);
+                sum.add_children(vec![target.clone(), ASTNode::new(NodeType::Operator(op.trim_end_matches('=').to_string())), value.clone()]);
+                let mut rewritten = ASTNode::new(NodeType::Assignment);
+                rewritten.add_children(vec![target.clone(), sum]);
+                return rewritten;
+            }
+        }
+        node
+    }
+}
+
+/// Tests that a fold can rebuild nodes into a different shape.
+#[test]
+fn test_fold_rewrites() {
+    let ast = ast!(TopLevelExpression[
+        FunctionDeclaration[
+            Identifier("f"),
+            Parameter[Identifier("x"), Type(Integer)],
+            Type(Integer),
+            BlockExpression[Assignment[Identifier("x"), Operator("+="), Literal(1)], Return[AssignedValue[Identifier("x")]]],
+        ],
+    ]);
+    let folded = ExpandCompound.fold_ast(ast);
+    let assignment = folded.get_root_ref().get_children_ref()[0].get_children_ref()[3].get_children_ref()[0].clone();
+    assert_eq!(assignment.get_children_ref().len(), 2);
+    assert_eq!(assignment.get_children_ref()[1].get_node_type(), NodeType::BinaryExpression);
+    assert_eq!(assignment.get_children_ref()[1].get_children_ref()[1].get_node_type(), NodeType::Operator("+".to_string()));
+}
+
+/// Tests that the default fold leaves the tree unchanged.
+#[test]
+fn test_identity_fold() {
+    struct Identity;
+    impl Fold for Identity {}
+    let ast = ast!(TopLevelExpression[
+        FunctionDeclaration[
+            Identifier("f"),
+            Parameter[Identifier("x"), Type(Integer)],
+            Type(Integer),
+            BlockExpression[Assignment[Identifier("x"), Operator("+="), Literal(1)], Return[AssignedValue[Identifier("x")]]],
+        ],
+    ]);
+    assert_eq!(Identity.fold_ast(ast.clone()), ast);
+}
@@ -1,6 +1,6 @@
 //! This file defines the core structures and functionalities associated with our `Module`.
 //! `Module` types are composite structures that aggregate multiple module elements, each containing an AST and a symbol table stack.
-use common::ast::{core::{ASTNode, AST}, node_type::NodeType};
+use common::{ast::{core::{ASTNode, AST}, node_type::NodeType}, source::FileId};
 use sts::core::SymbolTableStack;
 
 /// Represents a module, which is a collection of `ModElement` instances.
@@ -54,6 +54,12 @@ impl Module {
     }
 }
 
+impl Default for Module {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Provides a clone implementation for `Module`.
 ///
 /// # Returns
@@ -77,6 +83,8 @@ pub struct ModElement {
     sym_table_stack: SymbolTableStack,
     /// The priority of this element.
     priority: i32,
+    /// The source file this element was parsed from, if known.
+    file_id: Option<FileId>,
 }
 
 impl ModElement {
@@ -91,8 +99,30 @@ impl ModElement {
             ast,
             sym_table_stack,
             priority,
+            file_id: None,
         }
     }
+
+    /// Records the source file this module element was parsed from, so elements of a multi-file
+    /// compilation can be told apart in diagnostics.
+    ///
+    /// # Parameters
+    /// * `file_id` - The `FileId` issued by the `SourceManager` for the file.
+    ///
+    /// # Returns
+    /// The module element with its file set.
+    pub fn with_file_id(mut self, file_id: FileId) -> Self {
+        self.file_id = Some(file_id);
+        self
+    }
+
+    /// Retrieves the source file this module element was parsed from.
+    ///
+    /// # Returns
+    /// An `Option` containing the `FileId` if one was recorded.
+    pub fn get_file_id(&self) -> Option<FileId> {
+        self.file_id
+    }
     
     /// Retrieves the AST of this module element.
     ///
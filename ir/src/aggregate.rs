@@ -0,0 +1,177 @@
+//! This file hosts all of the functions necessary for generating LLVM IR
+//! for aggregate types such as structs and unions, and for accessing their members.
+
+use common::{ast::{core::ASTNode, data_type::DataType}, error::ErrorType};
+use safe_llvm::ir::core::{Tag, ValueTag};
+use crate::core::IRGenerator;
+
+impl IRGenerator {
+    /// Generates an LLVM type tag for a struct declaration.
+    ///
+    /// Use `common::layout::struct_layout` on the fields to place them. Fields are emitted in layout order,
+    /// with explicit `i8` array padding wherever a field's offset is past the end of the previous one. Bit-fields
+    /// sharing a storage unit are emitted as a single integer of the unit's type. A flexible array member is
+    /// emitted as a trailing zero-length array of its element type.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name of the struct, used as the name of the LLVM struct type.
+    /// - `fields`: The struct's fields as `(name, type)` pairs, as recorded in `SymbolValue::StructValue`.
+    /// - `bit_widths`: The widths of the fields declared as bit-fields, keyed by field name.
+    /// - `flexible_array`: The trailing flexible array member and its element type, if any.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result<Option<Tag>, ErrorType>` containing the tag of the generated struct type
+    /// if successful, or an `ErrorType` if there was an error generating this type tag.
+    ///
+    /// # Errors
+    ///
+    /// - Returns an ErrorType if the layout of the struct could not be computed or generation failed.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// //let (fields, bit_widths, flexible_array) = /* The parts of a StructValue from the symbol table */
+    /// //let type_result = self.generate_struct_type_ir("Packet", &fields, &bit_widths, flexible_array.as_ref());
+    /// /* check if type_result was Ok or Err, if Ok extract the TypeTag from
+    /// the Tag and use this when allocating variables of the struct type. */
+    /// ```
+    pub fn generate_struct_type_ir(&mut self, name: &str, fields: &[(String, DataType)], bit_widths: &[(String, u32)], flexible_array: Option<&(String, DataType)>) -> Result<Option<Tag>, ErrorType> {
+        let _ = name;
+        let _ = fields;
+        let _ = bit_widths;
+        let _ = flexible_array;
+        unimplemented!();
+    }
+
+    /// Generates LLVM IR reading a bit-field of a struct.
+    ///
+    /// Loads the storage unit holding the bit-field, shifts it right by the field's bit offset and ands it
+    /// with the field's bit mask, both taken from the field's `common::layout::FieldLayout`.
+    ///
+    /// # Parameters
+    ///
+    /// - `node`: A reference to a `MemberAccess` `ASTNode` naming a bit-field.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result<Option<Tag>, ErrorType>` containing the tag of the extracted value
+    /// if successful, or an `ErrorType` if there was a problem generating the read.
+    ///
+    /// # Errors
+    ///
+    /// - Returns an ErrorType if the member is not a bit-field of the accessed struct.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// //let a_node: ASTNode = /* Some MemberAccess ASTNode naming a bit-field */
+    /// //let result = self.generate_bit_field_read_ir(&a_node);
+    /// /* check if result was Ok or Err, if Ok extract the ValueTag from
+    /// the Tag and use it like any other integer value. */
+    /// ```
+    pub fn generate_bit_field_read_ir(&mut self, node: &ASTNode) -> Result<Option<Tag>, ErrorType> {
+        let _ = node;
+        unimplemented!();
+    }
+
+    /// Generates LLVM IR writing a bit-field of a struct.
+    ///
+    /// Loads the storage unit holding the bit-field, clears the field's bits by anding with the complement of
+    /// the mask shifted by the bit offset, ors in the new value masked and shifted the same way, and stores
+    /// the unit back.
+    ///
+    /// # Parameters
+    ///
+    /// - `node`: A reference to a `MemberAccess` `ASTNode` naming a bit-field.
+    /// - `value`: The `ValueTag` of the value being written.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result<Option<Tag>, ErrorType>` containing the tag of the store
+    /// if successful, or an `ErrorType` if there was a problem generating the write.
+    ///
+    /// # Errors
+    ///
+    /// - Returns an ErrorType if the member is not a bit-field of the accessed struct.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// //let a_node: ASTNode = /* Some MemberAccess ASTNode naming a bit-field */
+    /// //let result = self.generate_bit_field_write_ir(&a_node, value_tag);
+    /// /* check if result was Ok or Err. */
+    /// ```
+    pub fn generate_bit_field_write_ir(&mut self, node: &ASTNode, value: ValueTag) -> Result<Option<Tag>, ErrorType> {
+        let _ = node;
+        let _ = value;
+        unimplemented!();
+    }
+
+    /// Generates an LLVM type tag for a union declaration.
+    ///
+    /// LLVM has no union type, so a union is lowered to a named struct whose body is the union's most
+    /// strictly aligned member followed by an `i8` array padding it out to the size of the largest member.
+    /// Use `common::layout::union_layout` on the fields to obtain the size, alignment and leading member.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name of the union, used as the name of the LLVM struct type.
+    /// - `fields`: The union's fields as `(name, type)` pairs, as recorded in `SymbolValue::UnionValue`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result<Option<Tag>, ErrorType>` containing the tag of the generated union type
+    /// if successful, or an `ErrorType` if there was an error generating this type tag.
+    ///
+    /// # Errors
+    ///
+    /// - Returns an ErrorType if the layout of the union could not be computed or generation failed.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// //let fields: Vec<(String, DataType)> = /* The fields of a UnionValue from the symbol table */
+    /// //let type_result = self.generate_union_type_ir("Value", &fields);
+    /// /* check if type_result was Ok or Err, if Ok extract the TypeTag from
+    /// the Tag and use this when allocating variables of the union type. */
+    /// ```
+    pub fn generate_union_type_ir(&mut self, name: &str, fields: &[(String, DataType)]) -> Result<Option<Tag>, ErrorType> {
+        let _ = name;
+        let _ = fields;
+        unimplemented!();
+    }
+
+    /// Generates LLVM IR for a member access on a union.
+    ///
+    /// Every member of a union lives at offset zero, so the union's allocation is bitcast to a pointer
+    /// to the member's type, which can then be loaded from or stored to like any other variable.
+    ///
+    /// # Parameters
+    ///
+    /// - `node`: A reference to a `MemberAccess` `ASTNode` whose children are the accessed variable's identifier
+    /// and the member's identifier.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result<Option<Tag>, ErrorType>` containing the tag of the pointer to the member
+    /// if successful, or an `ErrorType` if there was a problem generating the access.
+    ///
+    /// # Errors
+    ///
+    /// - Returns an ErrorType if the variable is not a union or the member does not exist.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// //let a_node: ASTNode = /* Some MemberAccess ASTNode we want to generate a member pointer from */
+    /// //let result = self.generate_union_member_access_ir(&a_node);
+    /// /* check if result was Ok or Err, if Ok extract the ValueTag from
+    /// the Tag and load from or store to it. */
+    /// ```
+    pub fn generate_union_member_access_ir(&mut self, node: &ASTNode) -> Result<Option<Tag>, ErrorType> {
+        let _ = node;
+        unimplemented!();
+    }
+}
@@ -1,12 +1,15 @@
 //! This file hosts all of the functions necessary for generating LLVM IR
 //! for "block" nodes, nodes that generate and manipulate basic blocks.
 
-use common::{ast::core::ASTNode, error::ErrorType};
+use common::{ast::{core::ASTNode, desugar::desugar_node}, error::ErrorType};
 use safe_llvm::ir::core::Tag;
 use crate::core::IRGenerator;
 
 impl IRGenerator {
     /// Generates LLVM IR for a function declaration.
+    ///
+    /// A function whose `SymbolValue::FunctionValue` is marked `variadic`, that is one declared with a
+    /// trailing `VariadicParameter` node, is given a variadic LLVM function type.
     /// 
     /// # Parameters
     ///
@@ -143,6 +146,33 @@ impl IRGenerator {
         unimplemented!();
     }
 
+    /// Generates LLVM IR for a node after lowering it with the desugaring pass, so `for` and `do while` loops,
+    /// compound assignments, increments and `else if` chains go through the code paths of their core forms
+    /// instead of `generate_for_ir` and `generate_do_while_ir`.
+    /// 
+    /// # Parameters
+    ///
+    /// - `node`: A reference to an `ASTNode` to lower and generate IR for.
+    ///
+    /// # Returns
+    ///
+    /// Returns the result of `ir_router` on the lowered node.
+    ///
+    /// # Errors
+    ///
+    /// - Returns an ErrorType if generation of the lowered node failed.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// //let a_node: ASTNode = /* Some ASTNode holding a for loop */
+    /// //let result = self.generate_desugared_ir(&a_node);
+    /// /* the loop is generated as a while loop. */
+    /// ```
+    pub fn generate_desugared_ir(&mut self, node: &ASTNode) -> Result<Option<Tag>, ErrorType> {
+        self.ir_router(&desugar_node(node.clone()))
+    }
+
     /// Generates LLVM IR for an if statement.
     /// 
     /// # Parameters
@@ -5,9 +5,9 @@
 
 use std::sync::{Arc, Mutex};
 use integration::module::Module;
-use common::{ast::core::ASTNode, error::ErrorType};
+use common::{ast::{arena::NodeId, core::ASTNode, validate::validate}, error::ErrorType};
 use safe_llvm::ir::core::{BasicBlockTag, BuilderTag, ContextTag, IRManager, ModuleTag, Tag, ValueTag};
-use sts::core::SymbolTableStack;
+use sts::core::{ScopeId, SymbolTableStack};
 use crate::store::Store;
 
 /// A struct for generating LLVM Intermediate Representation (IR) from a module of abstract syntax trees (AST) and symbol table stacks (STS).
@@ -22,10 +22,8 @@ pub struct IRGenerator {
     builder: Option<BuilderTag>,
     /// Current function tag for IR generation, with the function stored in `resource_pools`. 
     function: Option<ValueTag>,
-    /// Current symbol table stack (sts) from the module.
+    /// Current symbol table stack (sts) from the module, whose current scope follows IR generation.
     sts: Option<SymbolTableStack>,
-    /// Index pointing to the current symbol table within the sts.
-    sts_pointer: usize,
     /// Stack of targets to keep track of the current target blocks for branch statements.
     current_target_stack: Option<Vec<BranchTarget>>, 
     /// Integer to make sure labels are unique. 
@@ -55,9 +53,9 @@ impl IRGenerator {
     /// generate a function. Once you do so, you must set this so that you can know where to write IR into
     /// for each function. Remember, main() is a function!
     /// 
-    /// sts is not set yet, and is None. Additionally, the sts stack pointer is set to 0. Set the STS 
-    /// when you have one and make sure to increment and decrement the stack pointer as you generate IR
-    /// to stay in step with the STS.
+    /// sts is not set yet, and is None. Set the STS when you have one, then enter the scope of each
+    /// function or block as you generate IR for it and exit it when you are done, to stay in step with
+    /// the STS. Scopes are found from the `NodeId` of their node, so there is nothing to count by hand.
     /// 
     /// current_target_stack is initialized as an empty stack. In C, in a while loop for example, break;
     /// will end the loop entirely upon reaching that statement, and continue; will jump back to checking
@@ -98,7 +96,6 @@ impl IRGenerator {
             builder: Some(builder),
             function: None,
             sts: None,
-            sts_pointer: 0,
             current_target_stack: Some(Vec::new()),
             current_label_id: 0,
             store: Store::new(),
@@ -396,46 +393,50 @@ impl IRGenerator {
         resource_pools_guard.get_current_block(self.get_builder())
     }
 
-    /// Increments the stack pointer.
+    /// Enters the scope of an AST node, such as a function or a block, in the current STS.
     ///
-    /// # Examples
+    /// # Parameters
+    ///
+    /// - `node`: The `NodeId` of the node in the `ArenaAST` built from the module's AST.
+    ///
+    /// # Returns
+    ///
+    /// Returns the `ScopeId` of the node's scope.
+    ///
+    /// # Errors
+    ///
+    /// - Returns an `ErrorType::DevError` if no STS is set or the node did not introduce a scope.
     ///
-    /// ```
-    /// /* It will be necessary to increment the stack pointer when we need to move
-    ///  to a new scope. Use*/
-    /// //self.increment_stack_pointer();
-    /// /* to do this. */
-    /// ```
-    pub fn increment_stack_pointer(&mut self) {
-        self.sts_pointer += 1;
-    }
-
-    /// Decrements the stack pointer.
-    /// 
     /// # Examples
     ///
     /// ```
-    /// /* It will be necessary to decrement the stack pointer when we need to move back
-    ///  to a previous scope. Use*/
-    /// //self.decrement_stack_pointer();
+    /// /* It will be necessary to enter a new scope when we generate IR for a block. Use*/
+    /// //self.enter_scope(block_id)?;
     /// /* to do this. */
     /// ```
-    pub fn decrement_stack_pointer(&mut self) {
-        self.sts_pointer -= 1;
+    pub fn enter_scope(&mut self, node: NodeId) -> Result<ScopeId, ErrorType> {
+        self.sts.as_mut()
+            .ok_or_else(|| ErrorType::DevError { message: "No STS set".to_string() })?
+            .enter_scope(node)
     }
 
-    /// Resets the stack pointer to zero.
-    /// 
+    /// Exits the current scope of the current STS, returning to the scope enclosing it.
+    ///
+    /// # Errors
+    ///
+    /// - Returns an `ErrorType::DevError` if no STS is set or no scope is open.
+    ///
     /// # Examples
     ///
     /// ```
-    /// /* Sometimes it could be necessary to reset the stack pointer to 0 if we need to move
-    ///  to a new STS. Use*/
-    /// //self.reset_stack_pointer();
+    /// /* It will be necessary to exit the scope when we are done generating IR for a block. Use*/
+    /// //self.exit_scope()?;
     /// /* to do this. */
     /// ```
-    pub fn reset_stack_pointer(&mut self) {
-        self.sts_pointer = 0;
+    pub fn exit_scope(&mut self) -> Result<(), ErrorType> {
+        self.sts.as_mut()
+            .ok_or_else(|| ErrorType::DevError { message: "No STS set".to_string() })?
+            .close_scope()
     }
 
     /// Retrieves a clone of the current symbol table stack.
@@ -473,22 +474,22 @@ impl IRGenerator {
         self.sts = Some(new_sts);
     }
 
-    /// Retrieves the current position of the stack pointer within the symbol table stack.
+    /// Retrieves the current scope within the symbol table stack.
     ///
     /// # Returns
     ///
-    /// Returns a `usize` representing the current stack pointer.
+    /// Returns the `ScopeId` of the current scope, or `None` if no STS is set or no scope is open.
     ///
     /// # Examples
     ///
     /// ```
-    /// /* when we need to access the STS, the way to find which stack to access is by 
-    /// using */
-    /// //let stack_pointer = self.get_stack_pointer();
-    /// /* to find the current value of the stack pointer, and access the STS with stack_pointer. */
+    /// /* when we need to access the STS, look names up from the current scope with */
+    /// //let info = self.get_stack().and_then(|sts| sts.lookup(name).cloned());
+    /// /* or find the current scope itself with */
+    /// //let scope = self.get_current_scope();
     /// ```
-    pub fn get_stack_pointer(&self) -> usize {
-        self.sts_pointer
+    pub fn get_current_scope(&self) -> Option<ScopeId> {
+        self.sts.as_ref().and_then(SymbolTableStack::get_current_scope)
     }
 
     /// Generates LLVM IR from a given module by processing its AST.
@@ -507,6 +508,32 @@ impl IRGenerator {
         unimplemented!();
     }
 
+    /// Validates the AST of every element of a module against the child-ordering conventions, then generates
+    /// LLVM IR for the module. A malformed tree is reported with the path to the offending node instead of
+    /// failing partway through generation.
+    ///
+    /// # Parameters
+    ///
+    /// - `input`: A `Module` Containing an AST and an STS.
+    ///
+    /// # Returns
+    ///
+    /// Returns the `ModuleTag` produced by `generate_ir` if every AST is valid.
+    ///
+    /// # Errors
+    ///
+    /// - Returns an `ErrorType::InvalidAST` for every node that breaks the conventions, without generating.
+    pub fn generate_ir_validated(&mut self, input: Module) -> Result<ModuleTag, Vec<ErrorType>> {
+        let errors: Vec<ErrorType> = input.get_children().iter()
+            .flat_map(|element| validate(&element.get_ast()))
+            .map(ErrorType::from)
+            .collect();
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+        Ok(self.generate_ir(input))
+    }
+
     /// Routes the generation of LLVM IR based on the type of AST node encountered.
     /// 
     /// # Parameters
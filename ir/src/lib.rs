@@ -11,6 +11,7 @@
 //! - `block`: Handles generation for block-containing elements like loops and functions.
 //! - `statement`: Handles generation for statements and operations within blocks.
 //! - `primitive`: Handles generation for primitive data types and operations.
+//! - `aggregate`: Handles generation for aggregate types like structs and unions and their member accesses.
 //! - `store`: Handles keeping track of the tags for creating and storing variables
 
 
@@ -26,5 +27,8 @@ mod statement;
 /// LLVM IR generation for primitive data types and operations.
 mod primitive;
 
+/// LLVM IR generation for aggregate types and member accesses.
+mod aggregate;
+
 /// Allocation store for managing variable allocations across scopes. 
 mod store;
\ No newline at end of file
@@ -168,4 +168,37 @@ impl IRGenerator {
         let _ = node;
         unimplemented!();
     }
+
+    /// Generates LLVM IR for a function call.
+    ///
+    /// Arguments matching a declared parameter are converted to that parameter's type. When the callee's
+    /// `SymbolValue::FunctionValue` is `variadic`, every argument past the declared parameters instead receives
+    /// the default argument promotions given by `DataType::default_argument_promotion`, such as a `float`
+    /// extended to a `double` or a `char` extended to an `int`, before the call is built.
+    /// 
+    /// # Parameters
+    ///
+    /// - `node`: A reference to an `ASTNode` to generate IR for a function call.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result<Option<Tag>, ErrorType>` containing the Tag of the call's return value
+    /// if generation went smoothly or an Error if there was a problem in generation.
+    ///
+    /// # Errors
+    ///
+    /// - Returns an ErrorType if the callee is not declared or is passed too few arguments.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// //let a_node: ASTNode = /* Some ASTNode we want to generate a function call from */
+    /// //let result = self.generate_function_call_ir(&a_node);
+    /// /* check if type_result was Ok or Err, if Ok, it will contain a Tag that houses
+    /// the value returned by the call. */
+    /// ```
+    pub fn generate_function_call_ir(&mut self, node: &ASTNode) -> Result<Option<Tag>, ErrorType> {
+        let _ = node;
+        unimplemented!();
+    }
 }
\ No newline at end of file
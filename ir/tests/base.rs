@@ -45,6 +45,7 @@ fn test_function_declaration() {
     let mut sts_global = SymbolTable::new();
     let fn_value = SymbolValue::FunctionValue{
         parameters: Vec::new(),
+        variadic: false,
     };
     let fn_info = SymbolInfo::new(DataType::Integer, fn_value);
     sts_global.add("testFunction".to_string(), fn_info);
@@ -162,6 +163,7 @@ fn test_function_with_if_else() {
     let mut sts_global = SymbolTable::new();
     let fn_value = SymbolValue::FunctionValue{
         parameters: Vec::new(),
+        variadic: false,
     };
     let fn_info = SymbolInfo::new(DataType::Integer, fn_value);
     sts_global.add("testFunction".to_string(), fn_info);
@@ -257,6 +259,7 @@ fn test_function_with_while_loop() {
     let mut sts_global = SymbolTable::new();
     let fn_value = SymbolValue::FunctionValue{
         parameters: Vec::new(),
+        variadic: false,
     };
     let fn_info = SymbolInfo::new(DataType::Integer, fn_value);
     sts_global.add("testFunctionWithWhileLoop".to_string(), fn_info);
@@ -336,6 +339,7 @@ fn test_function_with_while_no_body() {
     let mut sts_global = SymbolTable::new();
     let fn_value = SymbolValue::FunctionValue{
         parameters: Vec::new(),
+        variadic: false,
     };
     let fn_info = SymbolInfo::new(DataType::Integer, fn_value);
     sts_global.add("testFunctionWithWhileNoBody".to_string(), fn_info);
@@ -429,6 +433,7 @@ fn test_function_with_do_while_loop() {
     let mut sts_global = SymbolTable::new();
     let fn_value = SymbolValue::FunctionValue{
         parameters: Vec::new(),
+        variadic: false,
     };
     let fn_info = SymbolInfo::new(DataType::Integer, fn_value);
     sts_global.add("testFunctionWithDoWhileLoop".to_string(), fn_info);
@@ -523,6 +528,7 @@ fn test_function_with_assign() {
     let mut sts_global = SymbolTable::new();
     let fn_value = SymbolValue::FunctionValue{
         parameters: Vec::new(),
+        variadic: false,
     };
     let fn_info = SymbolInfo::new(DataType::Integer, fn_value);
     sts_global.add("testFunctionWithAssign".to_string(), fn_info);
@@ -624,6 +630,7 @@ fn test_function_with_variable_retrieval() {
     let mut sts_global = SymbolTable::new();
     let fn_value = SymbolValue::FunctionValue{
         parameters: Vec::new(),
+        variadic: false,
     };
     let fn_info = SymbolInfo::new(DataType::Integer, fn_value);
     sts_global.add("testFunctionWithRetrieve".to_string(), fn_info);
@@ -717,6 +724,7 @@ fn test_function_with_reassign() {
     let mut sts_global = SymbolTable::new();
     let fn_value = SymbolValue::FunctionValue{
         parameters: Vec::new(),
+        variadic: false,
     };
     let fn_info = SymbolInfo::new(DataType::Integer, fn_value);
     sts_global.add("testFunctionWithReassign".to_string(), fn_info);
@@ -855,6 +863,7 @@ fn test_function_with_for_loop() {
     let mut sts_global = SymbolTable::new();
     let fn_value = SymbolValue::FunctionValue{
         parameters: Vec::new(),
+        variadic: false,
     };
     let fn_info = SymbolInfo::new(DataType::Integer, fn_value);
     sts_global.add("testForLoop".to_string(), fn_info);
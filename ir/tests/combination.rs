@@ -81,14 +81,17 @@ fn test_triple_function_declaration() {
 
     let fn_value = SymbolValue::FunctionValue{
         parameters: Vec::new(),
+        variadic: false,
     };
     let fn_info = SymbolInfo::new(DataType::Integer, fn_value);
     let fn_value_2 = SymbolValue::FunctionValue{
         parameters: Vec::new(),
+        variadic: false,
     };
     let fn_info_2 = SymbolInfo::new(DataType::Integer, fn_value_2);
     let fn_value_3 = SymbolValue::FunctionValue{
         parameters: Vec::new(),
+        variadic: false,
     };
     let fn_info_3 = SymbolInfo::new(DataType::Integer, fn_value_3);
     sts_global.add("testFunction".to_string(), fn_info);
@@ -232,6 +235,7 @@ fn test_function_with_while_if_else() {
     let mut sts_global = SymbolTable::new();
     let fn_value = SymbolValue::FunctionValue{
         parameters: Vec::new(),
+        variadic: false,
     };
     let fn_info = SymbolInfo::new(DataType::Integer, fn_value);
     sts_global.add("testFunction".to_string(), fn_info);
@@ -442,6 +446,7 @@ fn test_nested_for_loop() {
     let mut sts_global = SymbolTable::new();
     let fn_value = SymbolValue::FunctionValue{
         parameters: Vec::new(),
+        variadic: false,
     };
     let fn_info = SymbolInfo::new(DataType::Integer, fn_value);
     sts_global.add("testForLoopNested".to_string(), fn_info);
@@ -533,6 +538,7 @@ fn test_return_var() {
     let mut sts_global = SymbolTable::new();
     let fn_value = SymbolValue::FunctionValue{
         parameters: Vec::new(),
+        variadic: false,
     };
     let fn_info = SymbolInfo::new(DataType::Integer, fn_value);
     sts_global.add("testFunctionWithRetrieveReturn".to_string(), fn_info);
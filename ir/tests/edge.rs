@@ -208,7 +208,7 @@ fn test_deeply_nested_loops1() {
     let ast = wrap_in_tle(function_ast);
     let mut sts_stack = SymbolTableStack::new();
     let mut sts_global = SymbolTable::new();
-    let fn_value = SymbolValue::FunctionValue { parameters: Vec::new() };
+    let fn_value = SymbolValue::FunctionValue { parameters: Vec::new(), variadic: false };
     let fn_info = SymbolInfo::new(DataType::Integer, fn_value);
     sts_global.add("testDeeplyNestedLoops".to_string(), fn_info);
     sts_stack.push(sts_global);
@@ -391,7 +391,7 @@ fn test_deeply_nested_loops2() {
     let ast = wrap_in_tle(function_ast);
     let mut sts_stack = SymbolTableStack::new();
     let mut sts_global = SymbolTable::new();
-    let fn_value = SymbolValue::FunctionValue { parameters: Vec::new() };
+    let fn_value = SymbolValue::FunctionValue { parameters: Vec::new(), variadic: false };
     let fn_info = SymbolInfo::new(DataType::Integer, fn_value);
     sts_global.add("testSwappedWhileForLoops".to_string(), fn_info);
     sts_stack.push(sts_global);
@@ -565,7 +565,7 @@ fn test_deply_nested_loops3() {
     let ast = wrap_in_tle(function_ast);
     let mut sts_stack = SymbolTableStack::new();
     let mut sts_global = SymbolTable::new();
-    let fn_value = SymbolValue::FunctionValue { parameters: Vec::new() };
+    let fn_value = SymbolValue::FunctionValue { parameters: Vec::new(), variadic: false };
     let fn_info = SymbolInfo::new(DataType::Integer, fn_value);
     sts_global.add("testMultipleDoWhileLoops3".to_string(), fn_info);
     sts_stack.push(sts_global);
@@ -688,6 +688,7 @@ fn test_function_with_while_if_else_break_continue() {
     let mut sts_global = SymbolTable::new();
     let fn_value = SymbolValue::FunctionValue{
         parameters: Vec::new(),
+        variadic: false,
     };
     let fn_info = SymbolInfo::new(DataType::Integer, fn_value);
     sts_global.add("testFunction".to_string(), fn_info);
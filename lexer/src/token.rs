@@ -45,6 +45,8 @@ pub enum Token {
     STRUCT,
     /// An "enum" definition.
     ENUM,
+    /// A "union" definition.
+    UNION,
     /// If conditional.
     IF,
     /// Else branch.
@@ -87,6 +89,8 @@ pub enum Token {
     COLON,
     /// Period `.`.
     DOT,
+    /// Ellipsis `...` ending a variadic parameter list.
+    ELLIPSIS,
 
     // ----- Boolean and Comparison Operators -----
     /// Logical and "&&".
@@ -1,3 +1,5 @@
+//! This file contains basic tests for the lexer, ensuring individual tokens are recognized.
+
 use lexer::{
     core::Lexer,
     token::Token,
@@ -196,6 +198,16 @@ fn test_enum() {
     assert_eq!(result, Ok(expected));
 }
 
+#[test]
+fn test_union() {
+    let input = "union";
+    let result = Lexer::lex(input);
+    let expected = vec![
+        Token::UNION, Token::EOF,
+    ];
+    assert_eq!(result, Ok(expected));
+}
+
 #[test]
 fn test_if() {
     let input = "if";
@@ -326,6 +338,16 @@ fn test_period() {
     assert_eq!(result, Ok(expected));
 }
 
+#[test]
+fn test_ellipsis() {
+    let input = "...";
+    let result = Lexer::lex(input);
+    let expected = vec![
+        Token::ELLIPSIS, Token::EOF,
+    ];
+    assert_eq!(result, Ok(expected));
+}
+
 #[test]
 fn test_lessthan() {
     let input = "<";
@@ -1,3 +1,5 @@
+//! This file contains combination tests for the lexer, which lex sequences of tokens representing common programming concepts.
+
 use common::error::ErrorType;
 use lexer::{
     core::Lexer,
@@ -1,3 +1,5 @@
+//! This file contains edge case tests for the lexer.
+
 use common::error::ErrorType;
 
 use lexer::{
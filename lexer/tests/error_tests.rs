@@ -1,3 +1,5 @@
+//! This file contains tests for the errors reported by the lexer.
+
 use common::error::ErrorType;
 use lexer::core::Lexer;
 
@@ -23,6 +23,8 @@ impl Parser {
 
     /// Parses the initialization of a variable or function. 
     /// Such a statement is characterized by a leading type annotation, representing either the type of the variable or the return type of the function.
+    /// A variable of struct or union type, such as `struct Point p;`, is given an `Identifier` naming the struct or union
+    /// after its `Type`, as in `Variable[Identifier("p"), Type(Struct), Identifier("Point")]`.
     ///
     /// # Returns
     ///
@@ -111,6 +113,9 @@ impl Parser {
     /// return type, parameters, and function body. The resulting AST will include a `FunctionDeclaration`
     /// node containing the function's identifier, parameters, return type, and body.
     ///
+    /// A parameter list ending in an ELLIPSIS token, such as `int sum(int count, ...)`, is variadic: a
+    /// `VariadicParameter` node is added after the last `Parameter` node.
+    ///
     /// # Parameters
     ///
     /// * `identifier_node`: An `ASTNode` representing the function's identifier.
@@ -123,6 +128,7 @@ impl Parser {
     /// # Errors
     ///
     /// * Returns an error if there is a failure in token consumption or block parsing.
+    /// * Returns an error if an ELLIPSIS token is not the last entry of the parameter list, or is the only one.
     pub fn parse_function_declaration(&mut self, identifier_node: ASTNode, return_type_node: ASTNode) -> Result<Option<ASTNode>, Vec<ErrorType>> {
         let _ = identifier_node;
         let _ = return_type_node;
@@ -148,6 +154,12 @@ impl Parser {
     /// including field names and types, enclosed in braces. The resulting AST will include a
     /// `StructDeclaration` node containing the struct's name and its fields as `Field` nodes.
     ///
+    /// Each field is written as its name, a colon and its type, such as `length: int`, and fields are separated
+    /// by commas. A field whose type is followed by a colon and a number, such as `flags: unsigned : 3`, is a
+    /// bit-field, and its `Field` node gets a trailing `BitWidth` child holding the width as a `Literal`. The last
+    /// field may be a flexible array member, whose type is followed by empty brackets, such as `data: char[]`,
+    /// and its `Field` node gets a trailing `FlexibleArray` child.
+    ///
     /// # Returns
     ///
     /// Returns an `Option<ASTNode>` containing the parsed struct declaration node, or an error `Vec<ErrorType>` if parsing fails.
@@ -155,8 +167,24 @@ impl Parser {
     /// # Errors
     ///
     /// * Returns an error if there is a failure in token consumption or if the expected tokens are not found.
+    /// * Returns an error if a flexible array member is not the last field of the struct.
     pub fn parse_struct_declaration(&mut self) -> Result<Option<ASTNode>, Vec<ErrorType>> {
         unimplemented!();
     }
 
+    /// Parses a union declaration. This method expects tokens for the union name and its fields,
+    /// including field names and types, enclosed in braces, exactly as a struct declaration does. The resulting
+    /// AST will include a `UnionDeclaration` node containing the union's name and its fields as `Field` nodes.
+    ///
+    /// # Returns
+    ///
+    /// Returns an `Option<ASTNode>` containing the parsed union declaration node, or an error `Vec<ErrorType>` if parsing fails.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if there is a failure in token consumption or if the expected tokens are not found.
+    pub fn parse_union_declaration(&mut self) -> Result<Option<ASTNode>, Vec<ErrorType>> {
+        unimplemented!();
+    }
+
 }
\ No newline at end of file
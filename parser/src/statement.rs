@@ -44,6 +44,28 @@ impl Parser {
         unimplemented!();
     }
 
+    /// Parses a member access on a struct or union, such as `packet.header`. Creates a top level
+    /// 'NodeType::MemberAccess' ASTNode, with children representing the accessed variable and the member's
+    /// identifier. Is called by 'Parser::parse_identifier' when a DOT token follows the identifier, which
+    /// fullfills the `name_chars` parameter.
+    ///
+    /// # Parameters
+    ///
+    /// * `name_chars`: A vector of characters representing the name of the variable being accessed.
+    ///
+    /// # Returns
+    ///
+    /// Returns an `Option<ASTNode>` representing the parsed member access, or an error
+    /// `Vec<ErrorType>` if parsing fails.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the member name is missing after the DOT token.
+    pub fn parse_member_access(&mut self, name_chars: Vec<char>) -> Result<Option<ASTNode>, Vec<ErrorType>> {
+        let _ = name_chars;
+        unimplemented!();
+    }
+
     /// Entry point for the parsing of a binary expression.
     ///
     /// # Returns
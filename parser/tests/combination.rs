@@ -6,7 +6,7 @@ use common::ast::{
 use lexer::token::Token;
 use parser::core::Parser;
 
-/// ---- Expression Section ---- 
+// ---- Expression Section ---- 
 
 
 /// Test that the parser correctly handles basic binary expressions.
@@ -400,7 +400,7 @@ fn test_binary_expr_with_unary_negation() {
     assert_eq!(ast, expected_ast, "The parsed AST does not match the expected AST.");
 }
 
-/// ---- Assignment Section ----
+// ---- Assignment Section ----
 
 /// Tests the parsing of a simple assignment expression to a number.
 /// This test checks if the parser correctly handles the assignment `x = 3;`,
@@ -741,7 +741,7 @@ fn test_assignment_to_nested_parentheses_expression() {
     assert_eq!(ast, expected_ast, "The parsed AST does not match the expected AST.");
 }
 
-/// ---- Initialization Section ---- 
+// ---- Initialization Section ---- 
 
 /// This test ensures that the parser correctly handles the initialization of a boolean variable without an assigned value.
 /// The input is `boolean x;`, and the expected AST reflects this declaration with the appropriate type and identifier.
@@ -850,7 +850,7 @@ fn test_initialization_parsing_long() {
     assert_eq!(ast, expected_ast);
 }
 
-/// ---- Struct Section ----
+// ---- Struct Section ----
 
 /// This test ensures that the parser correctly handles the declaration of a struct without any fields.
 /// The input is `struct MyStruct {};`, and the expected AST reflects this struct declaration with the appropriate identifier.
@@ -969,7 +969,210 @@ fn test_struct_declaration_with_char_fields() {
     assert_eq!(ast, expected_ast, "The parsed AST does not match the expected AST.");
 }
 
-/// ---- Enum Section ----
+/// This test ensures that the parser correctly handles a struct with bit-fields and a flexible array member.
+/// The input is `struct Packet { flags: unsigned : 3, mode: unsigned : 2, data: char[] };`, and the expected AST
+/// marks the bit-fields with their widths and the trailing array as flexible.
+#[test]
+fn test_struct_declaration_with_bit_fields_and_flexible_array() {
+    let tokens: Vec<Token> = vec![
+        Token::STRUCT,
+        Token::IDENTIFIER(vec!['P', 'a', 'c', 'k', 'e', 't']),
+        Token::LBRACE,
+        Token::IDENTIFIER(vec!['f', 'l', 'a', 'g', 's']),
+        Token::COLON,
+        Token::TUSIGN,
+        Token::COLON,
+        Token::NUMBER(vec!['3']),
+        Token::COMMA,
+        Token::IDENTIFIER(vec!['m', 'o', 'd', 'e']),
+        Token::COLON,
+        Token::TUSIGN,
+        Token::COLON,
+        Token::NUMBER(vec!['2']),
+        Token::COMMA,
+        Token::IDENTIFIER(vec!['d', 'a', 't', 'a']),
+        Token::COLON,
+        Token::TCHAR,
+        Token::LBRACE,
+        Token::RBRACE,
+        Token::RBRACE,
+        Token::SEMICOLON,
+    ];
+
+    let result = Parser::parse(tokens);
+    assert!(result.is_ok(), "Parser should successfully parse the struct declaration without errors.");
+    let ast = result.expect("Failed to parse");
+
+    let mut struct_node = ASTNode::new(NodeType::StructDeclaration);
+    struct_node.add_child(ASTNode::new(NodeType::Identifier("Packet".to_string())));
+
+    let mut flags_width = ASTNode::new(NodeType::BitWidth);
+    flags_width.add_child(ASTNode::new(NodeType::Literal("3".to_string())));
+
+    let mut field_flags = ASTNode::new(NodeType::Field);
+    field_flags.add_child(ASTNode::new(NodeType::Literal("flags".to_string())));
+    field_flags.add_child(ASTNode::new(NodeType::Type(DataType::Unsign)));
+    field_flags.add_child(flags_width);
+
+    let mut mode_width = ASTNode::new(NodeType::BitWidth);
+    mode_width.add_child(ASTNode::new(NodeType::Literal("2".to_string())));
+
+    let mut field_mode = ASTNode::new(NodeType::Field);
+    field_mode.add_child(ASTNode::new(NodeType::Literal("mode".to_string())));
+    field_mode.add_child(ASTNode::new(NodeType::Type(DataType::Unsign)));
+    field_mode.add_child(mode_width);
+
+    let mut field_data = ASTNode::new(NodeType::Field);
+    field_data.add_child(ASTNode::new(NodeType::Literal("data".to_string())));
+    field_data.add_child(ASTNode::new(NodeType::Type(DataType::Char)));
+    field_data.add_child(ASTNode::new(NodeType::FlexibleArray));
+
+    struct_node.add_child(field_flags);
+    struct_node.add_child(field_mode);
+    struct_node.add_child(field_data);
+
+    let mut top_level_expr = ASTNode::new(NodeType::TopLevelExpression);
+    top_level_expr.add_child(struct_node);
+
+    let expected_ast: AST = AST::new(top_level_expr);
+
+    assert_eq!(ast, expected_ast, "The parsed AST does not match the expected AST.");
+}
+
+/// This test ensures that the parser names the struct of a struct-typed variable.
+/// The input is `struct Packet packet;`, and the expected AST gives the `Variable` an `Identifier` naming the
+/// struct after its `Type`, which the type checker uses to look up the fields of `packet`.
+#[test]
+fn test_struct_typed_variable() {
+    let tokens: Vec<Token> = vec![
+        Token::STRUCT,
+        Token::IDENTIFIER(vec!['P', 'a', 'c', 'k', 'e', 't']),
+        Token::IDENTIFIER(vec!['p', 'a', 'c', 'k', 'e', 't']),
+        Token::SEMICOLON,
+    ];
+
+    let result = Parser::parse(tokens);
+    assert!(result.is_ok(), "Parser should successfully parse the variable declaration without errors.");
+    let ast = result.expect("Failed to parse");
+
+    let mut variable_node = ASTNode::new(NodeType::Variable);
+    variable_node.add_child(ASTNode::new(NodeType::Identifier("packet".to_string())));
+    variable_node.add_child(ASTNode::new(NodeType::Type(DataType::Struct)));
+    variable_node.add_child(ASTNode::new(NodeType::Identifier("Packet".to_string())));
+
+    let mut initialization_node = ASTNode::new(NodeType::Initialization);
+    initialization_node.add_child(variable_node);
+
+    let mut top_level_expr = ASTNode::new(NodeType::TopLevelExpression);
+    top_level_expr.add_child(initialization_node);
+
+    let expected_ast: AST = AST::new(top_level_expr);
+
+    assert_eq!(ast, expected_ast, "The parsed AST does not match the expected AST.");
+}
+
+// ---- Union Section ----
+
+/// This test ensures that the parser correctly handles the declaration of a union without any fields.
+/// The input is `union MyUnion {};`, and the expected AST reflects this union declaration with the appropriate identifier.
+#[test]
+fn test_union_declaration_empty() {
+    let tokens: Vec<Token> = vec![
+        Token::UNION,
+        Token::IDENTIFIER(vec!['M', 'y', 'U', 'n', 'i', 'o', 'n']),
+        Token::LBRACE,
+        Token::RBRACE,
+        Token::SEMICOLON,
+    ];
+
+    let result = Parser::parse(tokens);
+    assert!(result.is_ok(), "Parser should successfully parse the union declaration without errors.");
+    let ast = result.expect("Failed to parse");
+
+    let mut union_node = ASTNode::new(NodeType::UnionDeclaration);
+    union_node.add_child(ASTNode::new(NodeType::Identifier("MyUnion".to_string())));
+
+    let mut top_level_expr = ASTNode::new(NodeType::TopLevelExpression);
+    top_level_expr.add_child(union_node);
+
+    let expected_ast: AST = AST::new(top_level_expr);
+
+    assert_eq!(ast, expected_ast, "The parsed AST does not match the expected AST.");
+}
+
+/// This test ensures that the parser correctly handles the declaration of a union with fields of different types.
+/// The input is `union Value { i: int, d: double };`, and the expected AST reflects this union declaration with the appropriate identifier and fields.
+#[test]
+fn test_union_declaration_with_fields() {
+    let tokens: Vec<Token> = vec![
+        Token::UNION,
+        Token::IDENTIFIER(vec!['V', 'a', 'l', 'u', 'e']),
+        Token::LBRACE,
+        Token::IDENTIFIER(vec!['i']),
+        Token::COLON,
+        Token::TINTEGER,
+        Token::COMMA,
+        Token::IDENTIFIER(vec!['d']),
+        Token::COLON,
+        Token::TDOUBLE,
+        Token::RBRACE,
+        Token::SEMICOLON,
+    ];
+
+    let result = Parser::parse(tokens);
+    assert!(result.is_ok(), "Parser should successfully parse the union declaration without errors.");
+    let ast = result.expect("Failed to parse");
+
+    let mut union_node = ASTNode::new(NodeType::UnionDeclaration);
+    union_node.add_child(ASTNode::new(NodeType::Identifier("Value".to_string())));
+
+    let mut field_i = ASTNode::new(NodeType::Field);
+    field_i.add_child(ASTNode::new(NodeType::Literal("i".to_string())));
+    field_i.add_child(ASTNode::new(NodeType::Type(DataType::Integer)));
+
+    let mut field_d = ASTNode::new(NodeType::Field);
+    field_d.add_child(ASTNode::new(NodeType::Literal("d".to_string())));
+    field_d.add_child(ASTNode::new(NodeType::Type(DataType::Double)));
+
+    union_node.add_child(field_i);
+    union_node.add_child(field_d);
+
+    let mut top_level_expr = ASTNode::new(NodeType::TopLevelExpression);
+    top_level_expr.add_child(union_node);
+
+    let expected_ast: AST = AST::new(top_level_expr);
+
+    assert_eq!(ast, expected_ast, "The parsed AST does not match the expected AST.");
+}
+
+/// This test ensures that the parser correctly handles access to a union member.
+/// The input is `value.d;`, and the expected AST reflects a member access on `value`.
+#[test]
+fn test_union_member_access() {
+    let tokens: Vec<Token> = vec![
+        Token::IDENTIFIER(vec!['v', 'a', 'l', 'u', 'e']),
+        Token::DOT,
+        Token::IDENTIFIER(vec!['d']),
+        Token::SEMICOLON,
+    ];
+
+    let result = Parser::parse(tokens);
+    assert!(result.is_ok(), "Parser should successfully parse the member access without errors.");
+    let ast = result.expect("Failed to parse");
+
+    let mut member_access_node = ASTNode::new(NodeType::MemberAccess);
+    member_access_node.add_child(ASTNode::new(NodeType::Identifier("value".to_string())));
+    member_access_node.add_child(ASTNode::new(NodeType::Identifier("d".to_string())));
+
+    let mut top_level_expr = ASTNode::new(NodeType::TopLevelExpression);
+    top_level_expr.add_child(member_access_node);
+
+    let expected_ast: AST = AST::new(top_level_expr);
+
+    assert_eq!(ast, expected_ast, "The parsed AST does not match the expected AST.");
+}
+
+// ---- Enum Section ----
 
 /// This test checks the parser's ability to correctly parse an empty enum declaration.
 /// The input tokens represent `enum MyEnum {};` and the expected AST should reflect
@@ -1045,7 +1248,7 @@ fn test_enum_declaration_with_variants() {
     assert_eq!(ast, expected_ast, "The parsed AST does not match the expected AST.");
 }
 
-/// ---- Function Section ----
+// ---- Function Section ----
 
 /// This test checks the parser's ability to correctly parse a simple function declaration without parameters.
 /// The input tokens represent `void my_func() {}` and the expected AST should reflect this structure with an empty block.
@@ -1134,6 +1337,46 @@ fn test_function_with_parameters_and_return_type() {
     assert_eq!(ast, expected_ast);
 }
 
+/// This test checks the parser's ability to correctly parse a variadic function declaration.
+/// The input tokens represent `int sum(int count, ...) {}` and the expected AST should mark the
+/// parameter list as variadic after its last parameter.
+#[test]
+fn test_variadic_function_declaration() {
+    let tokens: Vec<Token> = vec![
+        Token::TINTEGER,
+        Token::IDENTIFIER(vec!['s', 'u', 'm']),
+        Token::LPAREN,
+        Token::TINTEGER,
+        Token::IDENTIFIER(vec!['c', 'o', 'u', 'n', 't']),
+        Token::COMMA,
+        Token::ELLIPSIS,
+        Token::RPAREN,
+        Token::LBRACKET,
+        Token::RBRACKET,
+        Token::EOF,
+    ];
+    let ast: AST = Parser::parse(tokens).expect("Failed to parse");
+
+    let mut function_declaration_node = ASTNode::new(NodeType::FunctionDeclaration);
+    function_declaration_node.add_child(ASTNode::new(NodeType::Identifier("sum".to_string())));
+
+    let mut parameter_count_node = ASTNode::new(NodeType::Parameter);
+    parameter_count_node.add_child(ASTNode::new(NodeType::Identifier("count".to_string())));
+    parameter_count_node.add_child(ASTNode::new(NodeType::Type(DataType::Integer)));
+
+    function_declaration_node.add_child(parameter_count_node);
+    function_declaration_node.add_child(ASTNode::new(NodeType::VariadicParameter));
+    function_declaration_node.add_child(ASTNode::new(NodeType::Type(DataType::Integer)));
+    function_declaration_node.add_child(ASTNode::new(NodeType::BlockExpression));
+
+    let mut top_level_expr = ASTNode::new(NodeType::TopLevelExpression);
+    top_level_expr.add_child(function_declaration_node);
+
+    let expected_ast: AST = AST::new(top_level_expr);
+
+    assert_eq!(ast, expected_ast);
+}
+
 /// This test checks the parser's ability to correctly parse a function with a body containing variable initialization.
 /// The input tokens represent `void test() { int x = 1; }` and the expected AST should reflect this structure with the
 /// initialization of variable `x` inside the function body.
@@ -1288,7 +1531,7 @@ fn test_function_with_if_else_statement() {
     assert_eq!(ast, expected_ast, "The parsed AST does not match the expected AST.");
 }
 
-/// ---- Control Flow Section ----
+// ---- Control Flow Section ----
 
 
 /// This test checks the parser's ability to correctly parse a for-loop statement.
@@ -1,3 +1,5 @@
+//! This file contains edge case tests for the parser.
+
 use common::ast::{
     core::{ASTNode, AST}, data_type::DataType, node_type::NodeType
 };
@@ -0,0 +1,51 @@
+//! This file contains round-trip tests for the pretty-printer: source is lexed, parsed, printed and parsed
+//! again, and both trees must be identical.
+
+use common::ast::core::AST;
+use lexer::core::Lexer;
+use parser::core::Parser;
+
+/// Lexes and parses a source string into an `AST`.
+fn parse_source(source: &str) -> AST {
+    let tokens = Lexer::lex(source).expect("Failed to lex");
+    Parser::parse(tokens).expect("Failed to parse")
+}
+
+/// Checks that printing the parsed `AST` of a source string and parsing it again yields the same tree.
+fn assert_round_trip(source: &str) {
+    let ast = parse_source(source);
+    let printed = ast.to_c_source().expect("Failed to print");
+    let reparsed = parse_source(&printed);
+    assert_eq!(ast, reparsed, "Round trip changed the tree, printed source was:\n{}", printed);
+}
+
+/// Tests that expressions keep their grouping through a round trip.
+#[test]
+fn test_round_trip_expressions() {
+    assert_round_trip("int x = (a + b) * c;");
+    assert_round_trip("int y = a - (b - c) / -d;");
+    assert_round_trip("bool z = !(a && b) || c;");
+}
+
+/// Tests that a function with control flow survives a round trip.
+#[test]
+fn test_round_trip_function() {
+    assert_round_trip(
+        "int max(int a, int b) { if (a > b) { return a; } else if (a == b) { return 0; } else { return b; } }",
+    );
+}
+
+/// Tests that loops and switch statements survive a round trip.
+#[test]
+fn test_round_trip_loops() {
+    assert_round_trip("void f() { for (int i = 0; i < 10; i++) { sum += i; } while (x > 0) { x = x - 1; } }");
+    assert_round_trip("void g(int x) { switch (x) { case 1: y = 2; break; default: y = 3; } }");
+}
+
+/// Tests that aggregate declarations survive a round trip. Their bodies are written in the parser's grammar,
+/// between the `[` and `]` the lexer reads as `LBRACE` and `RBRACE`, with `name: type` fields.
+#[test]
+fn test_round_trip_declarations() {
+    assert_round_trip("struct Packet [ flags: int : 3, data: char[] ]; enum Color [ RED, GREEN ];");
+    assert_round_trip("struct Packet packet; union Value value;");
+}
@@ -0,0 +1,238 @@
+//! The `sicc` command-line driver. It loads a C source file, runs it through the lexer, parser, symbol
+//! table stack generation, name resolver and type checker, and reports any errors, along with the warnings
+//! asked for by `--warn-shadowing`, on standard error, either rendered for people or, with
+//! `--error-format=json|sarif`, as JSON lines or a SARIF document. `--emit=sts` also prints the symbol table
+//! stack of the file on standard output. `sicc --explain E0xxx` prints the long-form explanation of an error
+//! code instead, and `sicc fix` applies the machine-applicable fix-its of the diagnostics to the source files,
+//! or prints them as a unified diff with `--diff`.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use clap::{Parser as CommandLine, Subcommand, ValueEnum};
+use common::{
+    diagnostic::Diagnostic,
+    emit::{to_json_lines, to_sarif, ErrorFormat},
+    error::ErrorType,
+    explain::explain,
+    fix::{apply_fixes, unified_diff, FixId},
+    render::{ColorChoice, Renderer},
+    source::{FileId, SourceManager},
+};
+use lexer::core::Lexer;
+use parser::core::Parser;
+use sts::{core::SymbolTableStack, resolve::NameResolver, types::TypeChecker};
+
+/// The command-line arguments of `sicc`.
+#[derive(CommandLine, Debug)]
+#[command(name = "sicc", version, about = "The Simple Instructional C99 Compiler")]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
+struct Arguments {
+    /// A mode other than compiling.
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// The C source file to compile.
+    #[arg(required_unless_present = "explain")]
+    file: Option<PathBuf>,
+
+    /// Print the explanation of an error code, such as E0001, and exit.
+    #[arg(long, value_name = "CODE", conflicts_with = "file")]
+    explain: Option<String>,
+
+    /// When to colour diagnostics: auto, always or never.
+    #[arg(long, value_name = "WHEN", default_value = "auto", global = true)]
+    color: ColorChoice,
+
+    /// How to report diagnostics: human, json or sarif.
+    #[arg(long, value_name = "FORMAT", default_value = "human", global = true)]
+    error_format: ErrorFormat,
+
+    /// Warn about declarations that hide a name of an enclosing scope.
+    #[arg(long, global = true)]
+    warn_shadowing: bool,
+
+    /// Print an intermediate result of compiling on standard output.
+    #[arg(long, value_name = "KIND")]
+    emit: Option<Emit>,
+}
+
+/// The intermediate results `sicc` can print.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum Emit {
+    /// The symbol table stack, one table per scope.
+    Sts,
+}
+
+/// The modes of `sicc` other than compiling.
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Apply the machine-applicable fix-its of the diagnostics to the source files.
+    Fix {
+        /// The C source file to fix.
+        file: PathBuf,
+
+        /// Print the fixes as a unified diff instead of applying them.
+        #[arg(long)]
+        diff: bool,
+    },
+}
+
+fn main() -> ExitCode {
+    let arguments = Arguments::parse();
+    if let Some(code) = &arguments.explain {
+        return explain_code(code, &arguments);
+    }
+    if let Some(Command::Fix { file, diff }) = &arguments.command {
+        return fix(file, *diff, &arguments);
+    }
+
+    let mut sources = SourceManager::new();
+    let path = arguments.file.clone().unwrap_or_default();
+    let (Ok(diagnostics) | Err(diagnostics)) = compile(&path, &mut sources, &arguments);
+    if diagnostics.is_empty() {
+        return ExitCode::SUCCESS;
+    }
+    report(&diagnostics, &sources, &arguments)
+}
+
+/// Reports diagnostics on standard error in the format chosen by `--error-format`.
+///
+/// # Parameters
+/// * `diagnostics` - The diagnostics to report.
+/// * `sources` - The files their spans refer to.
+/// * `arguments` - The command-line arguments.
+///
+/// # Returns
+/// Returns failure if any diagnostic is an error.
+fn report(diagnostics: &[Diagnostic], sources: &SourceManager, arguments: &Arguments) -> ExitCode {
+    match arguments.error_format {
+        ErrorFormat::Human => {
+            let renderer = Renderer::new(sources, arguments.color.should_color_stderr());
+            eprint!("{}", renderer.render_all(diagnostics));
+        },
+        ErrorFormat::Json => eprint!("{}", to_json_lines(diagnostics, sources)),
+        ErrorFormat::Sarif => eprintln!("{}", to_sarif(diagnostics, sources, env!("CARGO_PKG_VERSION"))),
+    }
+    if diagnostics.iter().any(Diagnostic::is_error) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Compiles a file and applies the machine-applicable fix-its of its diagnostics, either by rewriting the
+/// files in place or by printing a unified diff on standard output. Every diagnostic whose fix did not end up
+/// in the files, because it was skipped, only shown as a diff, or its file could not be written, is reported
+/// as usual.
+///
+/// # Parameters
+/// * `path` - The C source file to fix.
+/// * `diff` - Whether to print a diff instead of rewriting the files.
+/// * `arguments` - The command-line arguments.
+///
+/// # Returns
+/// Returns failure if a file could not be written or an error remains in the files.
+fn fix(path: &Path, diff: bool, arguments: &Arguments) -> ExitCode {
+    let mut sources = SourceManager::new();
+    let (Ok(diagnostics) | Err(diagnostics)) = compile(path, &mut sources, arguments);
+    if diagnostics.is_empty() {
+        return ExitCode::SUCCESS;
+    }
+    let (fixed, applied) = match apply_fixes(&diagnostics, &sources) {
+        Ok(result) => result,
+        Err(error) => return report(&to_diagnostics(error), &sources, arguments),
+    };
+
+    let mut unwritten = Vec::new();
+    let mut failures = Vec::new();
+    for (file, text) in fixed {
+        let (name, old) = match sources.get_file(file) {
+            Some(source) => (source.get_name(), source.get_text()),
+            None => continue,
+        };
+        if diff {
+            print!("{}", unified_diff(name, old, &text));
+        } else if let Err(error) = fs::write(name, text) {
+            unwritten.push(file);
+            failures.push(Diagnostic::error(&format!("couldn't write `{}`: {}", name, error)));
+        }
+    }
+
+    // A diagnostic is settled only once one of its fixes is in the files on disk; a diff changes nothing.
+    let settled: Vec<usize> = applied.iter()
+        .filter(|_| !diff)
+        .filter(|id| diagnostics[id.get_diagnostic()].get_fixes()[id.get_fix()].get_edits().iter()
+            .all(|edit| !unwritten.contains(&edit.get_span().get_file())))
+        .map(FixId::get_diagnostic)
+        .collect();
+    let mut remaining: Vec<Diagnostic> = diagnostics.into_iter().enumerate()
+        .filter(|(index, _)| !settled.contains(index))
+        .map(|(_, diagnostic)| diagnostic)
+        .collect();
+    remaining.extend(failures);
+    if remaining.is_empty() {
+        return ExitCode::SUCCESS;
+    }
+    report(&remaining, &sources, arguments)
+}
+
+/// Prints the explanation of an error code on standard output, or an error if the code is unknown.
+///
+/// # Parameters
+/// * `code` - The code to explain.
+/// * `arguments` - The command-line arguments, for the colour of the error.
+///
+/// # Returns
+/// Returns success if the code has an explanation.
+fn explain_code(code: &str, arguments: &Arguments) -> ExitCode {
+    match explain(code) {
+        Some(explanation) => {
+            print!("{}", explanation);
+            ExitCode::SUCCESS
+        },
+        None => {
+            let sources = SourceManager::new();
+            let diagnostic = Diagnostic::error(&format!("`{}` is not a valid error code", code))
+                .with_help("error codes have the form E0001");
+            eprint!("{}", Renderer::new(&sources, arguments.color.should_color_stderr()).render(&diagnostic));
+            ExitCode::FAILURE
+        },
+    }
+}
+
+/// Runs the front end on the input file.
+///
+/// # Parameters
+/// * `path` - The C source file to compile.
+/// * `sources` - The source manager the input file is loaded into.
+/// * `arguments` - The command-line arguments, for the warnings to report and what to emit.
+///
+/// # Returns
+/// Returns the warnings if every phase succeeded, or the warnings of the phases that ran followed by the
+/// diagnostics of the first phase that failed.
+fn compile(path: &Path, sources: &mut SourceManager, arguments: &Arguments) -> Result<Vec<Diagnostic>, Vec<Diagnostic>> {
+    let file: FileId = sources.load(path).map_err(to_diagnostics)?;
+    let text = sources.get_file(file).map(|source| source.get_text().to_string()).unwrap_or_default();
+
+    let tokens = Lexer::lex(&text).map_err(to_diagnostics)?;
+    let ast = Parser::parse(tokens).map_err(to_diagnostics)?;
+    let (ast, sts) = SymbolTableStack::gen_sym_table_stack(ast).map_err(to_diagnostics)?;
+    if arguments.emit == Some(Emit::Sts) {
+        print!("{}", sts);
+    }
+    let mut warnings = NameResolver::check(&ast, &sts, arguments.warn_shadowing).map_err(to_diagnostics)?;
+    if let Err(errors) = TypeChecker::check(&ast, &sts) {
+        warnings.extend(to_diagnostics(errors));
+        return Err(warnings);
+    }
+    Ok(warnings)
+}
+
+/// Maps the errors reported by a phase onto diagnostics.
+fn to_diagnostics(errors: impl Into<Vec<ErrorType>>) -> Vec<Diagnostic> {
+    errors.into().into_iter().map(Diagnostic::from).collect()
+}
@@ -1,22 +1,30 @@
 //! This file contains the core definitions and functionalities of the SymbolTableStack (STS).
 
-use std::{collections::{HashMap, HashSet}, fmt};
+use std::{collections::{BTreeMap, HashMap, HashSet}, fmt};
 use common::{
     ast::{
+        arena::{ArenaAST, NodeId},
         core::{ASTNode, AST}, 
         data_type::DataType,
+        node_type::NodeType,
+        printer::type_name,
+        validate::check_ast,
     }, 
-    error::ErrorType
+    diagnostic::Diagnostic,
+    error::ErrorType,
+    suggest::best_match,
 };
 
 /// Initialized values in a scope.
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SymbolTable {
     values: HashMap<String, SymbolInfo>,
 }
 
 /// Types of symbol values in a symbol table.
 #[derive(Clone, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SymbolValue {
     /// No expression
     #[default]
@@ -32,18 +40,31 @@ pub enum SymbolValue {
     StructValue { 
         /// Fields of the struct
         fields: Vec<(String, DataType)>,
+        /// Widths in bits of the fields declared as bit-fields
+        bit_widths: Vec<(String, u32)>,
+        /// Trailing flexible array member and its element type, if any
+        flexible_array: Option<(String, DataType)>,
+    },
+
+    /// A union's value (fields sharing one storage location)
+    UnionValue {
+        /// Fields of the union
+        fields: Vec<(String, DataType)>,
     },
     
     /// A function's value (params, return type)
     FunctionValue { 
         /// Parameters of the function
         parameters: Vec<(String, DataType)>, 
+        /// Whether the function accepts further arguments after its parameters (`...`)
+        variadic: bool,
 
     },
 }
 
 /// Information on a symbol in a symboltable.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SymbolInfo {
     data_type: DataType,
     value: SymbolValue,
@@ -131,6 +152,18 @@ impl SymbolTable {
         self.values.get(name)
     }
 
+    /// Retrieves the names of the symbols in the table.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<&str>` - The names of the symbols, sorted.
+    ///
+    pub fn get_names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.values.keys().map(String::as_str).collect();
+        names.sort_unstable();
+        names
+    }
+
     /// Checks if the symbol table is empty
     ///
     /// # Returns
@@ -145,22 +178,63 @@ impl SymbolTable {
 impl fmt::Debug for SymbolTable {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut builder = f.debug_map();
-        for (key, value) in &self.values {
-            builder.entry(&key, &value);
+        for name in self.get_names() {
+            builder.entry(&name, &self.values[name]);
         }
         builder.finish()
     }
 }
 
+/// A handle to a scope of a `SymbolTableStack`, which is the index of its table in the stack.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScopeId(usize);
+
+impl ScopeId {
+    /// Retrieves the index of this scope's table in its stack.
+    ///
+    /// # Returns
+    ///
+    /// Returns the `usize` index of the table.
+    pub fn get_index(&self) -> usize {
+        self.0
+    }
+}
+
+/// The place of a scope among the scopes of a `SymbolTableStack`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Scope {
+    /// The scope enclosing this one, if any.
+    parent: Option<ScopeId>,
+    /// The AST node that introduced this scope, if it was opened for one.
+    node: Option<NodeId>,
+    /// The kind of that node, with the name it declares if any, such as "FunctionDeclaration `main`".
+    construct: Option<String>,
+}
+
 /// A stack of symbol tables, used to represent different levels of scopes for an AST's symbols.
+///
+/// Every table is a scope, identified by a `ScopeId` and linked to the scope enclosing it. A scope opened for
+/// an AST node, such as a function or a block, is recorded against the node's `NodeId` in the `ArenaAST` built
+/// from the tree with `ArenaAST::from_ast`, so later phases can enter the scope of a node directly.
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SymbolTableStack {
     elements: Vec<SymbolTable>,
+    scopes: Vec<Scope>,
+    nodes: BTreeMap<NodeId, ScopeId>,
+    current: Option<ScopeId>,
 }
 
 impl SymbolTableStack {
     /// Drives the symbol table stack generation process.
     ///
+    /// The file scope is an outermost table without a node. Each scope-introducing node, a `for` loop or a
+    /// block, is given its own scope with `open_scope`, keyed by its `NodeId` in `ArenaAST::from_ast` of the
+    /// input, and the scope is closed with `close_scope` once the node is done, so sibling blocks such as the
+    /// branches of an `if` or the cases of a `switch` share the enclosing scope as their parent.
+    ///
     /// # Parameters
     ///
     /// - `ast`: An `AST` instance to generate the symbol table stack for.
@@ -174,6 +248,26 @@ impl SymbolTableStack {
         unimplemented!()
     }
 
+    /// Validates the `AST` against the child-ordering conventions before generating its symbol table stack,
+    /// so that a malformed hand-built tree is reported with the path to the offending node instead of failing
+    /// partway through generation.
+    ///
+    /// # Parameters
+    ///
+    /// - `ast`: An `AST` instance to validate and generate the symbol table stack for.
+    ///
+    /// # Returns
+    ///
+    /// Returns the result of `gen_sym_table_stack` if the `AST` is valid.
+    ///
+    /// # Errors
+    ///
+    /// - Returns an `ErrorType::InvalidAST` for every node that breaks the conventions, without generating.
+    pub fn gen_sym_table_stack_validated(ast: AST) -> Result<(AST, SymbolTableStack), Vec<ErrorType>> {
+        check_ast(&ast)?;
+        SymbolTableStack::gen_sym_table_stack(ast)
+    }
+
     /// Routes the generation of the SymbolTableStack based on the type of node encountered.
     /// 
     /// # Parameters
@@ -205,17 +299,166 @@ impl SymbolTableStack {
     pub fn new() -> Self {
         SymbolTableStack {
             elements: Vec::new(),
+            scopes: Vec::new(),
+            nodes: BTreeMap::new(),
+            current: None,
         }
     }
 
-    /// Pushes a new table onto the stack.
+    /// Pushes a new table onto the stack, as a scope inside the current one, and makes it the current scope.
     /// 
     /// # Parameters
     ///
     /// - `item`: A `SymbolTable` to be pushed onto the stack.
     ///
     pub fn push(&mut self, item: SymbolTable) {
-        self.elements.push(item);
+        self.add_scope(item, None);
+    }
+
+    /// Pops the table on top of the stack. If it was the current scope, the scope enclosing it becomes current.
+    /// 
+    /// # Returns
+    ///
+    /// Returns the `SymbolTable` that was on top of the stack, or `None` if the stack is empty.
+    ///
+    pub fn pop(&mut self) -> Option<SymbolTable> {
+        let table = self.elements.pop()?;
+        let scope = self.scopes.pop()?;
+        let id = ScopeId(self.elements.len());
+        if let Some(node) = scope.node {
+            self.nodes.remove(&node);
+        }
+        if self.current == Some(id) {
+            self.current = scope.parent;
+        }
+        Some(table)
+    }
+
+    /// Opens a new, empty scope for an AST node inside the current scope and makes it the current scope.
+    ///
+    /// # Parameters
+    ///
+    /// - `ast`: The `ArenaAST` holding the node, from which the construct opening the scope is recorded.
+    /// - `node`: The `NodeId` of the node introducing the scope, such as a function or a block.
+    ///
+    /// # Returns
+    ///
+    /// Returns the `ScopeId` of the new scope.
+    pub fn open_scope(&mut self, ast: &ArenaAST, node: NodeId) -> ScopeId {
+        let node_type = ast.get_node_type(node);
+        let construct = match ast.get_children(node).first().map(|&name| ast.get_node_type(name)) {
+            Some(NodeType::Identifier(name)) => format!("{} `{}`", node_type.get_kind_name(), name),
+            _ => node_type.get_kind_name().to_string(),
+        };
+        self.add_scope(SymbolTable::new(), Some((node, construct)))
+    }
+
+    /// Makes the scope of an AST node the current scope, as when a later phase reaches the node.
+    ///
+    /// # Parameters
+    ///
+    /// - `node`: The `NodeId` of the node whose scope is entered.
+    ///
+    /// # Returns
+    ///
+    /// Returns the `ScopeId` of the node's scope.
+    ///
+    /// # Errors
+    ///
+    /// - Returns an `ErrorType::DevError` if no scope was opened for the node.
+    pub fn enter_scope(&mut self, node: NodeId) -> Result<ScopeId, ErrorType> {
+        let scope = self.get_scope(node).ok_or_else(|| ErrorType::DevError {
+            message: format!("No scope was opened for node {}", node.get_index()),
+        })?;
+        self.current = Some(scope);
+        Ok(scope)
+    }
+
+    /// Closes the current scope, making the scope enclosing it current. The closed scope's table is kept, so
+    /// that it can be entered again with `enter_scope`.
+    ///
+    /// # Errors
+    ///
+    /// - Returns an `ErrorType::DevError` if no scope is open.
+    pub fn close_scope(&mut self) -> Result<(), ErrorType> {
+        let scope = self.current.ok_or_else(|| ErrorType::DevError { message: "No scope is open".to_string() })?;
+        self.current = self.get_parent(scope);
+        Ok(())
+    }
+
+    /// Retrieves the scope introduced by an AST node.
+    ///
+    /// # Parameters
+    ///
+    /// - `node`: The `NodeId` of the node.
+    ///
+    /// # Returns
+    ///
+    /// Returns the `ScopeId` of the node's scope, or `None` if the node did not introduce one.
+    pub fn get_scope(&self, node: NodeId) -> Option<ScopeId> {
+        self.nodes.get(&node).copied()
+    }
+
+    /// Retrieves the current scope, in which elements are added and from which names are looked up.
+    ///
+    /// # Returns
+    ///
+    /// Returns the `ScopeId` of the current scope, or `None` if the stack is empty or every scope is closed.
+    pub fn get_current_scope(&self) -> Option<ScopeId> {
+        self.current
+    }
+
+    /// Retrieves the scope enclosing another.
+    ///
+    /// # Parameters
+    ///
+    /// - `scope`: The `ScopeId` of the inner scope.
+    ///
+    /// # Returns
+    ///
+    /// Returns the `ScopeId` of the enclosing scope, or `None` for an outermost scope.
+    pub fn get_parent(&self, scope: ScopeId) -> Option<ScopeId> {
+        self.scopes.get(scope.0).and_then(|scope| scope.parent)
+    }
+
+    /// Retrieves the table of a scope.
+    ///
+    /// # Parameters
+    ///
+    /// - `scope`: The `ScopeId` of the scope.
+    ///
+    /// # Returns
+    ///
+    /// Returns a reference to the scope's `SymbolTable`, or `None` if the scope is not in this stack.
+    pub fn get_table(&self, scope: ScopeId) -> Option<&SymbolTable> {
+        self.elements.get(scope.0)
+    }
+
+    /// Looks a name up from the current scope, walking the enclosing scopes from the inside out.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name of the symbol.
+    ///
+    /// # Returns
+    ///
+    /// Returns the `SymbolInfo` of the innermost symbol with the name, or `None` if it is not visible.
+    pub fn lookup(&self, name: &str) -> Option<&SymbolInfo> {
+        self.visible_tables(self.current).find_map(|table| table.get(name))
+    }
+
+    /// Looks a name up as seen from a given scope, walking the scopes enclosing it from the inside out.
+    ///
+    /// # Parameters
+    ///
+    /// - `scope`: The `ScopeId` of the scope to look from, such as the scope of an AST node.
+    /// - `name`: The name of the symbol.
+    ///
+    /// # Returns
+    ///
+    /// Returns the `SymbolInfo` of the innermost symbol with the name, or `None` if it is not visible there.
+    pub fn lookup_in_scope(&self, scope: ScopeId, name: &str) -> Option<&SymbolInfo> {
+        self.visible_tables(Some(scope)).find_map(|table| table.get(name))
     }
 
     /// Retrieves the size of the stack.
@@ -238,7 +481,11 @@ impl SymbolTableStack {
         &self.elements
     }
 
-    /// Adds an element to the symbol table at the specified index (or global scope by default).
+    /// Adds an element to the symbol table of the current scope.
+    ///
+    /// A function may be declared again with the same signature, as a prototype followed by its definition,
+    /// even if its parameters are named differently; any other name already declared in the current scope is
+    /// a redeclaration.
     /// 
     /// # Parameters
     ///
@@ -247,16 +494,25 @@ impl SymbolTableStack {
     /// 
     /// # Returns
     ///
-    /// Returns a `Result<(), Vec<ErrorType>>` with Ok indicating the process was successful or
+    /// Returns a `Result<(), ErrorType>` with Ok indicating the process was successful or
     /// Err containing an error encountered if any were encountered.
+    ///
+    /// # Errors
+    ///
+    /// - Returns an `ErrorType::Redeclaration` describing the first declaration if the name is already
+    ///   declared in the current scope, whose table is left unchanged.
+    /// - Returns an `ErrorType::DevError` if no scope is open.
     pub fn add_element(&mut self, name: String, info: SymbolInfo) -> Result<(), ErrorType> {
-        let index = self.size() - 1;
-        if let Some(table) = self.elements.get_mut(index) {
-            table.add(name, info);
-            Ok(())
-        } else {
-            Err(ErrorType::DevError { message: "Scope index out of range".to_string() })
+        let Some(table) = self.current.and_then(|scope| self.elements.get_mut(scope.0)) else {
+            return Err(ErrorType::DevError { message: "Scope index out of range".to_string() });
+        };
+        if let Some(previous) = table.get(&name) {
+            if !same_signature(previous, &info) {
+                return Err(ErrorType::Redeclaration { previous: describe(previous), name, path: None });
+            }
         }
+        table.add(name, info);
+        Ok(())
     }
 
     /// Returns the `SymbolTable` in the symbol table stack at a given index.
@@ -267,37 +523,322 @@ impl SymbolTableStack {
     /// 
     /// # Returns
     ///
-    /// Returns a `Result<SymbolTable, Vec<ErrorType>>` with Ok containing a clone of the desired 
-    /// `SymbolTable` if the process was successful or Err containing an error encountered if 
-    /// any were encountered.
-    pub fn get_element(&self, index: usize) -> Result<SymbolTable, ErrorType> {
-        if index < self.elements.len() {
-            match self.elements.get(index) {
-                Some(sts) => {
-                    return Ok(sts.clone());
-                },
-                None => {
-                    return Err(ErrorType::DevError{ message: "No element found".to_string()});
+    /// Returns a `Result<&SymbolTable, ErrorType>` with Ok containing a reference to the desired 
+    /// `SymbolTable` if the index is in range.
+    ///
+    /// # Errors
+    ///
+    /// - Returns an `ErrorType::DevError` if the index is out of range.
+    pub fn get_element(&self, index: usize) -> Result<&SymbolTable, ErrorType> {
+        self.elements.get(index).ok_or_else(|| ErrorType::DevError {
+            message: format!("Invalid index: {} for size {}", index, self.elements.len()),
+        })
+    }
+
+    /// Suggests a visible variable, function or enum variant with a name close to one that was not found.
+    /// Struct, union and enum names are not suggested, as they name types rather than values.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name that was not found.
+    ///
+    /// # Returns
+    ///
+    /// Returns the closest visible name, preferring inner scopes, or `None` if none is close enough.
+    pub fn suggest_variable(&self, name: &str) -> Option<String> {
+        let mut candidates = Vec::new();
+        for (symbol, info) in self.visible_symbols() {
+            match &info.value {
+                SymbolValue::NoAssociatedValue | SymbolValue::FunctionValue { .. } => candidates.push(symbol),
+                SymbolValue::EnumValue { variants } => candidates.extend(variants.iter().map(String::as_str)),
+                SymbolValue::StructValue { .. } | SymbolValue::UnionValue { .. } => {},
+            }
+        }
+        best_match(name, candidates).map(str::to_string)
+    }
+
+    /// Suggests a visible function with a name close to one that was not found.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The function name that was not found.
+    ///
+    /// # Returns
+    ///
+    /// Returns the closest visible function name, or `None` if none is close enough.
+    pub fn suggest_function(&self, name: &str) -> Option<String> {
+        let candidates = self.visible_symbols().into_iter()
+            .filter(|(_, info)| matches!(info.value, SymbolValue::FunctionValue { .. }))
+            .map(|(symbol, _)| symbol);
+        best_match(name, candidates).map(str::to_string)
+    }
+
+    /// Suggests a field of a struct or union with a name close to one that was not found.
+    ///
+    /// # Parameters
+    ///
+    /// - `record`: The name of the struct or union, resolved from the current scope outwards.
+    /// - `field`: The field name that was not found.
+    ///
+    /// # Returns
+    ///
+    /// Returns the closest field name, or `None` if `record` is not a visible struct or union or no field
+    /// is close enough.
+    pub fn suggest_field(&self, record: &str, field: &str) -> Option<String> {
+        let fields = match &self.lookup(record)?.value {
+            SymbolValue::StructValue { fields, flexible_array, .. } => fields.iter()
+                .chain(flexible_array.iter())
+                .map(|(name, _)| name.as_str())
+                .collect::<Vec<&str>>(),
+            SymbolValue::UnionValue { fields } => fields.iter().map(|(name, _)| name.as_str()).collect(),
+            _ => return None,
+        };
+        best_match(field, fields).map(str::to_string)
+    }
+
+    /// Suggests a variant of a visible enum with a name close to one that was not found.
+    ///
+    /// # Parameters
+    ///
+    /// - `variant`: The variant name that was not found.
+    ///
+    /// # Returns
+    ///
+    /// Returns the closest variant name, or `None` if none is close enough.
+    pub fn suggest_variant(&self, variant: &str) -> Option<String> {
+        let mut candidates = Vec::new();
+        for (_, info) in self.visible_symbols() {
+            if let SymbolValue::EnumValue { variants } = &info.value {
+                candidates.extend(variants.iter().map(String::as_str));
+            }
+        }
+        best_match(variant, candidates).map(str::to_string)
+    }
+
+    /// Builds the error for a use of a variable that is not in scope, suggesting a close match if one is visible.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name that was not found.
+    ///
+    /// # Returns
+    ///
+    /// Returns an `ErrorType::UndefinedVariable`, carrying a "did you mean" help if a close match exists.
+    pub fn undefined_variable(&self, name: &str) -> ErrorType {
+        let error = ErrorType::UndefinedVariable { variable_name: name.to_string() };
+        with_suggestion(error, self.suggest_variable(name))
+    }
+
+    /// Builds the error for a call to a function that is not in scope, suggesting a close match if one is visible.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The function name that was not found.
+    ///
+    /// # Returns
+    ///
+    /// Returns an error with the code of an `UndefinedVariable`, carrying a "did you mean" help if a close
+    /// match exists.
+    pub fn undefined_function(&self, name: &str) -> ErrorType {
+        let message = format!("cannot find function `{}` in this scope", name);
+        with_suggestion(undefined(&message), self.suggest_function(name))
+    }
+
+    /// Builds the error for an access to a field a struct or union does not have, suggesting a close match.
+    ///
+    /// # Parameters
+    ///
+    /// - `record`: The name of the struct or union.
+    /// - `field`: The field name that was not found.
+    ///
+    /// # Returns
+    ///
+    /// Returns an error with the code of an `UndefinedVariable`, carrying a "did you mean" help if a close
+    /// match exists.
+    pub fn undefined_field(&self, record: &str, field: &str) -> ErrorType {
+        let message = format!("no field `{}` on `{}`", field, record);
+        with_suggestion(undefined(&message), self.suggest_field(record, field))
+    }
+
+    /// Builds the error for a use of an enum variant that is not in scope, suggesting a close match.
+    ///
+    /// # Parameters
+    ///
+    /// - `variant`: The variant name that was not found.
+    ///
+    /// # Returns
+    ///
+    /// Returns an error with the code of an `UndefinedVariable`, carrying a "did you mean" help if a close
+    /// match exists.
+    pub fn undefined_variant(&self, variant: &str) -> ErrorType {
+        let message = format!("cannot find enum variant `{}` in this scope", variant);
+        with_suggestion(undefined(&message), self.suggest_variant(variant))
+    }
+
+    /// Adds a table as a scope inside the current one and makes it the current scope.
+    fn add_scope(&mut self, table: SymbolTable, opener: Option<(NodeId, String)>) -> ScopeId {
+        let id = ScopeId(self.elements.len());
+        let (node, construct) = opener.unzip();
+        self.elements.push(table);
+        self.scopes.push(Scope { parent: self.current, node, construct });
+        if let Some(node) = node {
+            self.nodes.insert(node, id);
+        }
+        self.current = Some(id);
+        id
+    }
+
+    /// Retrieves the number of scopes enclosing a scope.
+    fn get_depth(&self, scope: ScopeId) -> usize {
+        std::iter::successors(self.get_parent(scope), |&parent| self.get_parent(parent)).count()
+    }
+
+    /// Retrieves the tables visible from a scope: its own, then those of the scopes enclosing it.
+    fn visible_tables(&self, scope: Option<ScopeId>) -> impl Iterator<Item = &SymbolTable> {
+        std::iter::successors(scope, |&scope| self.get_parent(scope)).filter_map(|scope| self.get_table(scope))
+    }
+
+    /// Checks whether a name is a variant of a visible enum.
+    pub(crate) fn is_variant(&self, name: &str) -> bool {
+        self.visible_tables(self.current).flat_map(|table| table.values.values()).any(|info| {
+            matches!(&info.value, SymbolValue::EnumValue { variants } if variants.iter().any(|variant| variant == name))
+        })
+    }
+
+    /// Retrieves the symbols visible from the current scope, innermost scope first and sorted by name within
+    /// a scope. A symbol shadowed by an inner one is left out.
+    fn visible_symbols(&self) -> Vec<(&str, &SymbolInfo)> {
+        let mut seen = HashSet::new();
+        let mut symbols = Vec::new();
+        for table in self.visible_tables(self.current) {
+            for name in table.get_names() {
+                if seen.insert(name) {
+                    symbols.push((name, &table.values[name]));
                 }
             }
-        } 
-        panic!("Invalid index: {:} for size {:}", index, self.elements.len())
+        }
+        symbols
+    }
+}
+
+/// Builds an error with the code of an `UndefinedVariable` and a custom message.
+fn undefined(message: &str) -> ErrorType {
+    let code = ErrorType::UndefinedVariable { variable_name: String::new() }.get_code().to_string();
+    ErrorType::from(Diagnostic::error(message).with_code(&code))
+}
+
+/// Describes what a symbol was declared as, for the note of a redeclaration.
+fn describe(info: &SymbolInfo) -> String {
+    let data_type = match type_name(&info.data_type) {
+        Ok(name) => name.to_string(),
+        Err(_) => info.data_type.to_string().to_lowercase(),
+    };
+    match &info.value {
+        SymbolValue::NoAssociatedValue => format!("a variable of type `{}`", data_type),
+        SymbolValue::FunctionValue { .. } => format!("a function returning `{}`", data_type),
+        SymbolValue::StructValue { .. } => "a struct".to_string(),
+        SymbolValue::UnionValue { .. } => "a union".to_string(),
+        SymbolValue::EnumValue { .. } => "an enum".to_string(),
+    }
+}
+
+/// Checks whether two symbols are declarations of the same function: the same return type, parameter types and
+/// variadic flag. Parameter names are not part of the signature.
+fn same_signature(previous: &SymbolInfo, info: &SymbolInfo) -> bool {
+    match (&previous.value, &info.value) {
+        (
+            SymbolValue::FunctionValue { parameters: previous_parameters, variadic: previous_variadic },
+            SymbolValue::FunctionValue { parameters, variadic },
+        ) => {
+            previous.data_type == info.data_type
+                && previous_variadic == variadic
+                && previous_parameters.iter().map(|(_, data_type)| data_type).eq(parameters.iter().map(|(_, data_type)| data_type))
+        },
+        _ => false,
+    }
+}
+
+/// Attaches a "did you mean" help to an error if a suggestion was found.
+fn with_suggestion(error: ErrorType, suggestion: Option<String>) -> ErrorType {
+    match suggestion {
+        Some(name) => ErrorType::from(Diagnostic::from(error).with_help(&format!("did you mean `{}`?", name))),
+        None => error,
+    }
+}
+
+impl fmt::Display for SymbolValue {
+    /// Formats the value in a C-like shorthand, such as `struct { x: Integer, flags: Unsigned : 3 }`, or `-`
+    /// for a symbol without an associated value.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SymbolValue::NoAssociatedValue => write!(f, "-"),
+            SymbolValue::EnumValue { variants } => write!(f, "enum {{ {} }}", variants.join(", ")),
+            SymbolValue::StructValue { fields, bit_widths, flexible_array } => {
+                let mut members: Vec<String> = fields.iter().map(|(name, data_type)| {
+                    match bit_widths.iter().find(|(field, _)| field == name) {
+                        Some((_, width)) => format!("{}: {} : {}", name, data_type, width),
+                        None => format!("{}: {}", name, data_type),
+                    }
+                }).collect();
+                members.extend(flexible_array.iter().map(|(name, data_type)| format!("{}: {}[]", name, data_type)));
+                write!(f, "struct {{ {} }}", members.join(", "))
+            },
+            SymbolValue::UnionValue { fields } => {
+                let members: Vec<String> = fields.iter().map(|(name, data_type)| format!("{}: {}", name, data_type)).collect();
+                write!(f, "union {{ {} }}", members.join(", "))
+            },
+            SymbolValue::FunctionValue { parameters, variadic } => {
+                let mut members: Vec<String> = parameters.iter().map(|(name, data_type)| format!("{}: {}", name, data_type)).collect();
+                if *variadic {
+                    members.push("...".to_string());
+                }
+                write!(f, "function ({})", members.join(", "))
+            },
+        }
     }
 }
 
 impl fmt::Display for SymbolTableStack {
+    /// Formats the stack as one table per scope, in the order the scopes were opened. Each scope is headed by
+    /// its id, its depth and the construct that opened it, and lists its symbols sorted by name, with their
+    /// data type and value in aligned columns.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.to_string())
+        for (index, (table, scope)) in self.elements.iter().zip(&self.scopes).enumerate() {
+            write!(f, "scope {} (depth {})", index, self.get_depth(ScopeId(index)))?;
+            match &scope.construct {
+                Some(construct) => writeln!(f, ": {}", construct)?,
+                None => writeln!(f)?,
+            }
+
+            let rows: Vec<[String; 3]> = table.get_names().into_iter().map(|name| {
+                let info = &table.values[name];
+                [name.to_string(), info.data_type.to_string(), info.value.to_string()]
+            }).collect();
+            if rows.is_empty() {
+                writeln!(f, "  (no symbols)")?;
+                continue;
+            }
+            let header = ["name".to_string(), "type".to_string(), "value".to_string()];
+            let name_width = rows.iter().chain([&header]).map(|row| row[0].len()).max().unwrap_or(0);
+            let type_width = rows.iter().chain([&header]).map(|row| row[1].len()).max().unwrap_or(0);
+            for [name, data_type, value] in [&header].into_iter().chain(&rows) {
+                writeln!(f, "  {:<name_width$}  {:<type_width$}  {}", name, data_type, value)?;
+            }
+        }
+        Ok(())
     }
 }
 
 impl fmt::Debug for SymbolTableStack {
+    /// Formats the stack as its scopes in the order they were opened, each with its parent, the node and
+    /// construct that opened it and its table, followed by the current scope.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("SymbolTableStack")
          .field("size", &self.elements.len())
-         .field("tables", &self.elements.iter().map(|table| {
-            format!("{:?}", table)
+         .field("scopes", &self.elements.iter().zip(&self.scopes).enumerate().map(|(index, (table, scope))| {
+            format!("{:?}: {:?} {:?}", ScopeId(index), scope, table)
          }).collect::<Vec<String>>())
+         .field("current", &self.current)
          .finish()
     }
 }
@@ -305,7 +846,7 @@ impl fmt::Debug for SymbolTableStack {
 
 impl PartialEq for SymbolTableStack {
     fn eq(&self, other: &Self) -> bool {
-        if self.elements.len() != other.elements.len() {
+        if self.elements.len() != other.elements.len() || self.scopes != other.scopes || self.nodes != other.nodes {
             return false;
         }
 
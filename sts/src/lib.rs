@@ -10,6 +10,8 @@
 //! * `core`: Defines the symbol table stack structure and drives the generation process.
 //! * `statement`: Handles stack generation for statements and operations within blocks.
 //! * `block`: Handles stack generation for code blocks.
+//! * `resolve`: Reports undefined, redeclared and shadowed names of the `AST`.
+//! * `types`: Checks the types of the `AST` once its symbol table stack has been generated.
 
 /// Definitions of the symbol table stack structure and core of the symbol table stack generation process
 pub mod core;
@@ -17,5 +19,11 @@ pub mod core;
 /// Stack generation for nodes with code blocks
 mod block;
 
+/// Resolution of the identifiers of the AST against their enclosing scopes
+pub mod resolve;
+
 /// Stack generation for nodes within code blocks
-mod statement;
\ No newline at end of file
+mod statement;
+
+/// Type checking of the AST and the table of the types of its expressions
+pub mod types;
\ No newline at end of file
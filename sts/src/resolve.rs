@@ -0,0 +1,322 @@
+//! This file contains the name resolver, which checks that every identifier of an `AST` refers to a declaration
+//! in an enclosing scope.
+//!
+//! The resolver walks the tree in source order, keeping a `SymbolTableStack` with one table per scope, so a name
+//! used before its declaration is reported as undefined even if it is declared later in the same block. A name
+//! declared twice in one scope is reported through `SymbolTableStack::add_element`, and a name that hides one of
+//! an enclosing scope can be reported as a warning.
+//!
+//! File-scope names are declared in source order too, so a global used by a function before its declaration
+//! is reported. The outermost table of the `SymbolTableStack` the resolver is given may hold symbols that do
+//! not come from the tree, such as those provided by the environment; only those sit below the scopes of the
+//! tree, where they resolve everywhere. The names of that table the tree declares at file scope are left out,
+//! since the walk declares them where they appear.
+//!
+//! A redeclaration is reported with the `NodePath` of the first declaration, as the nodes carry no spans.
+
+use std::collections::{HashMap, HashSet};
+
+use common::{
+    ast::{
+        core::{ASTNode, AST},
+        data_type::DataType,
+        node_type::NodeType,
+        path::NodePath,
+    },
+    diagnostic::Diagnostic,
+    error::ErrorType,
+};
+use crate::core::{ScopeId, SymbolInfo, SymbolTable, SymbolTableStack, SymbolValue};
+
+/// Resolves the identifiers of an `AST` against the scopes that enclose them.
+///
+/// # Fields
+/// * `scopes` - The globals given to the resolver, followed by a table for each enclosing scope of the tree.
+/// * `given` - The number of tables holding the globals given to the resolver.
+/// * `locations` - The path of the declaration of each name of the open scopes.
+/// * `warn_shadowing` - Whether to warn about declarations that hide a name of an enclosing scope.
+/// * `errors` - The errors found so far.
+/// * `warnings` - The warnings found so far.
+pub struct NameResolver {
+    scopes: SymbolTableStack,
+    given: usize,
+    locations: HashMap<(ScopeId, String), NodePath>,
+    warn_shadowing: bool,
+    errors: Vec<ErrorType>,
+    warnings: Vec<Diagnostic>,
+}
+
+impl NameResolver {
+    /// Resolves every identifier of an `AST` and checks its declarations.
+    ///
+    /// # Parameters
+    /// * `ast` - The `AST` to resolve.
+    /// * `sts` - The symbol table stack of the `AST`, whose outermost table holds the globals, whether declared by
+    ///   the `AST` or not.
+    /// * `warn_shadowing` - Whether to warn about declarations that hide a name of an enclosing scope.
+    ///
+    /// # Returns
+    /// Returns the warnings found, which are empty unless `warn_shadowing` is set.
+    ///
+    /// # Errors
+    /// * Returns an `UndefinedVariable`, or a diagnostic with its code, for every variable, function or enum
+    ///   variant used where it is not declared, with a "did you mean" help if a close match is visible.
+    /// * Returns an `ErrorType::Redeclaration` for every name declared twice in the same scope, with the path of
+    ///   its first declaration.
+    ///
+    /// The warnings follow the errors, wrapped in `ErrorType::Diagnostic`, so that none of them is lost.
+    pub fn check(ast: &AST, sts: &SymbolTableStack, warn_shadowing: bool) -> Result<Vec<Diagnostic>, Vec<ErrorType>> {
+        let root = ast.get_root_ref();
+        let mut scopes = SymbolTableStack::new();
+        if let Some(globals) = sts.get_elements().first() {
+            let declared = file_scope_names(root);
+            let mut given = SymbolTable::new();
+            for name in globals.get_names().into_iter().filter(|name| !declared.contains(*name)) {
+                if let Some(info) = globals.get(name) {
+                    given.add(name.to_string(), info.clone());
+                }
+            }
+            scopes.push(given);
+        }
+        let given = scopes.size();
+        scopes.push(SymbolTable::new());
+        let mut resolver = NameResolver {
+            scopes,
+            given,
+            locations: HashMap::new(),
+            warn_shadowing,
+            errors: Vec::new(),
+            warnings: Vec::new(),
+        };
+        resolver.statement(root, &NodePath::root(root));
+
+        let NameResolver { mut errors, warnings, .. } = resolver;
+        if errors.is_empty() {
+            Ok(warnings)
+        } else {
+            errors.extend(warnings.into_iter().map(ErrorType::from));
+            Err(errors)
+        }
+    }
+
+    /// Resolves a statement, or an expression in statement position, located at `path`.
+    fn statement(&mut self, node: &ASTNode, path: &NodePath) {
+        let children = node.get_children_ref();
+        match node.get_node_type_ref() {
+            NodeType::BlockExpression | NodeType::ForLoop => {
+                self.scopes.push(SymbolTable::new());
+                self.children(node, path, 0);
+                self.close_scope();
+            },
+            NodeType::FunctionDeclaration => self.function(node, path),
+            NodeType::StructDeclaration => self.record(node, path, DataType::Struct),
+            NodeType::UnionDeclaration => self.record(node, path, DataType::Union),
+            NodeType::EnumDeclaration => {
+                let variants = children.iter().skip(1)
+                    .filter_map(|variant| variant.get_children_ref().first().and_then(name))
+                    .collect();
+                if let Some(enum_name) = children.first().and_then(name) {
+                    self.declare(enum_name, SymbolInfo::new(DataType::Enum, SymbolValue::EnumValue { variants }), path);
+                }
+            },
+            NodeType::Initialization => {
+                let Some(variable) = children.first() else {
+                    return;
+                };
+                let parts = variable.get_children_ref();
+                if let (Some(variable_name), Some(data_type)) = (parts.first().and_then(name), parts.get(1).and_then(data_type)) {
+                    self.declare(variable_name, SymbolInfo::new(data_type, SymbolValue::NoAssociatedValue), path);
+                }
+                self.children(node, path, 1);
+            },
+            NodeType::Identifier(identifier) => {
+                if self.scopes.lookup(identifier).is_none() && !self.scopes.is_variant(identifier) {
+                    self.errors.push(self.scopes.undefined_variable(identifier));
+                }
+            },
+            NodeType::FunctionCall => {
+                if let Some(callee) = children.first() {
+                    match callee.get_node_type_ref() {
+                        NodeType::Identifier(function) if self.scopes.lookup(function).is_none() => {
+                            self.errors.push(self.scopes.undefined_function(function));
+                        },
+                        _ => self.statement(callee, &path.child(callee, 0)),
+                    }
+                }
+                self.children(node, path, 1);
+            },
+            NodeType::Case => {
+                if let Some(label) = children.first() {
+                    match label.get_node_type_ref() {
+                        NodeType::Identifier(variant) if self.scopes.lookup(variant).is_none() && !self.scopes.is_variant(variant) => {
+                            self.errors.push(self.scopes.undefined_variant(variant));
+                        },
+                        _ => self.statement(label, &path.child(label, 0)),
+                    }
+                }
+                self.children(node, path, 1);
+            },
+            NodeType::MemberAccess => {
+                if let Some(object) = children.first() {
+                    self.statement(object, &path.child(object, 0));
+                }
+            },
+            _ => self.children(node, path, 0),
+        }
+    }
+
+    /// Resolves the children of a node located at `path`, starting from the child at `first`.
+    fn children(&mut self, node: &ASTNode, path: &NodePath, first: usize) {
+        for (index, child) in node.get_children_ref().iter().enumerate().skip(first) {
+            self.statement(child, &path.child(child, index));
+        }
+    }
+
+    /// Declares a function, then resolves its body in a scope holding its parameters.
+    fn function(&mut self, node: &ASTNode, path: &NodePath) {
+        let mut function_name = None;
+        let mut parameters = Vec::new();
+        let mut variadic = false;
+        let mut return_type = DataType::Void;
+        let mut body = None;
+        for (index, child) in node.get_children_ref().iter().enumerate() {
+            match child.get_node_type_ref() {
+                NodeType::Identifier(identifier) => function_name = Some(identifier.clone()),
+                NodeType::Parameter => {
+                    let parts = child.get_children_ref();
+                    if let (Some(parameter), Some(data_type)) = (parts.first().and_then(name), parts.get(1).and_then(data_type)) {
+                        parameters.push((parameter, data_type, path.child(child, index)));
+                    }
+                },
+                NodeType::VariadicParameter => variadic = true,
+                NodeType::Type(data_type) => return_type = *data_type,
+                NodeType::BlockExpression => body = Some((child, path.child(child, index))),
+                _ => {},
+            }
+        }
+        if let Some(function_name) = function_name {
+            let value = SymbolValue::FunctionValue {
+                parameters: parameters.iter().map(|(parameter, data_type, _)| (parameter.clone(), *data_type)).collect(),
+                variadic,
+            };
+            self.declare(function_name, SymbolInfo::new(return_type, value), path);
+        }
+
+        let Some((body, body_path)) = body else {
+            return;
+        };
+        self.scopes.push(SymbolTable::new());
+        for (parameter, data_type, parameter_path) in parameters {
+            self.declare(parameter, SymbolInfo::new(data_type, SymbolValue::NoAssociatedValue), &parameter_path);
+        }
+        self.children(body, &body_path, 0);
+        self.close_scope();
+    }
+
+    /// Declares a struct or union with its fields.
+    fn record(&mut self, node: &ASTNode, path: &NodePath, kind: DataType) {
+        let children = node.get_children_ref();
+        let Some(record_name) = children.first().and_then(name) else {
+            return;
+        };
+        let mut fields = Vec::new();
+        let mut bit_widths = Vec::new();
+        let mut flexible_array = None;
+        for field in children.iter().skip(1) {
+            let parts = field.get_children_ref();
+            let (Some(NodeType::Literal(field_name)), Some(field_type)) =
+                (parts.first().map(ASTNode::get_node_type_ref), parts.get(1).and_then(data_type)) else {
+                continue;
+            };
+            match parts.get(2).map(|marker| (marker.get_node_type_ref(), marker.get_children_ref())) {
+                Some((NodeType::FlexibleArray, _)) => flexible_array = Some((field_name.clone(), field_type)),
+                Some((NodeType::BitWidth, [width])) => {
+                    if let NodeType::Literal(width) = width.get_node_type_ref() {
+                        if let Ok(width) = width.parse() {
+                            bit_widths.push((field_name.clone(), width));
+                        }
+                    }
+                    fields.push((field_name.clone(), field_type));
+                },
+                _ => fields.push((field_name.clone(), field_type)),
+            }
+        }
+        let value = match kind {
+            DataType::Union => SymbolValue::UnionValue { fields },
+            _ => SymbolValue::StructValue { fields, bit_widths, flexible_array },
+        };
+        self.declare(record_name, SymbolInfo::new(kind, value), path);
+    }
+
+    /// Closes the innermost scope, forgetting where its names were declared.
+    fn close_scope(&mut self) {
+        if let Some(closed) = self.scopes.get_current_scope() {
+            self.locations.retain(|(scope, _), _| *scope != closed);
+        }
+        self.scopes.pop();
+    }
+
+    /// Declares a name in the innermost scope, reporting a redeclaration and, if asked, shadowing.
+    fn declare(&mut self, name: String, info: SymbolInfo, path: &NodePath) {
+        if self.warn_shadowing {
+            let enclosing = &self.scopes.get_elements()[self.given..self.scopes.size() - 1];
+            if enclosing.iter().any(|table| table.get(&name).is_some()) {
+                let message = format!("declaration of `{}` shadows a declaration in an enclosing scope", name);
+                self.warnings.push(Diagnostic::warning(&message)
+                    .with_note(&format!("uses of `{}` in this scope refer to the new declaration", name)));
+            }
+        }
+        let scope = self.scopes.get_current_scope();
+        match (self.scopes.add_element(name.clone(), info), scope) {
+            (Ok(()), Some(scope)) => {
+                self.locations.entry((scope, name)).or_insert_with(|| path.clone());
+            },
+            (Err(ErrorType::Redeclaration { name, previous, .. }), Some(scope)) => {
+                let path = self.locations.get(&(scope, name.clone())).map(NodePath::to_string);
+                self.errors.push(ErrorType::Redeclaration { name, previous, path });
+            },
+            (Err(error), _) => self.errors.push(error),
+            (Ok(()), None) => {},
+        }
+    }
+}
+
+/// Collects the names the `AST` declares at file scope: those declared by the root, or by the
+/// `TopLevelExpression`s of a module root.
+fn file_scope_names(root: &ASTNode) -> HashSet<String> {
+    let mut names = HashSet::new();
+    let mut pending = vec![root];
+    while let Some(node) = pending.pop() {
+        for child in node.get_children_ref() {
+            let declared = match child.get_node_type_ref() {
+                NodeType::TopLevelExpression => {
+                    pending.push(child);
+                    None
+                },
+                NodeType::FunctionDeclaration | NodeType::StructDeclaration | NodeType::UnionDeclaration
+                | NodeType::EnumDeclaration => child.get_children_ref().first(),
+                NodeType::Initialization => child.get_children_ref().first()
+                    .and_then(|variable| variable.get_children_ref().first()),
+                _ => None,
+            };
+            names.extend(declared.and_then(name));
+        }
+    }
+    names
+}
+
+/// Retrieves the name of an `Identifier` node.
+fn name(node: &ASTNode) -> Option<String> {
+    match node.get_node_type_ref() {
+        NodeType::Identifier(name) => Some(name.clone()),
+        _ => None,
+    }
+}
+
+/// Retrieves the type of a `Type` node.
+fn data_type(node: &ASTNode) -> Option<DataType> {
+    match node.get_node_type_ref() {
+        NodeType::Type(data_type) => Some(*data_type),
+        _ => None,
+    }
+}
@@ -0,0 +1,758 @@
+//! This file contains the type checker, which runs after the symbol table stack has been generated.
+//!
+//! The checker computes a `DataType` for every expression, applying C's integer promotions and usual arithmetic
+//! conversions, and reports operands an operator does not accept, values that cannot be assigned to their
+//! target, calls with the wrong arguments and returns that do not match the function. The computed types are
+//! recorded in a `TypeTable` keyed by the `NodeId`s of an `ArenaAST`, which the IR generator can consult instead
+//! of inferring types again.
+//!
+//! Globals are taken from the outermost table of the `SymbolTableStack`, and declarations are also collected
+//! while walking the tree, so names declared in inner scopes resolve lexically. A name that cannot be resolved
+//! has the type `DataType::None` and is not reported here, since `NameResolver` reports undefined names;
+//! an expression of type `DataType::None` never produces further errors, so one mistake is reported once.
+//!
+//! A member access looks its field up in the struct or union named by the `Variable` that declared its base,
+//! through the `Identifier` the parser places after the `Type` of a struct- or union-typed variable.
+//! A base whose struct or union is not named, such as a global or a field, has no known fields, so the access
+//! has the type `DataType::None` rather than a guess.
+
+use std::collections::{HashMap, HashSet};
+
+use common::{
+    ast::{
+        arena::{ArenaAST, NodeId, TypeTable},
+        core::AST,
+        data_type::DataType,
+        node_type::NodeType,
+        printer::{print_expression, type_name},
+    },
+    diagnostic::Diagnostic,
+    error::ErrorType,
+};
+use crate::core::{SymbolInfo, SymbolTable, SymbolTableStack, SymbolValue};
+
+/// An `AST` together with the type of each of its expressions.
+///
+/// # Fields
+/// * `ast` - The checked tree, whose `NodeId`s key the type table.
+/// * `types` - The type computed for every expression node.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypedAST {
+    ast: ArenaAST,
+    types: TypeTable,
+}
+
+/// Checks the types of an `AST` against its symbol table stack.
+///
+/// # Fields
+/// * `ast` - The tree being checked.
+/// * `scopes` - The variables of each enclosing scope, outermost first.
+/// * `functions` - The signatures of the functions declared so far.
+/// * `records` - The structs and unions declared so far, in a table for each enclosing scope.
+/// * `variants` - The variants of the enums declared so far.
+/// * `return_type` - The return type of the function being checked, if any.
+/// * `types` - The types computed so far.
+/// * `errors` - The errors found so far.
+pub struct TypeChecker<'a> {
+    ast: &'a ArenaAST,
+    scopes: Vec<HashMap<String, Variable>>,
+    functions: HashMap<String, Signature>,
+    records: SymbolTableStack,
+    variants: HashSet<String>,
+    return_type: Option<DataType>,
+    types: TypeTable,
+    errors: Vec<ErrorType>,
+}
+
+/// The parameter types, variadicity and return type of a function.
+#[derive(Debug, Clone)]
+struct Signature {
+    parameters: Vec<DataType>,
+    variadic: bool,
+    return_type: DataType,
+}
+
+/// The type of a variable, with the name of its struct or union if it has one.
+#[derive(Debug, Clone)]
+struct Variable {
+    data_type: DataType,
+    record: Option<String>,
+}
+
+impl TypedAST {
+    /// Retrieves the checked tree.
+    ///
+    /// # Returns
+    /// Returns the `ArenaAST` whose `NodeId`s key the type table.
+    pub fn get_ast(&self) -> &ArenaAST {
+        &self.ast
+    }
+
+    /// Retrieves the type of every expression.
+    ///
+    /// # Returns
+    /// Returns the type table.
+    pub fn get_types(&self) -> &TypeTable {
+        &self.types
+    }
+
+    /// Retrieves the type of a node.
+    ///
+    /// # Parameters
+    /// * `id` - The id of the node.
+    ///
+    /// # Returns
+    /// Returns the type of the node if it is an expression, `None` otherwise.
+    pub fn get_type(&self, id: NodeId) -> Option<DataType> {
+        self.types.get(id).copied()
+    }
+}
+
+impl<'a> TypeChecker<'a> {
+    /// Checks the types of every expression and statement of an `AST`.
+    ///
+    /// # Parameters
+    /// * `ast` - The `AST`, as returned by `SymbolTableStack::gen_sym_table_stack`.
+    /// * `sts` - The symbol table stack of the `AST`, whose outermost table holds the globals.
+    ///
+    /// # Returns
+    /// Returns the `TypedAST` holding the tree and the type of each expression.
+    ///
+    /// # Errors
+    /// * Returns every `TypeMismatch`, `UnsupportedOperator`, `InvalidAssignment` and `DivisionByZero` found,
+    ///   along with diagnostics for calls with the wrong number of arguments, unknown fields and returns that
+    ///   do not match the function.
+    pub fn check(ast: &AST, sts: &SymbolTableStack) -> Result<TypedAST, Vec<ErrorType>> {
+        let arena = ArenaAST::from_ast(ast);
+        let mut records = SymbolTableStack::new();
+        records.push(SymbolTable::new());
+        let mut checker = TypeChecker {
+            ast: &arena,
+            scopes: vec![HashMap::new()],
+            functions: HashMap::new(),
+            records,
+            variants: HashSet::new(),
+            return_type: None,
+            types: TypeTable::new(),
+            errors: Vec::new(),
+        };
+        checker.add_globals(sts);
+        checker.statement(arena.get_root());
+
+        let TypeChecker { types, errors, .. } = checker;
+        if errors.is_empty() {
+            Ok(TypedAST { ast: arena, types })
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Declares the symbols of the outermost table of a symbol table stack.
+    fn add_globals(&mut self, sts: &SymbolTableStack) {
+        let Some(globals) = sts.get_elements().first() else {
+            return;
+        };
+        for name in globals.get_names() {
+            let Some(info) = globals.get(name) else {
+                continue;
+            };
+            match info.get_value() {
+                SymbolValue::NoAssociatedValue => self.declare(name, info.get_data_type(), None),
+                SymbolValue::FunctionValue { parameters, variadic } => {
+                    self.functions.insert(name.to_string(), Signature {
+                        parameters: parameters.into_iter().map(|(_, data_type)| data_type).collect(),
+                        variadic,
+                        return_type: info.get_data_type(),
+                    });
+                },
+                SymbolValue::StructValue { .. } | SymbolValue::UnionValue { .. } => {
+                    let _ = self.records.add_element(name.to_string(), info.clone());
+                },
+                SymbolValue::EnumValue { variants } => self.variants.extend(variants),
+            }
+        }
+    }
+
+    /// Checks a statement, or an expression in statement position.
+    fn statement(&mut self, id: NodeId) {
+        let children = self.ast.get_children(id);
+        match self.ast.get_node_type(id) {
+            NodeType::TopLevelExpression | NodeType::ModuleExpression | NodeType::ElseStatement
+            | NodeType::LoopInitializer => {
+                for &child in children {
+                    self.statement(child);
+                }
+            },
+            NodeType::BlockExpression => {
+                self.enter_scope(HashMap::new());
+                for &child in children {
+                    self.statement(child);
+                }
+                self.exit_scope();
+            },
+            NodeType::FunctionDeclaration => self.function(id),
+            NodeType::StructDeclaration => self.record(id, DataType::Struct),
+            NodeType::UnionDeclaration => self.record(id, DataType::Union),
+            NodeType::EnumDeclaration => {
+                for &variant in children.iter().skip(1) {
+                    if let Some(name) = self.ast.get_children(variant).first().and_then(|&name| self.name(name)) {
+                        self.variants.insert(name);
+                    }
+                }
+            },
+            NodeType::Initialization => self.initialization(id),
+            NodeType::Return => self.return_statement(id),
+            NodeType::IfStatement | NodeType::WhileLoop | NodeType::DoWhileLoop => {
+                for &child in children {
+                    match self.ast.get_node_type(child) {
+                        NodeType::Condition => self.condition(child),
+                        _ => self.statement(child),
+                    }
+                }
+            },
+            NodeType::ForLoop => {
+                self.enter_scope(HashMap::new());
+                for &child in children {
+                    match self.ast.get_node_type(child) {
+                        NodeType::Condition => self.condition(child),
+                        NodeType::LoopIncrement => {
+                            for &increment in self.ast.get_children(child) {
+                                self.expression(increment);
+                            }
+                        },
+                        _ => self.statement(child),
+                    }
+                }
+                self.exit_scope();
+            },
+            NodeType::SwitchStatement => self.switch(id),
+            NodeType::Condition => self.condition(id),
+            NodeType::Break | NodeType::Continue | NodeType::NoExpression => {},
+            _ => {
+                self.expression(id);
+            },
+        }
+    }
+
+    /// Checks a function declaration and its body.
+    fn function(&mut self, id: NodeId) {
+        let mut name = None;
+        let mut parameters = Vec::new();
+        let mut variadic = false;
+        let mut return_type = DataType::Void;
+        let mut body = None;
+        for &child in self.ast.get_children(id) {
+            match self.ast.get_node_type(child) {
+                NodeType::Identifier(identifier) => name = Some(identifier.clone()),
+                NodeType::Parameter => {
+                    let parameter = self.ast.get_children(child);
+                    if let (Some(&identifier), Some(&data_type)) = (parameter.first(), parameter.get(1)) {
+                        if let (Some(identifier), Some(data_type)) = (self.name(identifier), self.data_type(data_type)) {
+                            parameters.push((identifier, data_type));
+                        }
+                    }
+                },
+                NodeType::VariadicParameter => variadic = true,
+                NodeType::Type(data_type) => return_type = *data_type,
+                NodeType::BlockExpression => body = Some(child),
+                _ => {},
+            }
+        }
+        if let Some(name) = name {
+            self.functions.insert(name, Signature {
+                parameters: parameters.iter().map(|(_, data_type)| *data_type).collect(),
+                variadic,
+                return_type,
+            });
+        }
+
+        let Some(body) = body else {
+            return;
+        };
+        let enclosing = self.return_type.replace(return_type);
+        self.enter_scope(parameters.into_iter().map(|(name, data_type)| (name, Variable { data_type, record: None })).collect());
+        for &statement in self.ast.get_children(body) {
+            self.statement(statement);
+        }
+        self.exit_scope();
+        self.return_type = enclosing;
+    }
+
+    /// Records a struct or union declaration in the innermost scope.
+    fn record(&mut self, id: NodeId, kind: DataType) {
+        let children = self.ast.get_children(id);
+        let Some(name) = children.first().and_then(|&name| self.name(name)) else {
+            return;
+        };
+        let mut fields = Vec::new();
+        for &field in children.iter().skip(1) {
+            let parts = self.ast.get_children(field);
+            if let (Some(&name), Some(&data_type)) = (parts.first(), parts.get(1)) {
+                if let (NodeType::Literal(name), Some(data_type)) = (self.ast.get_node_type(name), self.data_type(data_type)) {
+                    fields.push((name.clone(), data_type));
+                }
+            }
+        }
+        let value = match kind {
+            DataType::Union => SymbolValue::UnionValue { fields },
+            _ => SymbolValue::StructValue { fields, bit_widths: Vec::new(), flexible_array: None },
+        };
+        // A redeclaration is reported by `NameResolver`, so only the first declaration is kept here.
+        let _ = self.records.add_element(name, SymbolInfo::new(kind, value));
+    }
+
+    /// Checks the initialization of a variable and declares it.
+    fn initialization(&mut self, id: NodeId) {
+        let children = self.ast.get_children(id);
+        let Some(&variable) = children.first() else {
+            return;
+        };
+        let target = self.expression(variable);
+        let parts = self.ast.get_children(variable);
+        if let Some(name) = parts.first().and_then(|&name| self.name(name)) {
+            self.declare(&name, target, parts.get(2).and_then(|&record| self.name(record)));
+        }
+        if let Some(&value) = children.get(1) {
+            let value_type = self.expression(value);
+            if !is_assignable(target, value_type) {
+                self.errors.push(mismatch(target, value_type));
+            }
+        }
+    }
+
+    /// Checks a return statement against the return type of the enclosing function.
+    fn return_statement(&mut self, id: NodeId) {
+        let value = self.ast.get_children(id).first().map(|&value| self.expression(value));
+        let Some(return_type) = self.return_type else {
+            return;
+        };
+        match value {
+            Some(value) if return_type == DataType::Void && value != DataType::None => {
+                self.errors.push(ErrorType::from(type_error("`return` with a value in a function returning `void`")
+                    .with_help("remove the value, or change the return type of the function")));
+            },
+            Some(value) if !is_assignable(return_type, value) => {
+                let message = format!("mismatched types: expected `{}` to be returned, found `{}`", spell(return_type), spell(value));
+                self.errors.push(ErrorType::from(Diagnostic::from(mismatch(return_type, value)).with_note(&message)));
+            },
+            None if return_type != DataType::Void && return_type != DataType::None => {
+                let message = format!("`return` without a value in a function returning `{}`", spell(return_type));
+                self.errors.push(ErrorType::from(type_error(&message)));
+            },
+            _ => {},
+        }
+    }
+
+    /// Checks the expression of a condition, which must be scalar.
+    fn condition(&mut self, id: NodeId) {
+        for &child in self.ast.get_children(id) {
+            let condition = self.expression(child);
+            if !is_scalar(condition) {
+                let message = format!("condition of type `{}` is not a number", spell(condition));
+                self.errors.push(ErrorType::from(type_error(&message)));
+            }
+        }
+    }
+
+    /// Checks a switch statement, whose scrutinee and case values must be integers.
+    fn switch(&mut self, id: NodeId) {
+        let children = self.ast.get_children(id);
+        if let Some(&scrutinee) = children.first() {
+            let scrutinee_type = self.expression(scrutinee);
+            self.require_integer(scrutinee_type, "switch");
+        }
+        let Some(&arms) = children.get(1) else {
+            return;
+        };
+        for &arm in self.ast.get_children(arms) {
+            for &part in self.ast.get_children(arm) {
+                match self.ast.get_node_type(part) {
+                    NodeType::BlockExpression => self.statement(part),
+                    _ => {
+                        let value = self.expression(part);
+                        self.require_integer(value, "case");
+                    },
+                }
+            }
+        }
+    }
+
+    /// Computes and records the type of an expression.
+    fn expression(&mut self, id: NodeId) -> DataType {
+        let data_type = self.infer(id);
+        self.types.insert(id, data_type);
+        data_type
+    }
+
+    /// Computes the type of an expression.
+    fn infer(&mut self, id: NodeId) -> DataType {
+        let children = self.ast.get_children(id);
+        match self.ast.get_node_type(id) {
+            NodeType::Literal(value) | NodeType::Constant(value) => literal_type(value),
+            NodeType::Identifier(name) => self.lookup(name),
+            NodeType::Variable => match children.get(1).and_then(|&data_type| self.data_type(data_type)) {
+                Some(data_type) => data_type,
+                None => children.first().and_then(|&name| self.name(name)).map_or(DataType::None, |name| self.lookup(&name)),
+            },
+            NodeType::AssignedValue | NodeType::Operand => match children.first() {
+                Some(&value) => self.expression(value),
+                None => DataType::None,
+            },
+            NodeType::BinaryExpression => self.binary(id),
+            NodeType::UnaryExpression => self.unary(id),
+            NodeType::Assignment => self.assignment(id),
+            NodeType::FunctionCall => self.call(id),
+            NodeType::MemberAccess => self.member(id),
+            _ => DataType::None,
+        }
+    }
+
+    /// Computes the type of a binary expression, reporting divisions by a constant zero.
+    fn binary(&mut self, id: NodeId) -> DataType {
+        let children = self.ast.get_children(id);
+        let (Some(&left), Some(operator), Some(&right)) = (children.first(), children.get(1).and_then(|&op| self.operator(op)), children.get(2)) else {
+            return DataType::None;
+        };
+        let left_type = self.expression(left);
+        let right_type = self.expression(right);
+
+        let result = self.report(binary_type(&operator, left_type, right_type));
+        if (operator == "/" || operator == "%") && result.is_integer() && is_zero(self.ast.get_node_type(right)) {
+            self.errors.push(ErrorType::DivisionByZero { operation: self.print(id) });
+        }
+        result
+    }
+
+    /// Computes the type of a unary expression.
+    fn unary(&mut self, id: NodeId) -> DataType {
+        let children = self.ast.get_children(id);
+        let (operator, operand) = match children {
+            [first, second] => match (self.operator(*first), self.operator(*second)) {
+                (Some(operator), _) => (operator, *second),
+                (None, Some(operator)) => (operator, *first),
+                (None, None) => return DataType::None,
+            },
+            _ => return DataType::None,
+        };
+        let operand_type = self.expression(operand);
+        if operand_type == DataType::None {
+            return DataType::None;
+        }
+
+        let accepted = match operator.as_str() {
+            "+" | "-" | "++" | "--" => operand_type.is_arithmetic(),
+            "~" => operand_type.is_integer(),
+            "!" => is_scalar(operand_type),
+            _ => return DataType::None,
+        };
+        if !accepted {
+            return self.report(Err(ErrorType::UnsupportedOperator { operator, operand_type: spell(operand_type) }));
+        }
+        match operator.as_str() {
+            "++" | "--" if !self.is_lvalue(operand) => {
+                self.errors.push(ErrorType::InvalidAssignment { target: self.print(operand) });
+                operand_type
+            },
+            "++" | "--" => operand_type,
+            "!" => DataType::Integer,
+            _ => operand_type.integer_promotion(),
+        }
+    }
+
+    /// Computes the type of an assignment, which is the type of its target.
+    fn assignment(&mut self, id: NodeId) -> DataType {
+        let children = self.ast.get_children(id);
+        let (target, operator, value) = match children {
+            [target, value] => (*target, None, *value),
+            [target, operator, value] => (*target, self.operator(*operator), *value),
+            _ => return DataType::None,
+        };
+        let target_type = self.expression(target);
+        let value_type = self.expression(value);
+        if !self.is_lvalue(target) || target_type == DataType::Function {
+            self.errors.push(ErrorType::InvalidAssignment { target: self.print(target) });
+            return DataType::None;
+        }
+
+        let value_type = match operator {
+            Some(operator) => self.report(binary_type(operator.trim_end_matches('='), target_type, value_type)),
+            None => value_type,
+        };
+        if !is_assignable(target_type, value_type) {
+            self.errors.push(mismatch(target_type, value_type));
+        }
+        target_type
+    }
+
+    /// Computes the type of a function call, checking its arguments against the function's parameters.
+    fn call(&mut self, id: NodeId) -> DataType {
+        let children = self.ast.get_children(id);
+        let Some((&callee, arguments)) = children.split_first() else {
+            return DataType::None;
+        };
+        let arguments: Vec<DataType> = arguments.iter().map(|&argument| self.expression(argument)).collect();
+        let Some(name) = self.name(callee) else {
+            return DataType::None;
+        };
+        self.types.insert(callee, DataType::Function);
+
+        let Some(signature) = self.functions.get(&name).cloned() else {
+            let callee_type = self.lookup(&name);
+            if callee_type != DataType::None {
+                self.errors.push(ErrorType::UnsupportedOperator { operator: "()".to_string(), operand_type: spell(callee_type) });
+            }
+            return DataType::None;
+        };
+
+        let (expected, supplied) = (signature.parameters.len(), arguments.len());
+        if supplied < expected || (supplied > expected && !signature.variadic) {
+            let at_least = if signature.variadic { "at least " } else { "" };
+            let plural = if expected == 1 { "argument" } else { "arguments" };
+            let verb = if supplied == 1 { "was" } else { "were" };
+            let message = format!("function `{}` takes {}{} {} but {} {} supplied", name, at_least, expected, plural, supplied, verb);
+            self.errors.push(ErrorType::from(type_error(&message)));
+        }
+        for (index, (&parameter, &argument)) in signature.parameters.iter().zip(&arguments).enumerate() {
+            if !is_assignable(parameter, argument) {
+                let note = format!("in argument {} of `{}`, which expects `{}`", index + 1, name, spell(parameter));
+                self.errors.push(ErrorType::from(Diagnostic::from(mismatch(parameter, argument)).with_note(&note)));
+            }
+        }
+        signature.return_type
+    }
+
+    /// Computes the type of a member access from the fields of the struct or union its base was declared with.
+    fn member(&mut self, id: NodeId) -> DataType {
+        let children = self.ast.get_children(id);
+        let (Some(&base), Some(member)) = (children.first(), children.get(1).and_then(|&member| self.name(member))) else {
+            return DataType::None;
+        };
+        let base_type = self.expression(base);
+        if base_type == DataType::None {
+            return DataType::None;
+        }
+        if base_type != DataType::Struct && base_type != DataType::Union {
+            return self.report(Err(ErrorType::UnsupportedOperator { operator: ".".to_string(), operand_type: spell(base_type) }));
+        }
+
+        let Some(record) = self.record_of(base) else {
+            return DataType::None;
+        };
+        let fields = match self.records.lookup(&record).map(SymbolInfo::get_value) {
+            Some(SymbolValue::StructValue { mut fields, flexible_array, .. }) => {
+                fields.extend(flexible_array);
+                fields
+            },
+            Some(SymbolValue::UnionValue { fields }) => fields,
+            _ => return DataType::None,
+        };
+        match fields.into_iter().find(|(name, _)| *name == member) {
+            Some((_, data_type)) => data_type,
+            None => {
+                self.errors.push(self.records.undefined_field(&record, &member));
+                DataType::None
+            },
+        }
+    }
+
+    /// Retrieves the name of the struct or union the base of a member access was declared with.
+    fn record_of(&self, id: NodeId) -> Option<String> {
+        match self.ast.get_node_type(id) {
+            NodeType::Identifier(name) => self.scopes.iter().rev().find_map(|scope| scope.get(name))?.record.clone(),
+            NodeType::Variable => self.ast.get_children(id).get(2).and_then(|&record| self.name(record)),
+            _ => None,
+        }
+    }
+
+    /// Reports that a scrutinee or case value is not an integer.
+    fn require_integer(&mut self, data_type: DataType, construct: &str) {
+        if data_type != DataType::None && !data_type.is_integer() {
+            let message = format!("`{}` value of type `{}` is not an integer", construct, spell(data_type));
+            self.errors.push(ErrorType::from(type_error(&message)));
+        }
+    }
+
+    /// Records the error of an operation, if any, and returns its type.
+    fn report(&mut self, result: Result<DataType, ErrorType>) -> DataType {
+        result.unwrap_or_else(|error| {
+            self.errors.push(error);
+            DataType::None
+        })
+    }
+
+    /// Declares a variable in the innermost scope.
+    fn declare(&mut self, name: &str, data_type: DataType, record: Option<String>) {
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.insert(name.to_string(), Variable { data_type, record });
+        }
+    }
+
+    /// Enters a scope holding the given variables.
+    fn enter_scope(&mut self, variables: HashMap<String, Variable>) {
+        self.scopes.push(variables);
+        self.records.push(SymbolTable::new());
+    }
+
+    /// Leaves the innermost scope.
+    fn exit_scope(&mut self) {
+        self.scopes.pop();
+        self.records.pop();
+    }
+
+    /// Resolves the type of a name: a variable from the innermost scope outwards, then a function, then an enum
+    /// variant.
+    fn lookup(&self, name: &str) -> DataType {
+        if let Some(variable) = self.scopes.iter().rev().find_map(|scope| scope.get(name)) {
+            variable.data_type
+        } else if self.functions.contains_key(name) {
+            DataType::Function
+        } else if self.variants.contains(name) {
+            DataType::Enum
+        } else {
+            DataType::None
+        }
+    }
+
+    /// Checks whether an expression designates a place that can be assigned to.
+    fn is_lvalue(&self, id: NodeId) -> bool {
+        match self.ast.get_node_type(id) {
+            NodeType::Identifier(name) => self.scopes.iter().any(|scope| scope.contains_key(name)),
+            NodeType::Variable | NodeType::MemberAccess => true,
+            _ => false,
+        }
+    }
+
+    /// Retrieves the name of an `Identifier` node.
+    fn name(&self, id: NodeId) -> Option<String> {
+        match self.ast.get_node_type(id) {
+            NodeType::Identifier(name) => Some(name.clone()),
+            _ => None,
+        }
+    }
+
+    /// Retrieves the type of a `Type` node.
+    fn data_type(&self, id: NodeId) -> Option<DataType> {
+        match self.ast.get_node_type(id) {
+            NodeType::Type(data_type) => Some(*data_type),
+            _ => None,
+        }
+    }
+
+    /// Retrieves the spelling of an `Operator` node.
+    fn operator(&self, id: NodeId) -> Option<String> {
+        match self.ast.get_node_type(id) {
+            NodeType::Operator(operator) => Some(operator.clone()),
+            _ => None,
+        }
+    }
+
+    /// Prints an expression as C source for an error message.
+    fn print(&self, id: NodeId) -> String {
+        print_expression(&self.ast.to_node(id)).unwrap_or_else(|_| self.ast.get_node_type(id).to_string())
+    }
+}
+
+/// Computes the type of a binary operation from the types of its operands.
+///
+/// # Errors
+/// * Returns an `UnsupportedOperator` naming the first operand the operator does not accept, or a
+///   `TypeMismatch` for an equality between values that cannot be compared.
+fn binary_type(operator: &str, left: DataType, right: DataType) -> Result<DataType, ErrorType> {
+    if left == DataType::None || right == DataType::None {
+        return Ok(DataType::None);
+    }
+    let unsupported = |accepts: fn(&DataType) -> bool| {
+        let operand = if accepts(&left) { right } else { left };
+        ErrorType::UnsupportedOperator { operator: operator.to_string(), operand_type: spell(operand) }
+    };
+    match operator {
+        "+" | "-" | "*" | "/" => left.usual_arithmetic_conversion(&right).ok_or_else(|| unsupported(DataType::is_arithmetic)),
+        "%" | "&" | "|" | "^" if left.is_integer() && right.is_integer() => Ok(left.usual_arithmetic_conversion(&right).unwrap_or(DataType::Integer)),
+        "%" | "&" | "|" | "^" | "<<" | ">>" if !left.is_integer() || !right.is_integer() => Err(unsupported(DataType::is_integer)),
+        "<<" | ">>" => Ok(left.integer_promotion()),
+        "<" | ">" | "<=" | ">=" => left.usual_arithmetic_conversion(&right).map(|_| DataType::Integer).ok_or_else(|| unsupported(DataType::is_arithmetic)),
+        "==" | "!=" if left.usual_arithmetic_conversion(&right).is_some() || (left == right && left == DataType::String) => Ok(DataType::Integer),
+        "==" | "!=" if is_scalar(left) && is_scalar(right) => Err(mismatch(left, right)),
+        "==" | "!=" => Err(unsupported(|data_type| is_scalar(*data_type))),
+        "&&" | "||" if is_scalar(left) && is_scalar(right) => Ok(DataType::Integer),
+        "&&" | "||" => Err(unsupported(|data_type| is_scalar(*data_type))),
+        "," => Ok(right),
+        _ => Ok(DataType::None),
+    }
+}
+
+/// Infers the type of a literal from its spelling: strings, characters, booleans, and numbers with their
+/// suffixes. A spelling that is none of these has the type `DataType::None`.
+fn literal_type(value: &str) -> DataType {
+    let lowercase = value.to_ascii_lowercase();
+    if value.starts_with('"') {
+        DataType::String
+    } else if value.starts_with('\'') {
+        DataType::Char
+    } else if value == "true" || value == "false" {
+        DataType::Boolean
+    } else if !lowercase.starts_with(|c: char| c.is_ascii_digit() || c == '.') {
+        DataType::None
+    } else if !lowercase.starts_with("0x") && (lowercase.contains('.') || lowercase.contains('e')) {
+        if lowercase.ends_with('f') { DataType::Float } else { DataType::Double }
+    } else if lowercase.trim_end_matches('u').ends_with('l') {
+        DataType::Long
+    } else if lowercase.ends_with('u') {
+        DataType::Unsign
+    } else {
+        DataType::Integer
+    }
+}
+
+/// Checks whether a node is an integer literal equal to zero.
+fn is_zero(node_type: &NodeType) -> bool {
+    match node_type {
+        NodeType::Literal(value) | NodeType::Constant(value) => {
+            let lowercase = value.to_ascii_lowercase();
+            let digits = lowercase.trim_end_matches(['u', 'l']);
+            let parsed = match digits.strip_prefix("0x") {
+                Some(hex) => u64::from_str_radix(hex, 16),
+                None => digits.parse::<u64>(),
+            };
+            parsed == Ok(0)
+        },
+        _ => false,
+    }
+}
+
+/// Checks whether a value of one type can be stored in a place of another. Arithmetic types convert into each
+/// other implicitly; any other type only into itself. An unknown type is compatible with everything.
+fn is_assignable(target: DataType, value: DataType) -> bool {
+    target == DataType::None || value == DataType::None
+        || (target == value && target != DataType::Void)
+        || (target.is_arithmetic() && value.is_arithmetic())
+}
+
+/// Checks whether a type can be used as a condition or compared for equality.
+fn is_scalar(data_type: DataType) -> bool {
+    data_type == DataType::None || data_type.is_arithmetic() || data_type == DataType::String
+}
+
+/// Builds a type error that is not a mismatch between two operands, with the code of a `TypeMismatch`.
+fn type_error(message: &str) -> Diagnostic {
+    Diagnostic::error(message).with_code(mismatch(DataType::None, DataType::None).get_code())
+}
+
+/// Builds a type mismatch between two types.
+fn mismatch(left: DataType, right: DataType) -> ErrorType {
+    ErrorType::TypeMismatch { left_type: spell(left), right_type: spell(right) }
+}
+
+/// Spells a type as C would, naming aggregates by their keyword.
+fn spell(data_type: DataType) -> String {
+    match type_name(&data_type) {
+        Ok(name) => name.to_string(),
+        Err(_) => match data_type {
+            DataType::Struct => "struct".to_string(),
+            DataType::Union => "union".to_string(),
+            DataType::Enum => "enum".to_string(),
+            DataType::Function => "function".to_string(),
+            _ => "unknown".to_string(),
+        },
+    }
+}
@@ -1,9 +1,13 @@
 //! Base Case Testing for STS.
 
-use common::ast::{
-    core::{ASTNode, AST}, 
-    data_type::DataType, 
-    node_type::NodeType
+use common::{
+    ast::{
+        arena::ArenaAST,
+        core::{ASTNode, AST}, 
+        data_type::DataType, 
+        node_type::NodeType
+    },
+    error::ErrorType,
 };
 use sts::core::{SymbolInfo, SymbolTable, SymbolTableStack, SymbolValue};
 
@@ -20,7 +24,7 @@ fn test_empty_function() {
     assert_eq!(stack_pair_result, Ok((AST::new(fn_node), {
         let mut stack = SymbolTableStack::new();
         let mut table = SymbolTable::new();
-        table.add("empty_function".to_string(), SymbolInfo::new(DataType::Void, SymbolValue::FunctionValue{parameters: vec![]}));
+        table.add("empty_function".to_string(), SymbolInfo::new(DataType::Void, SymbolValue::FunctionValue{parameters: vec![], variadic: false}));
         stack.push(table);
         stack
     })));
@@ -47,7 +51,8 @@ fn test_empty_function_with_parameter() {
         let mut stack = SymbolTableStack::new();
         let mut table = SymbolTable::new();
         table.add("empty_function_with_param".to_string(), SymbolInfo::new(DataType::Void, SymbolValue::FunctionValue{
-            parameters: vec![("param1".to_string(), DataType::Integer)]
+            parameters: vec![("param1".to_string(), DataType::Integer)],
+            variadic: false,
         }));
         stack.push(table);
         stack
@@ -55,6 +60,36 @@ fn test_empty_function_with_parameter() {
 }
 
 
+#[test]
+fn test_variadic_function() {
+    let mut param_node = ASTNode::new(NodeType::Parameter);
+    param_node.set_children(vec![
+        ASTNode::new(NodeType::Identifier("count".to_string())),
+        ASTNode::new(NodeType::Type(DataType::Integer)),
+    ]);
+
+    let mut fn_node = ASTNode::new(NodeType::FunctionDeclaration);
+    fn_node.set_children(vec![
+        ASTNode::new(NodeType::Identifier("sum".to_string())),
+        param_node,
+        ASTNode::new(NodeType::VariadicParameter),
+        ASTNode::new(NodeType::Type(DataType::Integer)),
+    ]);
+
+    let stack_pair_result = SymbolTableStack::gen_sym_table_stack(AST::new(fn_node.clone()));
+
+    assert_eq!(stack_pair_result, Ok((AST::new(fn_node), {
+        let mut stack = SymbolTableStack::new();
+        let mut table = SymbolTable::new();
+        table.add("sum".to_string(), SymbolInfo::new(DataType::Integer, SymbolValue::FunctionValue{
+            parameters: vec![("count".to_string(), DataType::Integer)],
+            variadic: true,
+        }));
+        stack.push(table);
+        stack
+    })));
+}
+
 #[test]
 fn test_for_loop() {
     let condition_node = ASTNode::new(NodeType::Condition);
@@ -62,16 +97,19 @@ fn test_for_loop() {
     let mut for_node = ASTNode::new(NodeType::ForLoop);
     for_node.set_children(vec![condition_node, block_node]);
 
-    let stack_pair_result = SymbolTableStack::gen_sym_table_stack(AST::new(for_node.clone()));
+    let ast = AST::new(for_node);
+    let stack_pair_result = SymbolTableStack::gen_sym_table_stack(ast.clone());
 
-    assert_eq!(stack_pair_result, Ok((AST::new(for_node), {
+    assert_eq!(stack_pair_result, Ok((ast.clone(), {
+        let arena = ArenaAST::from_ast(&ast);
+        let loop_id = arena.get_root();
+        let block_id = arena.get_children(loop_id)[1];
         let mut stack = SymbolTableStack::new();
-        let global_table = SymbolTable::new();
-        let loop_condition_table = SymbolTable::new();
-        let loop_block_table = SymbolTable::new();
-        stack.push(global_table);
-        stack.push(loop_condition_table);
-        stack.push(loop_block_table);
+        stack.push(SymbolTable::new());
+        stack.open_scope(&arena, loop_id);
+        stack.open_scope(&arena, block_id);
+        stack.close_scope().unwrap();
+        stack.close_scope().unwrap();
         stack
     })));
 }
@@ -82,14 +120,16 @@ fn test_while_loop() {
     let mut while_node = ASTNode::new(NodeType::WhileLoop);
     while_node.set_children(vec![block_node]);
 
-    let stack_pair_result = SymbolTableStack::gen_sym_table_stack(AST::new(while_node.clone()));
+    let ast = AST::new(while_node);
+    let stack_pair_result = SymbolTableStack::gen_sym_table_stack(ast.clone());
 
-    assert_eq!(stack_pair_result, Ok((AST::new(while_node), {
+    assert_eq!(stack_pair_result, Ok((ast.clone(), {
+        let arena = ArenaAST::from_ast(&ast);
+        let block_id = arena.get_children(arena.get_root())[0];
         let mut stack = SymbolTableStack::new();
-        let global_table = SymbolTable::new();
-        let loop_table = SymbolTable::new();
-        stack.push(global_table);
-        stack.push(loop_table);
+        stack.push(SymbolTable::new());
+        stack.open_scope(&arena, block_id);
+        stack.close_scope().unwrap();
         stack
     })));
 }
@@ -100,14 +140,16 @@ fn test_do_while_loop() {
     let mut do_while_node = ASTNode::new(NodeType::DoWhileLoop);
     do_while_node.set_children(vec![block_node]);
 
-    let stack_pair_result = SymbolTableStack::gen_sym_table_stack(AST::new(do_while_node.clone()));
+    let ast = AST::new(do_while_node);
+    let stack_pair_result = SymbolTableStack::gen_sym_table_stack(ast.clone());
 
-    assert_eq!(stack_pair_result, Ok((AST::new(do_while_node), {
+    assert_eq!(stack_pair_result, Ok((ast.clone(), {
+        let arena = ArenaAST::from_ast(&ast);
+        let block_id = arena.get_children(arena.get_root())[0];
         let mut stack = SymbolTableStack::new();
-        let global_table = SymbolTable::new();
-        let loop_table = SymbolTable::new();
-        stack.push(global_table);
-        stack.push(loop_table);
+        stack.push(SymbolTable::new());
+        stack.open_scope(&arena, block_id);
+        stack.close_scope().unwrap();
         stack
     })));
 }
@@ -123,16 +165,17 @@ fn test_if_else_statement() {
     let mut if_else_node = ASTNode::new(NodeType::IfStatement);
     if_else_node.set_children(vec![if_node, else_node]);
 
-    let stack_pair_result = SymbolTableStack::gen_sym_table_stack(AST::new(if_else_node.clone()));
+    let ast = AST::new(if_else_node);
+    let stack_pair_result = SymbolTableStack::gen_sym_table_stack(ast.clone());
 
-    assert_eq!(stack_pair_result, Ok((AST::new(if_else_node), {
+    assert_eq!(stack_pair_result, Ok((ast.clone(), {
+        let arena = ArenaAST::from_ast(&ast);
         let mut stack = SymbolTableStack::new();
-        let global_table = SymbolTable::new();
-        let if_table = SymbolTable::new();
-        let else_table = SymbolTable::new();
-        stack.push(global_table);
-        stack.push(if_table);
-        stack.push(else_table);
+        stack.push(SymbolTable::new());
+        for &branch_id in arena.get_children(arena.get_root()) {
+            stack.open_scope(&arena, arena.get_children(branch_id)[0]);
+            stack.close_scope().unwrap();
+        }
         stack
     })));
 }
@@ -148,18 +191,17 @@ fn test_switch_statement() {
     let mut switch_node = ASTNode::new(NodeType::SwitchStatement);
     switch_node.set_children(vec![case_node1, case_node2, case_node3]);
 
-    let stack_pair_result = SymbolTableStack::gen_sym_table_stack(AST::new(switch_node.clone()));
+    let ast = AST::new(switch_node);
+    let stack_pair_result = SymbolTableStack::gen_sym_table_stack(ast.clone());
 
-    assert_eq!(stack_pair_result, Ok((AST::new(switch_node), {
+    assert_eq!(stack_pair_result, Ok((ast.clone(), {
+        let arena = ArenaAST::from_ast(&ast);
         let mut stack = SymbolTableStack::new();
-        let global_table = SymbolTable::new();
-        let case1_table = SymbolTable::new();
-        let case2_table = SymbolTable::new();
-        let case3_table = SymbolTable::new();
-        stack.push(global_table);
-        stack.push(case1_table);
-        stack.push(case2_table);
-        stack.push(case3_table);
+        stack.push(SymbolTable::new());
+        for &case_id in arena.get_children(arena.get_root()) {
+            stack.open_scope(&arena, arena.get_children(case_id)[0]);
+            stack.close_scope().unwrap();
+        }
         stack
     })));
 }
@@ -188,4 +230,90 @@ fn test_initialization() {
         stack.push(global_table);
         stack
     })));
-}
\ No newline at end of file
+}
+#[test]
+fn test_union_declaration() {
+    let mut field_i = ASTNode::new(NodeType::Field);
+    field_i.set_children(vec![
+        ASTNode::new(NodeType::Literal("i".to_string())),
+        ASTNode::new(NodeType::Type(DataType::Integer)),
+    ]);
+    let mut field_d = ASTNode::new(NodeType::Field);
+    field_d.set_children(vec![
+        ASTNode::new(NodeType::Literal("d".to_string())),
+        ASTNode::new(NodeType::Type(DataType::Double)),
+    ]);
+    let mut union_node = ASTNode::new(NodeType::UnionDeclaration);
+    union_node.set_children(vec![
+        ASTNode::new(NodeType::Identifier("Value".to_string())),
+        field_i,
+        field_d,
+    ]);
+
+    let stack_pair_result = SymbolTableStack::gen_sym_table_stack(AST::new(union_node.clone()));
+
+    assert_eq!(stack_pair_result, Ok((AST::new(union_node), {
+        let mut stack = SymbolTableStack::new();
+        let mut table = SymbolTable::new();
+        table.add("Value".to_string(), SymbolInfo::new(DataType::Union, SymbolValue::UnionValue {
+            fields: vec![
+                ("i".to_string(), DataType::Integer),
+                ("d".to_string(), DataType::Double),
+            ]
+        }));
+        stack.push(table);
+        stack
+    })));
+}
+
+#[test]
+fn test_struct_with_bit_fields_and_flexible_array() {
+    let mut flags_width = ASTNode::new(NodeType::BitWidth);
+    flags_width.add_child(ASTNode::new(NodeType::Literal("3".to_string())));
+    let mut field_flags = ASTNode::new(NodeType::Field);
+    field_flags.set_children(vec![
+        ASTNode::new(NodeType::Literal("flags".to_string())),
+        ASTNode::new(NodeType::Type(DataType::Unsign)),
+        flags_width,
+    ]);
+    let mut field_data = ASTNode::new(NodeType::Field);
+    field_data.set_children(vec![
+        ASTNode::new(NodeType::Literal("data".to_string())),
+        ASTNode::new(NodeType::Type(DataType::Char)),
+        ASTNode::new(NodeType::FlexibleArray),
+    ]);
+    let mut struct_node = ASTNode::new(NodeType::StructDeclaration);
+    struct_node.set_children(vec![
+        ASTNode::new(NodeType::Identifier("Packet".to_string())),
+        field_flags,
+        field_data,
+    ]);
+
+    let stack_pair_result = SymbolTableStack::gen_sym_table_stack(AST::new(struct_node.clone()));
+
+    assert_eq!(stack_pair_result, Ok((AST::new(struct_node), {
+        let mut stack = SymbolTableStack::new();
+        let mut table = SymbolTable::new();
+        table.add("Packet".to_string(), SymbolInfo::new(DataType::Struct, SymbolValue::StructValue {
+            fields: vec![("flags".to_string(), DataType::Unsign)],
+            bit_widths: vec![("flags".to_string(), 3)],
+            flexible_array: Some(("data".to_string(), DataType::Char)),
+        }));
+        stack.push(table);
+        stack
+    })));
+}
+
+/// Tests that the validating entry point reports a malformed tree instead of generating a stack for it.
+#[test]
+fn test_gen_sym_table_stack_validated_rejects_malformed_ast() {
+    let mut fn_node = ASTNode::new(NodeType::FunctionDeclaration);
+    fn_node.set_children(vec![
+        ASTNode::new(NodeType::Type(DataType::Void)),
+        ASTNode::new(NodeType::Identifier("misordered".to_string())),
+    ]);
+
+    let errors = SymbolTableStack::gen_sym_table_stack_validated(AST::new(fn_node)).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(&errors[0], ErrorType::InvalidAST { path, .. } if path == "root/FunctionDeclaration[0]"));
+}
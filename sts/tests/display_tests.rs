@@ -0,0 +1,83 @@
+//! This file contains tests for the tabular display of a `SymbolTableStack`.
+
+use common::{
+    ast,
+    ast::{arena::ArenaAST, data_type::DataType},
+};
+use sts::core::{SymbolInfo, SymbolTable, SymbolTableStack, SymbolValue};
+
+/// Tests that every scope is shown with its depth and opening construct, and its symbols sorted in columns.
+#[test]
+fn test_display() {
+    let arena = ArenaAST::from_ast(&ast!(FunctionDeclaration[
+        Identifier("main"),
+        Parameter[Identifier("argc"), Type(Integer)],
+        Type(Integer),
+        BlockExpression[ForLoop[LoopInitializer, Condition, LoopIncrement, BlockExpression]],
+    ]));
+    let for_loop = arena.get_children(arena.get_children(arena.get_root())[3])[0];
+
+    let mut globals = SymbolTable::new();
+    globals.add("main".to_string(), SymbolInfo::new(DataType::Integer, SymbolValue::FunctionValue {
+        parameters: vec![("argc".to_string(), DataType::Integer)],
+        variadic: true,
+    }));
+    globals.add("Packet".to_string(), SymbolInfo::new(DataType::Struct, SymbolValue::StructValue {
+        fields: vec![("flags".to_string(), DataType::Unsign), ("length".to_string(), DataType::Integer)],
+        bit_widths: vec![("flags".to_string(), 3)],
+        flexible_array: Some(("data".to_string(), DataType::Char)),
+    }));
+    globals.add("Color".to_string(), SymbolInfo::new(DataType::Enum, SymbolValue::EnumValue {
+        variants: vec!["RED".to_string(), "GREEN".to_string()],
+    }));
+    let mut sts = SymbolTableStack::new();
+    sts.push(globals);
+    sts.open_scope(&arena, arena.get_root());
+    sts.add_element("argc".to_string(), SymbolInfo::new(DataType::Integer, SymbolValue::NoAssociatedValue)).unwrap();
+    sts.add_element("total".to_string(), SymbolInfo::new(DataType::Long, SymbolValue::NoAssociatedValue)).unwrap();
+    sts.open_scope(&arena, for_loop);
+
+    let expected = "\
+scope 0 (depth 0)
+  name    type     value
+  Color   Enum     enum { RED, GREEN }
+  Packet  Struct   struct { flags: Unsigned : 3, length: Integer, data: Char[] }
+  main    Integer  function (argc: Integer, ...)
+scope 1 (depth 1): FunctionDeclaration `main`
+  name   type     value
+  argc   Integer  -
+  total  Long     -
+scope 2 (depth 2): ForLoop
+  (no symbols)
+";
+    assert_eq!(sts.to_string(), expected);
+    assert_eq!(sts.clone().to_string(), expected);
+}
+
+/// Tests that the debug output of a table lists its symbols in order, whatever order they were added in.
+#[test]
+fn test_debug_is_sorted() {
+    let mut table = SymbolTable::new();
+    for name in ["y", "b", "x", "a"] {
+        table.add(name.to_string(), SymbolInfo::new(DataType::Char, SymbolValue::NoAssociatedValue));
+    }
+    let debug = format!("{:?}", table);
+    let positions: Vec<usize> = ["\"a\"", "\"b\"", "\"x\"", "\"y\""].iter().map(|name| debug.find(name).unwrap()).collect();
+    assert!(positions.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+/// Tests that the debug output of a stack shows each scope's parent and the node and construct that opened it.
+#[test]
+fn test_debug_shows_scopes() {
+    let arena = ArenaAST::from_ast(&ast!(WhileLoop[BlockExpression]));
+    let block = arena.get_children(arena.get_root())[0];
+    let mut sts = SymbolTableStack::new();
+    sts.push(SymbolTable::new());
+    sts.open_scope(&arena, block);
+    sts.close_scope().unwrap();
+
+    let debug = format!("{:?}", sts);
+    assert!(debug.contains("ScopeId(0): Scope { parent: None, node: None, construct: None } {}"));
+    assert!(debug.contains(&format!("ScopeId(1): Scope {{ parent: Some(ScopeId(0)), node: Some({:?}), construct: Some(\\\"BlockExpression\\\") }} {{}}", block)));
+    assert!(debug.contains("current: Some(ScopeId(0))"));
+}
@@ -0,0 +1,183 @@
+//! This file contains tests for the resolution of identifiers and the detection of redeclared and shadowed names.
+
+use common::{
+    ast,
+    ast::data_type::DataType,
+    diagnostic::Diagnostic,
+    error::ErrorType,
+};
+use sts::{
+    core::{SymbolInfo, SymbolTable, SymbolTableStack, SymbolValue},
+    resolve::NameResolver,
+};
+
+/// Retrieves the messages of the diagnostics of a failed resolution.
+fn messages(errors: Vec<ErrorType>) -> Vec<String> {
+    errors.into_iter().map(|error| Diagnostic::from(error).to_string()).collect()
+}
+
+/// Tests that uses before a declaration, outside its scope, or of unknown functions or enum variants are reported.
+#[test]
+fn test_undefined_names() {
+    let mut globals = SymbolTable::new();
+    globals.add("printf".to_string(), SymbolInfo::new(DataType::Integer, SymbolValue::FunctionValue {
+        parameters: vec![("format".to_string(), DataType::String)],
+        variadic: true,
+    }));
+    let mut sts = SymbolTableStack::new();
+    sts.push(globals);
+
+    let ast = ast!(TopLevelExpression[
+        EnumDeclaration[Identifier("Color"), Variant[Identifier("RED")], Variant[Identifier("GREEN")]],
+        StructDeclaration[Identifier("Point"), Field[Literal("x"), Type(Integer)]],
+        FunctionDeclaration[
+            Identifier("main"),
+            Parameter[Identifier("count"), Type(Integer)],
+            Type(Integer),
+            BlockExpression[
+                Assignment[Identifier("total"), AssignedValue[Identifier("count")]],
+                Initialization[Variable[Identifier("total"), Type(Integer)], AssignedValue[Identifier("RED")]],
+                Initialization[Variable[Identifier("p"), Type(Struct), Identifier("Point")]],
+                Assignment[MemberAccess[Identifier("p"), Identifier("x")], AssignedValue[Identifier("GREEM")]],
+                SwitchStatement[Identifier("count"), BlockExpression[
+                    Case[Identifier("RED"), BlockExpression[Break]],
+                    Case[Identifier("GREN"), BlockExpression[Break]],
+                ]],
+                BlockExpression[Initialization[Variable[Identifier("last"), Type(Integer)]]],
+                FunctionCall[Identifier("printf"), Literal("\"%d\""), Identifier("last")],
+                FunctionCall[Identifier("print"), Identifier("total")],
+                Return[AssignedValue[Identifier("main")]],
+            ],
+        ],
+    ]);
+
+    assert_eq!(messages(NameResolver::check(&ast, &sts, false).unwrap_err()), vec![
+        "error[E0002]: cannot find variable `total` in this scope",
+        "error[E0002]: cannot find variable `GREEM` in this scope\n  = help: did you mean `GREEN`?",
+        "error[E0002]: cannot find enum variant `GREN` in this scope\n  = help: did you mean `GREEN`?",
+        "error[E0002]: cannot find variable `last` in this scope",
+        "error[E0002]: cannot find function `print` in this scope\n  = help: did you mean `printf`?",
+    ]);
+}
+
+/// Tests that names declared twice in one scope are reported with their first declaration, while prototypes,
+/// even with differently named parameters, and declarations in inner scopes are accepted.
+#[test]
+fn test_redeclarations() {
+    let ast = ast!(TopLevelExpression[
+        FunctionDeclaration[Identifier("square"), Parameter[Identifier("value"), Type(Integer)], Type(Integer)],
+        FunctionDeclaration[
+            Identifier("square"),
+            Parameter[Identifier("n"), Type(Integer)],
+            Type(Integer),
+            BlockExpression[
+                Initialization[Variable[Identifier("n"), Type(Double)]],
+                BlockExpression[Initialization[Variable[Identifier("total"), Type(Integer)]]],
+                Initialization[Variable[Identifier("total"), Type(Integer)]],
+                Return[AssignedValue[Identifier("total")]],
+            ],
+        ],
+        FunctionDeclaration[Identifier("square"), Type(Void)],
+        UnionDeclaration[Identifier("Value"), Field[Literal("number"), Type(Double)]],
+        Initialization[Variable[Identifier("Value"), Type(Long)]],
+    ]);
+
+    let errors = NameResolver::check(&ast, &SymbolTableStack::new(), false).unwrap_err();
+    assert_eq!(errors[0], ErrorType::Redeclaration {
+        name: "n".to_string(),
+        previous: "a variable of type `int`".to_string(),
+        path: Some("root/TopLevelExpression[0]/FunctionDeclaration[1]/Parameter[1]".to_string()),
+    });
+    assert_eq!(messages(errors), vec![
+        "error[E0009]: redeclaration of `n` in the same scope\n  = note: `n` was first declared in this scope as a variable of type `int`, at root/TopLevelExpression[0]/FunctionDeclaration[1]/Parameter[1]",
+        "error[E0009]: redeclaration of `square` in the same scope\n  = note: `square` was first declared in this scope as a function returning `int`, at root/TopLevelExpression[0]/FunctionDeclaration[0]",
+        "error[E0009]: redeclaration of `Value` in the same scope\n  = note: `Value` was first declared in this scope as a union, at root/TopLevelExpression[0]/UnionDeclaration[3]",
+    ]);
+}
+
+/// Tests that file-scope names are declared in source order, even when the symbol table stack already holds
+/// them, while symbols the tree does not declare resolve everywhere.
+#[test]
+fn test_globals_in_source_order() {
+    let mut globals = SymbolTable::new();
+    globals.add("later".to_string(), SymbolInfo::new(DataType::Integer, SymbolValue::NoAssociatedValue));
+    globals.add("f".to_string(), SymbolInfo::new(DataType::Integer, SymbolValue::FunctionValue { parameters: vec![], variadic: false }));
+    globals.add("errno".to_string(), SymbolInfo::new(DataType::Integer, SymbolValue::NoAssociatedValue));
+    let mut sts = SymbolTableStack::new();
+    sts.push(globals);
+
+    let ast = ast!(TopLevelExpression[
+        FunctionDeclaration[
+            Identifier("f"),
+            Type(Integer),
+            BlockExpression[Return[AssignedValue[BinaryExpression[Identifier("later"), Operator("+"), Identifier("errno")]]]],
+        ],
+        Initialization[Variable[Identifier("later"), Type(Integer)], AssignedValue[Literal(1)]],
+        Initialization[Variable[Identifier("after"), Type(Integer)], AssignedValue[Identifier("later")]],
+    ]);
+
+    assert_eq!(messages(NameResolver::check(&ast, &sts, false).unwrap_err()), vec![
+        "error[E0002]: cannot find variable `later` in this scope",
+    ]);
+}
+
+/// Tests that declarations hiding a name of an enclosing scope are warned about only when asked.
+#[test]
+fn test_shadowing() {
+    let mut globals = SymbolTable::new();
+    globals.add("limit".to_string(), SymbolInfo::new(DataType::Integer, SymbolValue::NoAssociatedValue));
+    let mut sts = SymbolTableStack::new();
+    sts.push(globals);
+
+    let ast = ast!(TopLevelExpression[
+        Initialization[Variable[Identifier("limit"), Type(Integer)], AssignedValue[Literal(10)]],
+        FunctionDeclaration[
+            Identifier("main"),
+            Type(Integer),
+            BlockExpression[
+                Initialization[Variable[Identifier("count"), Type(Integer)], AssignedValue[Identifier("limit")]],
+                ForLoop[
+                    LoopInitializer[Initialization[Variable[Identifier("count"), Type(Integer)], AssignedValue[Literal(0)]]],
+                    Condition[BinaryExpression[Identifier("count"), Operator("<"), Identifier("limit")]],
+                    LoopIncrement[UnaryExpression[Identifier("count"), Operator("++")]],
+                    BlockExpression[Initialization[Variable[Identifier("limit"), Type(Long)]]],
+                ],
+                Return[AssignedValue[Identifier("count")]],
+            ],
+        ],
+    ]);
+
+    assert_eq!(NameResolver::check(&ast, &sts, false).unwrap(), vec![]);
+    let warnings: Vec<String> = NameResolver::check(&ast, &sts, true).unwrap().iter().map(Diagnostic::to_string).collect();
+    assert_eq!(warnings, vec![
+        "warning: declaration of `count` shadows a declaration in an enclosing scope\n  = note: uses of `count` in this scope refer to the new declaration",
+        "warning: declaration of `limit` shadows a declaration in an enclosing scope\n  = note: uses of `limit` in this scope refer to the new declaration",
+    ]);
+}
+
+/// Tests that adding an element never overwrites a name of the innermost table, except to define a function
+/// declared with the same signature.
+#[test]
+fn test_add_element() {
+    let variable = SymbolInfo::new(DataType::Integer, SymbolValue::NoAssociatedValue);
+    let mut sts = SymbolTableStack::new();
+    assert!(matches!(sts.add_element("x".to_string(), variable.clone()), Err(ErrorType::DevError { .. })));
+
+    sts.push(SymbolTable::new());
+    sts.add_element("x".to_string(), variable.clone()).unwrap();
+    let error = sts.add_element("x".to_string(), SymbolInfo::new(DataType::Float, SymbolValue::NoAssociatedValue)).unwrap_err();
+    assert_eq!(error.get_code(), "E0009");
+    assert_eq!(sts.get_elements()[0].get("x").unwrap().get_data_type(), DataType::Integer);
+
+    let function = |parameter: &str, data_type: DataType| SymbolInfo::new(DataType::Integer, SymbolValue::FunctionValue {
+        parameters: vec![(parameter.to_string(), data_type)],
+        variadic: false,
+    });
+    sts.add_element("f".to_string(), function("a", DataType::Integer)).unwrap();
+    assert!(sts.add_element("f".to_string(), function("b", DataType::Integer)).is_ok());
+    assert_eq!(sts.add_element("f".to_string(), function("b", DataType::Long)).unwrap_err().get_code(), "E0009");
+
+    sts.push(SymbolTable::new());
+    assert!(sts.add_element("x".to_string(), variable).is_ok());
+    assert_eq!(sts.pop().map(|table| table.is_empty()), Some(false));
+}
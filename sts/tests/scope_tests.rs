@@ -0,0 +1,86 @@
+//! This file contains tests for the scopes of a `SymbolTableStack` and the lookup of names through them.
+
+use common::{
+    ast,
+    ast::{arena::ArenaAST, data_type::DataType, node_type::NodeType},
+    error::ErrorType,
+};
+use sts::core::{SymbolInfo, SymbolTable, SymbolTableStack, SymbolValue};
+
+/// Builds the `SymbolInfo` of a variable.
+fn variable(data_type: DataType) -> SymbolInfo {
+    SymbolInfo::new(data_type, SymbolValue::NoAssociatedValue)
+}
+
+/// Tests that scopes opened for nodes are linked to their enclosing scope and can be entered again by node.
+#[test]
+fn test_scopes_of_nodes() {
+    let arena = ArenaAST::from_ast(&ast!(FunctionDeclaration[
+        Identifier("main"),
+        Type(Integer),
+        BlockExpression[
+            Initialization[Variable[Identifier("x"), Type(Integer)]],
+            BlockExpression[Initialization[Variable[Identifier("x"), Type(Double)]]],
+            BlockExpression[Initialization[Variable[Identifier("y"), Type(Char)]]],
+        ],
+    ]));
+    let body = arena.get_children(arena.get_root())[2];
+    let (first, second) = (arena.get_children(body)[1], arena.get_children(body)[2]);
+
+    let mut sts = SymbolTableStack::new();
+    sts.push(SymbolTable::new());
+    sts.add_element("main".to_string(), SymbolInfo::new(DataType::Integer, SymbolValue::FunctionValue {
+        parameters: Vec::new(),
+        variadic: false,
+    })).unwrap();
+    let body_scope = sts.open_scope(&arena, body);
+    sts.add_element("x".to_string(), variable(DataType::Integer)).unwrap();
+    let first_scope = sts.open_scope(&arena, first);
+    sts.add_element("x".to_string(), variable(DataType::Double)).unwrap();
+    sts.close_scope().unwrap();
+    let second_scope = sts.open_scope(&arena, second);
+    sts.add_element("y".to_string(), variable(DataType::Char)).unwrap();
+    sts.close_scope().unwrap();
+    sts.close_scope().unwrap();
+
+    assert_eq!(sts.size(), 4);
+    assert_eq!(sts.get_scope(second), Some(second_scope));
+    assert_eq!(sts.get_scope(arena.get_root()), None);
+    assert_eq!(sts.get_parent(first_scope), Some(body_scope));
+    assert_eq!(sts.get_parent(second_scope), Some(body_scope));
+    assert_eq!(sts.get_current_scope().map(|scope| scope.get_index()), Some(0));
+    assert!(sts.lookup("x").is_none());
+
+    assert_eq!(sts.enter_scope(second).unwrap(), second_scope);
+    assert_eq!(sts.lookup("x").map(SymbolInfo::get_data_type), Some(DataType::Integer));
+    assert_eq!(sts.lookup("main").map(SymbolInfo::get_data_type), Some(DataType::Integer));
+    assert_eq!(sts.lookup_in_scope(first_scope, "x").map(SymbolInfo::get_data_type), Some(DataType::Double));
+    assert!(sts.lookup_in_scope(first_scope, "y").is_none());
+    assert!(sts.get_table(second_scope).unwrap().get("y").is_some());
+
+    let initialization = arena.get_descendants(body).into_iter()
+        .find(|&id| arena.get_node_type(id) == &NodeType::Initialization)
+        .unwrap();
+    assert!(matches!(sts.enter_scope(initialization), Err(ErrorType::DevError { .. })));
+}
+
+/// Tests that pushing and popping tables keeps the current scope in step, and that out-of-range accesses fail
+/// instead of panicking.
+#[test]
+fn test_push_and_pop() {
+    let mut sts = SymbolTableStack::new();
+    assert!(sts.close_scope().is_err());
+    assert!(sts.get_element(0).is_err());
+
+    let mut globals = SymbolTable::new();
+    globals.add("limit".to_string(), variable(DataType::Long));
+    sts.push(globals);
+    sts.push(SymbolTable::new());
+    sts.add_element("limit".to_string(), variable(DataType::Integer)).unwrap();
+    assert_eq!(sts.lookup("limit").map(SymbolInfo::get_data_type), Some(DataType::Integer));
+
+    assert!(sts.pop().is_some());
+    assert_eq!(sts.lookup("limit").map(SymbolInfo::get_data_type), Some(DataType::Long));
+    assert!(sts.get_element(0).unwrap().get("limit").is_some());
+    assert!(matches!(sts.get_element(1), Err(ErrorType::DevError { .. })));
+}
@@ -0,0 +1,49 @@
+//! This file contains tests for serializing a `SymbolTableStack`.
+//!
+//! Run with `cargo test -p sts --features serde`.
+
+#![cfg(feature = "serde")]
+
+use common::{
+    ast::data_type::DataType,
+    serialize::{from_binary, from_json, to_binary, to_json},
+};
+use sts::core::{SymbolInfo, SymbolTable, SymbolTableStack, SymbolValue};
+
+/// Builds a stack with a global scope holding a struct and a function, and an inner scope holding a variable.
+fn sample_stack() -> SymbolTableStack {
+    let mut global = SymbolTable::new();
+    global.add("Point".to_string(), SymbolInfo::new(DataType::Struct, SymbolValue::StructValue {
+        fields: vec![("x".to_string(), DataType::Integer), ("y".to_string(), DataType::Integer)],
+        bit_widths: Vec::new(),
+        flexible_array: None,
+    }));
+    global.add("main".to_string(), SymbolInfo::new(DataType::Integer, SymbolValue::FunctionValue {
+        parameters: Vec::new(),
+        variadic: false,
+    }));
+
+    let mut inner = SymbolTable::new();
+    inner.add("count".to_string(), SymbolInfo::new(DataType::Integer, SymbolValue::NoAssociatedValue));
+
+    let mut stack = SymbolTableStack::new();
+    stack.push(global);
+    stack.push(inner);
+    stack
+}
+
+/// Tests that a `SymbolTableStack` round-trips through JSON.
+#[test]
+fn test_sts_json_round_trip() {
+    let stack = sample_stack();
+    let json = to_json(&stack).unwrap();
+    assert_eq!(from_json::<SymbolTableStack>(&json).unwrap(), stack);
+}
+
+/// Tests that a `SymbolTableStack` round-trips through the binary format.
+#[test]
+fn test_sts_binary_round_trip() {
+    let stack = sample_stack();
+    let bytes = to_binary(&stack).unwrap();
+    assert_eq!(from_binary::<SymbolTableStack>(&bytes).unwrap(), stack);
+}
@@ -0,0 +1,75 @@
+//! This file contains tests for suggesting close matches for names missing from a symbol table stack.
+
+use common::{
+    ast::data_type::DataType,
+    diagnostic::Diagnostic,
+    error::ErrorType,
+};
+use sts::core::{SymbolInfo, SymbolTable, SymbolTableStack, SymbolValue};
+
+/// Builds a stack with globals, a struct, an enum and a function, and a local scope shadowing one global.
+fn stack() -> SymbolTableStack {
+    let mut globals = SymbolTable::new();
+    globals.add("count".to_string(), SymbolInfo::new(DataType::Integer, SymbolValue::NoAssociatedValue));
+    globals.add("compute".to_string(), SymbolInfo::new(DataType::Integer, SymbolValue::FunctionValue {
+        parameters: vec![],
+        variadic: false,
+    }));
+    globals.add("Point".to_string(), SymbolInfo::new(DataType::Struct, SymbolValue::StructValue {
+        fields: vec![("x".to_string(), DataType::Integer), ("height".to_string(), DataType::Integer)],
+        bit_widths: vec![],
+        flexible_array: None,
+    }));
+    globals.add("Color".to_string(), SymbolInfo::new(DataType::Enum, SymbolValue::EnumValue {
+        variants: vec!["RED".to_string(), "GREEN".to_string()],
+    }));
+
+    let mut locals = SymbolTable::new();
+    locals.add("total".to_string(), SymbolInfo::new(DataType::Float, SymbolValue::NoAssociatedValue));
+
+    let mut stack = SymbolTableStack::new();
+    stack.push(globals);
+    stack.push(locals);
+    stack
+}
+
+/// Tests suggestions for variables, functions, fields and variants.
+#[test]
+fn test_suggestions() {
+    let stack = stack();
+    assert_eq!(stack.suggest_variable("cuont"), Some("count".to_string()));
+    assert_eq!(stack.suggest_variable("totl"), Some("total".to_string()));
+    assert_eq!(stack.suggest_variable("green"), Some("GREEN".to_string()));
+    assert_eq!(stack.suggest_variable("Pint"), None);
+    assert_eq!(stack.suggest_function("comptue"), Some("compute".to_string()));
+    assert_eq!(stack.suggest_function("cont"), None);
+    assert_eq!(stack.suggest_field("Point", "hieght"), Some("height".to_string()));
+    assert_eq!(stack.suggest_field("Color", "RED"), None);
+    assert_eq!(stack.suggest_field("Missing", "x"), None);
+    assert_eq!(stack.suggest_variant("Red"), Some("RED".to_string()));
+}
+
+/// Tests that the errors carry the code of an undefined variable and a help only when a match exists.
+#[test]
+fn test_undefined_errors() {
+    let stack = stack();
+
+    let diagnostic = Diagnostic::from(stack.undefined_variable("cuont"));
+    assert_eq!(diagnostic.get_code(), Some("E0002"));
+    assert_eq!(diagnostic.get_message(), "cannot find variable `cuont` in this scope");
+    assert_eq!(diagnostic.get_help(), ["did you mean `count`?".to_string()]);
+
+    assert_eq!(stack.undefined_variable("zzz"), ErrorType::UndefinedVariable { variable_name: "zzz".to_string() });
+
+    let diagnostic = Diagnostic::from(stack.undefined_field("Point", "hieght"));
+    assert_eq!(diagnostic.get_message(), "no field `hieght` on `Point`");
+    assert_eq!(diagnostic.get_help(), ["did you mean `height`?".to_string()]);
+
+    let diagnostic = Diagnostic::from(stack.undefined_function("printf"));
+    assert_eq!(diagnostic.get_code(), Some("E0002"));
+    assert!(diagnostic.get_help().is_empty());
+
+    let diagnostic = Diagnostic::from(stack.undefined_variant("BLEU"));
+    assert_eq!(diagnostic.get_message(), "cannot find enum variant `BLEU` in this scope");
+    assert!(diagnostic.get_help().is_empty());
+}
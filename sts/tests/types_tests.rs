@@ -0,0 +1,169 @@
+//! This file contains tests for the type checker and the types it records for expressions.
+
+use common::{
+    ast,
+    ast::{data_type::DataType, node_type::NodeType},
+    diagnostic::Diagnostic,
+    error::ErrorType,
+};
+use sts::{
+    core::{SymbolInfo, SymbolTable, SymbolTableStack, SymbolValue},
+    types::{TypeChecker, TypedAST},
+};
+
+/// Finds the type recorded for the first node of a kind that has one, in pre-order.
+fn type_of(typed: &TypedAST, kind: &NodeType) -> Option<DataType> {
+    let arena = typed.get_ast();
+    let mut nodes = vec![arena.get_root()];
+    nodes.extend(arena.get_descendants(arena.get_root()));
+    nodes.into_iter().filter(|&id| arena.get_node_type(id) == kind).find_map(|id| typed.get_type(id))
+}
+
+/// Retrieves the messages of the errors of a failed check.
+fn messages(errors: Vec<ErrorType>) -> Vec<String> {
+    errors.into_iter().map(|error| Diagnostic::from(error).to_string()).collect()
+}
+
+/// Tests the integer promotions and usual arithmetic conversions recorded for expressions.
+#[test]
+fn test_arithmetic_conversions() {
+    let ast = ast!(FunctionDeclaration[
+        Identifier("main"),
+        Parameter[Identifier("c"), Type(Char)],
+        Parameter[Identifier("ratio"), Type(Float)],
+        Type(Integer),
+        BlockExpression[
+            Initialization[Variable[Identifier("scaled"), Type(Double)], AssignedValue[
+                BinaryExpression[Identifier("c"), Operator("*"), Identifier("ratio")],
+            ]],
+            Initialization[Variable[Identifier("big"), Type(Long)], AssignedValue[
+                BinaryExpression[Literal("1u"), Operator("+"), Literal("2L")],
+            ]],
+            Return[AssignedValue[BinaryExpression[UnaryExpression[Operator("-"), Identifier("c")], Operator("<"), Literal(1.5)]]],
+        ],
+    ]);
+
+    let typed = TypeChecker::check(&ast, &SymbolTableStack::new()).unwrap();
+    assert_eq!(type_of(&typed, &NodeType::BinaryExpression), Some(DataType::Float));
+    assert_eq!(type_of(&typed, &NodeType::Identifier("c".to_string())), Some(DataType::Char));
+    assert_eq!(type_of(&typed, &NodeType::Literal("2L".to_string())), Some(DataType::Long));
+    assert_eq!(type_of(&typed, &NodeType::UnaryExpression), Some(DataType::Integer));
+    assert_eq!(type_of(&typed, &NodeType::Literal("1.5".to_string())), Some(DataType::Double));
+    assert_eq!(type_of(&typed, &NodeType::Variable), Some(DataType::Double));
+}
+
+/// Tests operands an operator does not accept, mismatched assignments, invalid targets and division by zero.
+#[test]
+fn test_operator_and_assignment_errors() {
+    let ast = ast!(FunctionDeclaration[
+        Identifier("main"),
+        Type(Void),
+        BlockExpression[
+            StructDeclaration[Identifier("Point"), Field[Literal("x"), Type(Integer)]],
+            Initialization[Variable[Identifier("p"), Type(Struct), Identifier("Point")]],
+            Initialization[Variable[Identifier("ratio"), Type(Double)], AssignedValue[Literal(2.5)]],
+            Initialization[Variable[Identifier("name"), Type(String)], AssignedValue[Literal("\"sicc\"")]],
+            BinaryExpression[Identifier("ratio"), Operator("%"), Literal(2)],
+            BinaryExpression[Identifier("p"), Operator("+"), Literal(1)],
+            Assignment[Identifier("ratio"), AssignedValue[Identifier("name")]],
+            Assignment[Literal(5), Identifier("ratio")],
+            UnaryExpression[Literal(1), Operator("++")],
+            Initialization[Variable[Identifier("x"), Type(Integer)], AssignedValue[
+                BinaryExpression[MemberAccess[Identifier("p"), Identifier("x")], Operator("/"), Literal(0)],
+            ]],
+            BinaryExpression[Identifier("ratio"), Operator("/"), Literal(0)],
+        ],
+    ]);
+
+    let errors = TypeChecker::check(&ast, &SymbolTableStack::new()).unwrap_err();
+    assert_eq!(errors, vec![
+        ErrorType::UnsupportedOperator { operator: "%".to_string(), operand_type: "double".to_string() },
+        ErrorType::UnsupportedOperator { operator: "+".to_string(), operand_type: "struct".to_string() },
+        ErrorType::TypeMismatch { left_type: "double".to_string(), right_type: "char *".to_string() },
+        ErrorType::InvalidAssignment { target: "5".to_string() },
+        ErrorType::InvalidAssignment { target: "1".to_string() },
+        ErrorType::DivisionByZero { operation: "p.x / 0".to_string() },
+    ]);
+}
+
+/// Tests calls against the signatures of the symbol table stack, and returns against the function.
+#[test]
+fn test_calls_and_returns() {
+    let mut globals = SymbolTable::new();
+    globals.add("printf".to_string(), SymbolInfo::new(DataType::Integer, SymbolValue::FunctionValue {
+        parameters: vec![("format".to_string(), DataType::String)],
+        variadic: true,
+    }));
+    globals.add("limit".to_string(), SymbolInfo::new(DataType::Long, SymbolValue::NoAssociatedValue));
+    let mut sts = SymbolTableStack::new();
+    sts.push(globals);
+
+    let ast = ast!(TopLevelExpression[
+        FunctionDeclaration[
+            Identifier("square"),
+            Parameter[Identifier("n"), Type(Integer)],
+            Type(Integer),
+            BlockExpression[Return[AssignedValue[BinaryExpression[Identifier("n"), Operator("*"), Identifier("n")]]]],
+        ],
+        FunctionDeclaration[
+            Identifier("main"),
+            Type(Integer),
+            BlockExpression[
+                FunctionCall[Identifier("printf"), Literal("\"%d %f\""), Identifier("limit"), Literal(1.5)],
+                FunctionCall[Identifier("printf")],
+                FunctionCall[Identifier("square"), Literal("\"two\"")],
+                FunctionCall[Identifier("square"), Literal(1), Literal(2)],
+                FunctionCall[Identifier("limit")],
+                Return,
+            ],
+        ],
+        FunctionDeclaration[Identifier("log"), Type(Void), BlockExpression[Return[AssignedValue[Literal(0)]]]],
+        FunctionDeclaration[Identifier("name"), Type(Integer), BlockExpression[Return[AssignedValue[Literal("\"x\"")]]]],
+    ]);
+
+    assert_eq!(messages(TypeChecker::check(&ast, &sts).unwrap_err()), vec![
+        "error[E0001]: function `printf` takes at least 1 argument but 0 were supplied",
+        "error[E0001]: mismatched types `int` and `char *`\n  = note: in argument 1 of `square`, which expects `int`",
+        "error[E0001]: function `square` takes 1 argument but 2 were supplied",
+        "error[E0003]: operator `()` cannot be applied to type `long`",
+        "error[E0001]: `return` without a value in a function returning `int`",
+        "error[E0001]: `return` with a value in a function returning `void`\n  = help: remove the value, or change the return type of the function",
+        "error[E0001]: mismatched types `int` and `char *`\n  = note: mismatched types: expected `int` to be returned, found `char *`",
+    ]);
+}
+
+/// Tests conditions, switches, compound assignments, member types, scopes and unknown fields, which are looked up
+/// in the struct or union the base was declared with.
+#[test]
+fn test_statements_and_members() {
+    let ast = ast!(FunctionDeclaration[
+        Identifier("main"),
+        Type(Integer),
+        BlockExpression[
+            UnionDeclaration[Identifier("Value"), Field[Literal("number"), Type(Double)], Field[Literal("letter"), Type(Char)]],
+            StructDeclaration[Identifier("Point"), Field[Literal("x"), Type(Integer)]],
+            Initialization[Variable[Identifier("v"), Type(Union), Identifier("Value")]],
+            Initialization[Variable[Identifier("p"), Type(Struct), Identifier("Point")]],
+            Initialization[Variable[Identifier("total"), Type(Integer)], AssignedValue[Literal(0)]],
+            ForLoop[
+                LoopInitializer[Initialization[Variable[Identifier("i"), Type(Integer)], AssignedValue[Literal(0)]]],
+                Condition[BinaryExpression[Identifier("i"), Operator("<"), Literal(10)]],
+                LoopIncrement[UnaryExpression[Identifier("i"), Operator("++")]],
+                BlockExpression[Assignment[Identifier("total"), Operator("+="), MemberAccess[Identifier("v"), Identifier("number")]]],
+            ],
+            WhileLoop[Condition[Identifier("v")], BlockExpression],
+            SwitchStatement[Literal("1.0"), BlockExpression[Case[Literal(1), BlockExpression[Break]]]],
+            MemberAccess[Identifier("v"), Identifier("nubmer")],
+            MemberAccess[Identifier("p"), Identifier("number")],
+            Return[AssignedValue[Identifier("i")]],
+        ],
+    ]);
+
+    let errors = messages(TypeChecker::check(&ast, &SymbolTableStack::new()).unwrap_err());
+    assert_eq!(errors, vec![
+        "error[E0001]: condition of type `union` is not a number",
+        "error[E0001]: `switch` value of type `double` is not an integer",
+        "error[E0002]: no field `nubmer` on `Value`\n  = help: did you mean `number`?",
+        "error[E0002]: no field `number` on `Point`",
+    ]);
+}